@@ -0,0 +1,95 @@
+#![feature(test)]
+#![deny(warnings)]
+
+extern crate test;
+
+use blasoxide::*;
+
+use test::Bencher;
+
+fn dgemv_driver(m: usize, n: usize, bencher: &mut Bencher) {
+    let a = aligned_alloc::Alloc::new(m * n * std::mem::size_of::<f64>());
+    let x = aligned_alloc::Alloc::new(n * std::mem::size_of::<f64>());
+    let y = aligned_alloc::Alloc::new(m * std::mem::size_of::<f64>());
+
+    let context = Context::new();
+
+    bencher.iter(|| unsafe {
+        dgemv(
+            &context,
+            Layout::ColMajor,
+            false,
+            m,
+            n,
+            7.,
+            a.ptr() as *const f64,
+            m,
+            x.ptr() as *const f64,
+            1,
+            11.,
+            y.ptr() as *mut f64,
+            1,
+        );
+    });
+}
+
+fn dsymv_driver(n: usize, bencher: &mut Bencher) {
+    let a = aligned_alloc::Alloc::new(n * n * std::mem::size_of::<f64>());
+    let x = aligned_alloc::Alloc::new(n * std::mem::size_of::<f64>());
+    let y = aligned_alloc::Alloc::new(n * std::mem::size_of::<f64>());
+
+    let context = Context::new();
+
+    bencher.iter(|| unsafe {
+        dsymv(
+            &context,
+            Layout::ColMajor,
+            Uplo::Upper,
+            n,
+            7.,
+            a.ptr() as *const f64,
+            n,
+            x.ptr() as *const f64,
+            1,
+            11.,
+            y.ptr() as *mut f64,
+            1,
+        );
+    });
+}
+
+#[bench]
+fn bench_dgemv_250(bencher: &mut Bencher) {
+    const LEN: usize = 250;
+    dgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dgemv_1000(bencher: &mut Bencher) {
+    const LEN: usize = 1000;
+    dgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dgemv_4000(bencher: &mut Bencher) {
+    const LEN: usize = 4000;
+    dgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dsymv_250(bencher: &mut Bencher) {
+    const LEN: usize = 250;
+    dsymv_driver(LEN, bencher);
+}
+
+#[bench]
+fn bench_dsymv_1000(bencher: &mut Bencher) {
+    const LEN: usize = 1000;
+    dsymv_driver(LEN, bencher);
+}
+
+#[bench]
+fn bench_dsymv_4000(bencher: &mut Bencher) {
+    const LEN: usize = 4000;
+    dsymv_driver(LEN, bencher);
+}
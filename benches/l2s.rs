@@ -0,0 +1,95 @@
+#![feature(test)]
+#![deny(warnings)]
+
+extern crate test;
+
+use blasoxide::*;
+
+use test::Bencher;
+
+fn sgemv_driver(m: usize, n: usize, bencher: &mut Bencher) {
+    let a = aligned_alloc::Alloc::new(m * n * std::mem::size_of::<f32>());
+    let x = aligned_alloc::Alloc::new(n * std::mem::size_of::<f32>());
+    let y = aligned_alloc::Alloc::new(m * std::mem::size_of::<f32>());
+
+    let context = Context::new();
+
+    bencher.iter(|| unsafe {
+        sgemv(
+            &context,
+            Layout::ColMajor,
+            false,
+            m,
+            n,
+            7.,
+            a.ptr() as *const f32,
+            m,
+            x.ptr() as *const f32,
+            1,
+            11.,
+            y.ptr() as *mut f32,
+            1,
+        );
+    });
+}
+
+fn ssymv_driver(n: usize, bencher: &mut Bencher) {
+    let a = aligned_alloc::Alloc::new(n * n * std::mem::size_of::<f32>());
+    let x = aligned_alloc::Alloc::new(n * std::mem::size_of::<f32>());
+    let y = aligned_alloc::Alloc::new(n * std::mem::size_of::<f32>());
+
+    let context = Context::new();
+
+    bencher.iter(|| unsafe {
+        ssymv(
+            &context,
+            Layout::ColMajor,
+            Uplo::Upper,
+            n,
+            7.,
+            a.ptr() as *const f32,
+            n,
+            x.ptr() as *const f32,
+            1,
+            11.,
+            y.ptr() as *mut f32,
+            1,
+        );
+    });
+}
+
+#[bench]
+fn bench_sgemv_250(bencher: &mut Bencher) {
+    const LEN: usize = 250;
+    sgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_sgemv_1000(bencher: &mut Bencher) {
+    const LEN: usize = 1000;
+    sgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_sgemv_4000(bencher: &mut Bencher) {
+    const LEN: usize = 4000;
+    sgemv_driver(LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_ssymv_250(bencher: &mut Bencher) {
+    const LEN: usize = 250;
+    ssymv_driver(LEN, bencher);
+}
+
+#[bench]
+fn bench_ssymv_1000(bencher: &mut Bencher) {
+    const LEN: usize = 1000;
+    ssymv_driver(LEN, bencher);
+}
+
+#[bench]
+fn bench_ssymv_4000(bencher: &mut Bencher) {
+    const LEN: usize = 4000;
+    ssymv_driver(LEN, bencher);
+}
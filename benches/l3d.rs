@@ -0,0 +1,60 @@
+#![feature(test)]
+#![deny(warnings)]
+
+extern crate test;
+
+use blasoxide::*;
+
+use test::Bencher;
+
+fn dgemm_driver(m: usize, n: usize, k: usize, bencher: &mut Bencher) {
+    let a = aligned_alloc::Alloc::new(m * k * std::mem::size_of::<f64>());
+    let b = aligned_alloc::Alloc::new(n * k * std::mem::size_of::<f64>());
+    let c = aligned_alloc::Alloc::new(m * n * std::mem::size_of::<f64>());
+
+    let context = Context::new();
+
+    bencher.iter(|| unsafe {
+        dgemm(
+            &context,
+            Layout::ColMajor,
+            false,
+            false,
+            m,
+            n,
+            k,
+            7.,
+            a.ptr() as *const f64,
+            m,
+            b.ptr() as *const f64,
+            k,
+            11.,
+            c.ptr() as *mut f64,
+            m,
+        );
+    });
+}
+
+#[bench]
+fn bench_dgemm_250(bencher: &mut Bencher) {
+    const LEN: usize = 250;
+    dgemm_driver(LEN, LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dgemm_500(bencher: &mut Bencher) {
+    const LEN: usize = 500;
+    dgemm_driver(LEN, LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dgemm_1000(bencher: &mut Bencher) {
+    const LEN: usize = 1000;
+    dgemm_driver(LEN, LEN, LEN, bencher);
+}
+
+#[bench]
+fn bench_dgemm_2000(bencher: &mut Bencher) {
+    const LEN: usize = 2000;
+    dgemm_driver(LEN, LEN, LEN, bencher);
+}
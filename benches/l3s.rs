@@ -17,6 +17,7 @@ fn sgemm_driver(m: usize, n: usize, k: usize, bencher: &mut Bencher) {
     bencher.iter(|| unsafe {
         sgemm(
             &context,
+            Layout::ColMajor,
             false,
             false,
             m,
@@ -0,0 +1,177 @@
+#ifndef BLASOXIDE_CBLAS_H
+#define BLASOXIDE_CBLAS_H
+
+/* C interface exported when blasoxide is built with the `cblas` feature. */
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define CBLAS_INDEX size_t
+
+enum CBLAS_ORDER { CblasRowMajor = 101, CblasColMajor = 102 };
+enum CBLAS_TRANSPOSE { CblasNoTrans = 111, CblasTrans = 112, CblasConjTrans = 113 };
+enum CBLAS_UPLO { CblasUpper = 121, CblasLower = 122 };
+enum CBLAS_DIAG { CblasNonUnit = 131, CblasUnit = 132 };
+
+typedef enum CBLAS_ORDER CBLAS_LAYOUT;
+
+/* Level 1 */
+
+void cblas_srot(const int N, float *X, const int incX, float *Y, const int incY,
+                const float c, const float s);
+void cblas_srotg(float *a, float *b, float *c, float *s);
+void cblas_srotmg(float *d1, float *d2, float *b1, const float b2, float *P);
+void cblas_srotm(const int N, float *X, const int incX, float *Y, const int incY,
+                const float *P);
+void cblas_sswap(const int N, float *X, const int incX, float *Y, const int incY);
+void cblas_sscal(const int N, const float alpha, float *X, const int incX);
+void cblas_scopy(const int N, const float *X, const int incX, float *Y, const int incY);
+void cblas_saxpy(const int N, const float alpha, const float *X, const int incX,
+                 float *Y, const int incY);
+float cblas_sdot(const int N, const float *X, const int incX, const float *Y,
+                 const int incY);
+float cblas_snrm2(const int N, const float *X, const int incX);
+float cblas_sasum(const int N, const float *X, const int incX);
+CBLAS_INDEX cblas_isamax(const int N, const float *X, const int incX);
+CBLAS_INDEX cblas_isamin(const int N, const float *X, const int incX);
+
+void cblas_drot(const int N, double *X, const int incX, double *Y, const int incY,
+                const double c, const double s);
+void cblas_drotg(double *a, double *b, double *c, double *s);
+void cblas_drotmg(double *d1, double *d2, double *b1, const double b2, double *P);
+void cblas_drotm(const int N, double *X, const int incX, double *Y, const int incY,
+                const double *P);
+void cblas_dswap(const int N, double *X, const int incX, double *Y, const int incY);
+void cblas_dscal(const int N, const double alpha, double *X, const int incX);
+void cblas_dcopy(const int N, const double *X, const int incX, double *Y, const int incY);
+void cblas_daxpy(const int N, const double alpha, const double *X, const int incX,
+                 double *Y, const int incY);
+double cblas_ddot(const int N, const double *X, const int incX, const double *Y,
+                  const int incY);
+double cblas_dnrm2(const int N, const double *X, const int incX);
+double cblas_dasum(const int N, const double *X, const int incX);
+CBLAS_INDEX cblas_idamax(const int N, const double *X, const int incX);
+CBLAS_INDEX cblas_idamin(const int N, const double *X, const int incX);
+
+/* Level 2 */
+
+void cblas_sgemv(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const int M, const int N, const float alpha, const float *A,
+                 const int lda, const float *X, const int incX, const float beta,
+                 float *Y, const int incY);
+void cblas_sgbmv(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const int M, const int N, const int KL, const int KU, const float alpha,
+                 const float *A, const int lda, const float *X, const int incX,
+                 const float beta, float *Y, const int incY);
+void cblas_ssymv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const float alpha, const float *A, const int lda, const float *X,
+                 const int incX, const float beta, float *Y, const int incY);
+void cblas_ssbmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const int K, const float alpha, const float *A, const int lda,
+                 const float *X, const int incX, const float beta, float *Y,
+                 const int incY);
+void cblas_sspmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const float alpha, const float *Ap, const float *X, const int incX,
+                 const float beta, float *Y, const int incY);
+void cblas_sger(const enum CBLAS_ORDER Order, const int M, const int N, const float alpha,
+                const float *X, const int incX, const float *Y, const int incY, float *A,
+                const int lda);
+void cblas_ssyr(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                const float alpha, const float *X, const int incX, float *A, const int lda);
+void cblas_ssyr2(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const float alpha, const float *X, const int incX, const float *Y,
+                 const int incY, float *A, const int lda);
+void cblas_sspr(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                const float alpha, const float *X, const int incX, float *Ap);
+void cblas_sspr2(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const float alpha, const float *X, const int incX, const float *Y,
+                 const int incY, float *Ap);
+void cblas_strmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const float *A, const int lda, float *X, const int incX);
+void cblas_strsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const float *A, const int lda, float *X, const int incX);
+void cblas_stbmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const int K, const float *A, const int lda, float *X, const int incX);
+void cblas_stbsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const int K, const float *A, const int lda, float *X, const int incX);
+void cblas_stpmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const float *Ap, float *X, const int incX);
+void cblas_stpsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const float *Ap, float *X, const int incX);
+
+void cblas_dgemv(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const int M, const int N, const double alpha, const double *A,
+                 const int lda, const double *X, const int incX, const double beta,
+                 double *Y, const int incY);
+void cblas_dgbmv(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const int M, const int N, const int KL, const int KU, const double alpha,
+                 const double *A, const int lda, const double *X, const int incX,
+                 const double beta, double *Y, const int incY);
+void cblas_dsymv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const double alpha, const double *A, const int lda, const double *X,
+                 const int incX, const double beta, double *Y, const int incY);
+void cblas_dsbmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const int K, const double alpha, const double *A, const int lda,
+                 const double *X, const int incX, const double beta, double *Y,
+                 const int incY);
+void cblas_dspmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const double alpha, const double *Ap, const double *X, const int incX,
+                 const double beta, double *Y, const int incY);
+void cblas_dger(const enum CBLAS_ORDER Order, const int M, const int N, const double alpha,
+                const double *X, const int incX, const double *Y, const int incY, double *A,
+                const int lda);
+void cblas_dsyr(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                const double alpha, const double *X, const int incX, double *A, const int lda);
+void cblas_dsyr2(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const double alpha, const double *X, const int incX, const double *Y,
+                 const int incY, double *A, const int lda);
+void cblas_dspr(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                const double alpha, const double *X, const int incX, double *Ap);
+void cblas_dspr2(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo, const int N,
+                 const double alpha, const double *X, const int incX, const double *Y,
+                 const int incY, double *Ap);
+void cblas_dtrmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const double *A, const int lda, double *X, const int incX);
+void cblas_dtrsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const double *A, const int lda, double *X, const int incX);
+void cblas_dtbmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const int K, const double *A, const int lda, double *X, const int incX);
+void cblas_dtbsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const int K, const double *A, const int lda, double *X, const int incX);
+void cblas_dtpmv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const double *Ap, double *X, const int incX);
+void cblas_dtpsv(const enum CBLAS_ORDER Order, const enum CBLAS_UPLO Uplo,
+                 const enum CBLAS_TRANSPOSE TransA, const enum CBLAS_DIAG Diag, const int N,
+                 const double *Ap, double *X, const int incX);
+
+/* Level 3 */
+
+void cblas_sgemm(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const enum CBLAS_TRANSPOSE TransB, const int M, const int N, const int K,
+                 const float alpha, const float *A, const int lda, const float *B,
+                 const int ldb, const float beta, float *C, const int ldc);
+
+void cblas_dgemm(const enum CBLAS_ORDER Order, const enum CBLAS_TRANSPOSE TransA,
+                 const enum CBLAS_TRANSPOSE TransB, const int M, const int N, const int K,
+                 const double alpha, const double *A, const int lda, const double *B,
+                 const int ldb, const double beta, double *C, const int ldc);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
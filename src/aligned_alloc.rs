@@ -5,12 +5,33 @@ pub struct Alloc {
     layout: alloc::Layout,
 }
 
+const ALIGN: usize = 64;
+
 impl Alloc {
+    /// Allocates `size` bytes aligned to 64 bytes.
+    ///
+    /// Calls [`handle_alloc_error`](alloc::handle_alloc_error) when the
+    /// allocation fails.
     pub fn new(size: usize) -> Alloc {
-        const ALIGN: usize = 64;
         let layout = alloc::Layout::from_size_align(size, ALIGN).unwrap();
+
+        Alloc::try_new(size).unwrap_or_else(|| alloc::handle_alloc_error(layout))
+    }
+
+    /// Allocates `size` bytes aligned to 64 bytes, or returns `None` when
+    /// `size` is zero or the allocation fails.
+    pub fn try_new(size: usize) -> Option<Alloc> {
+        let layout = alloc::Layout::from_size_align(size, ALIGN).ok()?;
+        if layout.size() == 0 {
+            return None;
+        }
+
         let ptr = unsafe { alloc::alloc(layout) };
-        Alloc { ptr, layout }
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(Alloc { ptr, layout })
     }
 
     pub fn ptr(&self) -> *mut u8 {
@@ -18,6 +39,10 @@ impl Alloc {
     }
 }
 
+unsafe impl Send for Alloc {}
+
+unsafe impl Sync for Alloc {}
+
 impl Drop for Alloc {
     fn drop(&mut self) {
         unsafe {
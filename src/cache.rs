@@ -0,0 +1,214 @@
+use std::sync::OnceLock;
+
+/// Sizes in bytes of the data caches seen by one core.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct CacheSizes {
+    pub(crate) l1: usize,
+    pub(crate) l2: usize,
+    pub(crate) l3: Option<usize>,
+}
+
+pub(crate) fn cache_sizes() -> Option<CacheSizes> {
+    static CACHE_SIZES: OnceLock<Option<CacheSizes>> = OnceLock::new();
+
+    *CACHE_SIZES.get_or_init(detect)
+}
+
+fn detect() -> Option<CacheSizes> {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        if let Some(sizes) = cpuid::detect() {
+            return Some(sizes);
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if let Some(sizes) = sysfs::detect() {
+            return Some(sizes);
+        }
+    }
+
+    None
+}
+
+#[derive(Default)]
+struct Levels {
+    l1: Option<usize>,
+    l2: Option<usize>,
+    l3: Option<usize>,
+}
+
+impl Levels {
+    fn add(&mut self, level: u32, size: usize) {
+        let slot = match level {
+            1 => &mut self.l1,
+            2 => &mut self.l2,
+            3 => &mut self.l3,
+            _ => return,
+        };
+
+        *slot = Some(slot.map_or(size, |prev| prev.max(size)));
+    }
+
+    fn finish(self) -> Option<CacheSizes> {
+        Some(CacheSizes {
+            l1: self.l1?,
+            l2: self.l2?,
+            l3: self.l3,
+        })
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod cpuid {
+    use super::{CacheSizes, Levels};
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::{__cpuid, __cpuid_count};
+
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::{__cpuid, __cpuid_count};
+
+    const INTEL_LEAF: u32 = 0x4;
+    const AMD_LEAF: u32 = 0x8000_001d;
+
+    pub(super) fn detect() -> Option<CacheSizes> {
+        let max_leaf = __cpuid(0).eax;
+        let max_ext_leaf = __cpuid(0x8000_0000).eax;
+
+        if max_leaf >= INTEL_LEAF {
+            if let Some(sizes) = read_leaf(INTEL_LEAF) {
+                return Some(sizes);
+            }
+        }
+
+        if max_ext_leaf >= AMD_LEAF {
+            return read_leaf(AMD_LEAF);
+        }
+
+        None
+    }
+
+    // Leaf 4 and its AMD equivalent 0x8000001d enumerate one cache per
+    // subleaf until the cache type field reads zero.
+    fn read_leaf(leaf: u32) -> Option<CacheSizes> {
+        let mut levels = Levels::default();
+
+        for subleaf in 0.. {
+            let regs = __cpuid_count(leaf, subleaf);
+
+            let cache_type = regs.eax & 0x1f;
+            if cache_type == 0 {
+                break;
+            }
+
+            // Instruction caches do not hold packed panels.
+            if cache_type == 2 {
+                continue;
+            }
+
+            let level = (regs.eax >> 5) & 0x7;
+            let ways = ((regs.ebx >> 22) & 0x3ff) as usize + 1;
+            let partitions = ((regs.ebx >> 12) & 0x3ff) as usize + 1;
+            let line_size = (regs.ebx & 0xfff) as usize + 1;
+            let sets = regs.ecx as usize + 1;
+
+            levels.add(level, ways * partitions * line_size * sets);
+        }
+
+        levels.finish()
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod sysfs {
+    use super::{CacheSizes, Levels};
+    use std::fs;
+    use std::path::Path;
+
+    pub(super) fn detect() -> Option<CacheSizes> {
+        let dir = Path::new("/sys/devices/system/cpu/cpu0/cache");
+        let mut levels = Levels::default();
+
+        for entry in fs::read_dir(dir).ok()? {
+            let path = entry.ok()?.path();
+
+            let is_index = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("index"));
+            if !is_index {
+                continue;
+            }
+
+            let read = |name: &str| fs::read_to_string(path.join(name)).ok();
+
+            let cache_type = read("type")?;
+            if cache_type.trim() == "Instruction" {
+                continue;
+            }
+
+            let level = read("level")?.trim().parse().ok()?;
+            let size = parse_size(read("size")?.trim())?;
+
+            levels.add(level, size);
+        }
+
+        levels.finish()
+    }
+
+    pub(super) fn parse_size(size: &str) -> Option<usize> {
+        let (digits, scale) = match size.as_bytes().last()? {
+            b'K' => (&size[..size.len() - 1], 1 << 10),
+            b'M' => (&size[..size.len() - 1], 1 << 20),
+            b'G' => (&size[..size.len() - 1], 1 << 30),
+            _ => (size, 1),
+        };
+
+        digits.parse::<usize>().ok()?.checked_mul(scale)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_keep_the_largest_cache_of_each_level() {
+        let mut levels = Levels::default();
+        levels.add(1, 32 << 10);
+        levels.add(1, 48 << 10);
+        levels.add(2, 1 << 20);
+        levels.add(4, 64 << 20);
+
+        let sizes = levels.finish().unwrap();
+
+        assert_eq!((sizes.l1, sizes.l2, sizes.l3), (48 << 10, 1 << 20, None));
+    }
+
+    #[test]
+    fn levels_need_l1_and_l2() {
+        let mut levels = Levels::default();
+        levels.add(1, 32 << 10);
+        levels.add(3, 8 << 20);
+
+        assert!(levels.finish().is_none());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn parse_size_reads_sysfs_sizes() {
+        use super::sysfs::parse_size;
+
+        assert_eq!(parse_size("48K"), Some(48 << 10));
+        assert_eq!(parse_size("2M"), Some(2 << 20));
+        assert_eq!(parse_size("1G"), Some(1 << 30));
+        assert_eq!(parse_size("4096"), Some(4096));
+        assert_eq!(parse_size(""), None);
+        assert_eq!(parse_size("K"), None);
+        assert_eq!(parse_size("12KB"), None);
+        assert_eq!(parse_size("-1K"), None);
+        assert_eq!(parse_size(&format!("{}G", usize::MAX)), None);
+    }
+}
@@ -0,0 +1,204 @@
+use super::check_inc;
+use crate::error::BlasError;
+
+pub unsafe fn drot(
+    n: usize,
+    x: *mut f64,
+    incx: isize,
+    y: *mut f64,
+    incy: isize,
+    c: f64,
+    s: f64,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::drot(n, x, incx, y, incy, c, s);
+    Ok(())
+}
+
+pub unsafe fn drotm(
+    n: usize,
+    x: *mut f64,
+    incx: isize,
+    y: *mut f64,
+    incy: isize,
+    param: &[f64; 5],
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::drotm(n, x, incx, y, incy, param);
+    Ok(())
+}
+
+pub unsafe fn dswap(
+    n: usize,
+    x: *mut f64,
+    incx: isize,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::dswap(n, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn dscal(n: usize, a: f64, x: *mut f64, incx: isize) -> Result<(), BlasError> {
+    check_inc(4, incx)?;
+
+    crate::dscal(n, a, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dcopy(
+    n: usize,
+    x: *const f64,
+    incx: isize,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::dcopy(n, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn daxpy(
+    n: usize,
+    a: f64,
+    x: *const f64,
+    incx: isize,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(4, incx)?;
+    check_inc(6, incy)?;
+
+    crate::daxpy(n, a, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn ddot(
+    n: usize,
+    x: *const f64,
+    incx: isize,
+    y: *const f64,
+    incy: isize,
+) -> Result<f64, BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    Ok(crate::ddot(n, x, incx, y, incy))
+}
+
+pub unsafe fn dnrm2(n: usize, x: *const f64, incx: isize) -> Result<f64, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::dnrm2(n, x, incx))
+}
+
+pub unsafe fn dasum(n: usize, x: *const f64, incx: isize) -> Result<f64, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::dasum(n, x, incx))
+}
+
+pub unsafe fn idamax(n: usize, x: *const f64, incx: isize) -> Result<usize, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::idamax(n, x, incx))
+}
+
+pub unsafe fn idamin(n: usize, x: *const f64, incx: isize) -> Result<usize, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::idamin(n, x, incx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+
+    #[test]
+    fn zero_increments_report_their_position() {
+        unsafe {
+            let param = [-1.0; 5];
+
+            assert_eq!(
+                drot(1, null_mut(), 0, null_mut(), 1, 1.0, 0.0),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                drot(1, null_mut(), 1, null_mut(), 0, 1.0, 0.0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                drotm(1, null_mut(), 0, null_mut(), 1, &param),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                drotm(1, null_mut(), 1, null_mut(), 0, &param),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                dswap(1, null_mut(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                dswap(1, null_mut(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                dscal(1, 2.0, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(4))
+            );
+            assert_eq!(
+                dcopy(1, null(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                dcopy(1, null(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                daxpy(1, 2.0, null(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(4))
+            );
+            assert_eq!(
+                daxpy(1, 2.0, null(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(6))
+            );
+            assert_eq!(
+                ddot(1, null(), 0, null(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                ddot(1, null(), 1, null(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(dnrm2(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(dasum(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(idamax(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(idamin(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+        }
+    }
+
+    #[test]
+    fn valid_arguments_run_the_routine() {
+        let x = [3.0f64, -4.0];
+        let mut y = [1.0f64, 1.0];
+
+        unsafe {
+            assert_eq!(daxpy(2, 2.0, x.as_ptr(), 1, y.as_mut_ptr(), -1), Ok(()));
+            assert_eq!(y, [-7.0, 7.0]);
+            assert_eq!(ddot(2, x.as_ptr(), 1, y.as_ptr(), 1), Ok(-49.0));
+            assert_eq!(dnrm2(2, x.as_ptr(), 1), Ok(5.0));
+            assert_eq!(idamax(2, x.as_ptr(), 1), Ok(1));
+        }
+    }
+}
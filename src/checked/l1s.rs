@@ -0,0 +1,204 @@
+use super::check_inc;
+use crate::error::BlasError;
+
+pub unsafe fn srot(
+    n: usize,
+    x: *mut f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+    c: f32,
+    s: f32,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::srot(n, x, incx, y, incy, c, s);
+    Ok(())
+}
+
+pub unsafe fn srotm(
+    n: usize,
+    x: *mut f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+    param: &[f32; 5],
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::srotm(n, x, incx, y, incy, param);
+    Ok(())
+}
+
+pub unsafe fn sswap(
+    n: usize,
+    x: *mut f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::sswap(n, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn sscal(n: usize, a: f32, x: *mut f32, incx: isize) -> Result<(), BlasError> {
+    check_inc(4, incx)?;
+
+    crate::sscal(n, a, x, incx);
+    Ok(())
+}
+
+pub unsafe fn scopy(
+    n: usize,
+    x: *const f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    crate::scopy(n, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn saxpy(
+    n: usize,
+    a: f32,
+    x: *const f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_inc(4, incx)?;
+    check_inc(6, incy)?;
+
+    crate::saxpy(n, a, x, incx, y, incy);
+    Ok(())
+}
+
+pub unsafe fn sdot(
+    n: usize,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+) -> Result<f32, BlasError> {
+    check_inc(3, incx)?;
+    check_inc(5, incy)?;
+
+    Ok(crate::sdot(n, x, incx, y, incy))
+}
+
+pub unsafe fn snrm2(n: usize, x: *const f32, incx: isize) -> Result<f32, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::snrm2(n, x, incx))
+}
+
+pub unsafe fn sasum(n: usize, x: *const f32, incx: isize) -> Result<f32, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::sasum(n, x, incx))
+}
+
+pub unsafe fn isamax(n: usize, x: *const f32, incx: isize) -> Result<usize, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::isamax(n, x, incx))
+}
+
+pub unsafe fn isamin(n: usize, x: *const f32, incx: isize) -> Result<usize, BlasError> {
+    check_inc(3, incx)?;
+
+    Ok(crate::isamin(n, x, incx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+
+    #[test]
+    fn zero_increments_report_their_position() {
+        unsafe {
+            let param = [-1.0; 5];
+
+            assert_eq!(
+                srot(1, null_mut(), 0, null_mut(), 1, 1.0, 0.0),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                srot(1, null_mut(), 1, null_mut(), 0, 1.0, 0.0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                srotm(1, null_mut(), 0, null_mut(), 1, &param),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                srotm(1, null_mut(), 1, null_mut(), 0, &param),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                sswap(1, null_mut(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                sswap(1, null_mut(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                sscal(1, 2.0, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(4))
+            );
+            assert_eq!(
+                scopy(1, null(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                scopy(1, null(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(
+                saxpy(1, 2.0, null(), 0, null_mut(), 1),
+                Err(BlasError::ZeroIncrement(4))
+            );
+            assert_eq!(
+                saxpy(1, 2.0, null(), 1, null_mut(), 0),
+                Err(BlasError::ZeroIncrement(6))
+            );
+            assert_eq!(
+                sdot(1, null(), 0, null(), 1),
+                Err(BlasError::ZeroIncrement(3))
+            );
+            assert_eq!(
+                sdot(1, null(), 1, null(), 0),
+                Err(BlasError::ZeroIncrement(5))
+            );
+            assert_eq!(snrm2(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(sasum(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(isamax(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+            assert_eq!(isamin(1, null(), 0), Err(BlasError::ZeroIncrement(3)));
+        }
+    }
+
+    #[test]
+    fn valid_arguments_run_the_routine() {
+        let x = [3.0f32, -4.0];
+        let mut y = [1.0f32, 1.0];
+
+        unsafe {
+            assert_eq!(saxpy(2, 2.0, x.as_ptr(), 1, y.as_mut_ptr(), -1), Ok(()));
+            assert_eq!(y, [-7.0, 7.0]);
+            assert_eq!(sdot(2, x.as_ptr(), 1, y.as_ptr(), 1), Ok(-49.0));
+            assert_eq!(snrm2(2, x.as_ptr(), 1), Ok(5.0));
+            assert_eq!(isamax(2, x.as_ptr(), 1), Ok(1));
+        }
+    }
+}
@@ -0,0 +1,788 @@
+use super::{
+    check_gbmv, check_gemv, check_ger, check_sbmv, check_spmv, check_spr, check_spr2, check_symv,
+    check_syr, check_tbmv, check_tpmv, check_trmv,
+};
+use crate::context::Context;
+use crate::error::BlasError;
+use crate::types::{Diag, Layout, Uplo};
+
+pub unsafe fn dgemv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    incx: isize,
+    beta: f64,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_gemv(layout, m, n, lda, incx, incy)?;
+
+    crate::dgemv(
+        context, layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn dger(
+    context: &Context,
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: f64,
+    x: *const f64,
+    incx: isize,
+    y: *const f64,
+    incy: isize,
+    a: *mut f64,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ger(layout, m, n, incx, incy, lda)?;
+
+    crate::dger(context, layout, m, n, alpha, x, incx, y, incy, a, lda);
+    Ok(())
+}
+
+pub unsafe fn dsyr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: *const f64,
+    incx: isize,
+    a: *mut f64,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_syr(layout, n, incx, lda)?;
+
+    crate::dsyr(context, layout, uplo, n, alpha, x, incx, a, lda);
+    Ok(())
+}
+
+pub unsafe fn dsyr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: *const f64,
+    incx: isize,
+    y: *const f64,
+    incy: isize,
+    a: *mut f64,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ger(layout, n, n, incx, incy, lda)?;
+
+    crate::dsyr2(context, layout, uplo, n, alpha, x, incx, y, incy, a, lda);
+    Ok(())
+}
+
+pub unsafe fn dtrmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f64,
+    lda: usize,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_trmv(layout, n, lda, incx)?;
+
+    crate::dtrmv(context, layout, uplo, trans, diag, n, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dtrsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f64,
+    lda: usize,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_trmv(layout, n, lda, incx)?;
+
+    crate::dtrsv(context, layout, uplo, trans, diag, n, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dsymv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    incx: isize,
+    beta: f64,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_symv(layout, n, lda, incx, incy)?;
+
+    crate::dsymv(
+        context, layout, uplo, n, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn dgbmv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    incx: isize,
+    beta: f64,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_gbmv(kl, ku, lda, incx, incy)?;
+
+    crate::dgbmv(
+        context, layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn dsbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    x: *const f64,
+    incx: isize,
+    beta: f64,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_sbmv(k, lda, incx, incy)?;
+
+    crate::dsbmv(
+        context, layout, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn dtbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f64,
+    lda: usize,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tbmv(k, lda, incx)?;
+
+    crate::dtbmv(context, layout, uplo, trans, diag, n, k, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dtbsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f64,
+    lda: usize,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tbmv(k, lda, incx)?;
+
+    crate::dtbsv(context, layout, uplo, trans, diag, n, k, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dspmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: isize,
+    beta: f64,
+    y: *mut f64,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_spmv(incx, incy)?;
+
+    crate::dspmv(context, layout, uplo, n, alpha, ap, x, incx, beta, y, incy);
+    Ok(())
+}
+
+pub unsafe fn dtpmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f64,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tpmv(incx)?;
+
+    crate::dtpmv(context, layout, uplo, trans, diag, n, ap, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dtpsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f64,
+    x: *mut f64,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tpmv(incx)?;
+
+    crate::dtpsv(context, layout, uplo, trans, diag, n, ap, x, incx);
+    Ok(())
+}
+
+pub unsafe fn dspr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: *const f64,
+    incx: isize,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    check_spr(incx)?;
+
+    crate::dspr(context, layout, uplo, n, alpha, x, incx, ap);
+    Ok(())
+}
+
+pub unsafe fn dspr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: *const f64,
+    incx: isize,
+    y: *const f64,
+    incy: isize,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    check_spr2(incx, incy)?;
+
+    crate::dspr2(context, layout, uplo, n, alpha, x, incx, y, incy, ap);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+    use BlasError::{LeadingDimensionTooSmall, ZeroIncrement};
+
+    const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+
+    // Smallest leading dimension of a 2 x 3 matrix.
+    fn min_ld(layout: Layout) -> usize {
+        match layout {
+            Layout::ColMajor => 2,
+            Layout::RowMajor => 3,
+        }
+    }
+
+    #[test]
+    fn gemv_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let ld = min_ld(layout);
+
+            for &trans in &[false, true] {
+                let gemv = |lda, incx, incy| unsafe {
+                    dgemv(
+                        &context,
+                        layout,
+                        trans,
+                        2,
+                        3,
+                        1.0,
+                        null(),
+                        lda,
+                        null(),
+                        incx,
+                        0.0,
+                        null_mut(),
+                        incy,
+                    )
+                };
+
+                assert_eq!(gemv(ld - 1, 1, 1), Err(LeadingDimensionTooSmall(7)));
+                assert_eq!(gemv(ld, 0, 1), Err(ZeroIncrement(9)));
+                assert_eq!(gemv(ld, 1, 0), Err(ZeroIncrement(12)));
+                assert_eq!(gemv(ld - 1, 0, 0), Err(LeadingDimensionTooSmall(7)));
+            }
+
+            let a = [1.0f64; 6];
+            let x = [1.0f64; 3];
+            let mut y = [0.0f64; 2];
+
+            unsafe {
+                assert_eq!(
+                    dgemv(
+                        &context,
+                        layout,
+                        false,
+                        2,
+                        3,
+                        1.0,
+                        a.as_ptr(),
+                        ld,
+                        x.as_ptr(),
+                        1,
+                        0.0,
+                        y.as_mut_ptr(),
+                        1,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(y, [3.0, 3.0]);
+        }
+    }
+
+    #[test]
+    fn ger_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let ld = min_ld(layout);
+            let ger = |incx, incy, lda| unsafe {
+                dger(
+                    &context,
+                    layout,
+                    2,
+                    3,
+                    1.0,
+                    null(),
+                    incx,
+                    null(),
+                    incy,
+                    null_mut(),
+                    lda,
+                )
+            };
+
+            assert_eq!(ger(0, 1, ld), Err(ZeroIncrement(6)));
+            assert_eq!(ger(1, 0, ld), Err(ZeroIncrement(8)));
+            assert_eq!(ger(1, 1, ld - 1), Err(LeadingDimensionTooSmall(10)));
+            assert_eq!(ger(0, 0, ld - 1), Err(ZeroIncrement(6)));
+
+            let x = [1.0f64; 2];
+            let y = [1.0f64; 3];
+            let mut a = [0.0f64; 6];
+
+            unsafe {
+                assert_eq!(
+                    dger(
+                        &context,
+                        layout,
+                        2,
+                        3,
+                        1.0,
+                        x.as_ptr(),
+                        1,
+                        y.as_ptr(),
+                        1,
+                        a.as_mut_ptr(),
+                        ld,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(a, [1.0; 6]);
+        }
+    }
+
+    #[test]
+    fn syr_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                let syr = |incx, lda| unsafe {
+                    dsyr(
+                        &context,
+                        layout,
+                        uplo,
+                        2,
+                        1.0,
+                        null(),
+                        incx,
+                        null_mut(),
+                        lda,
+                    )
+                };
+
+                assert_eq!(syr(0, 2), Err(ZeroIncrement(6)));
+                assert_eq!(syr(1, 1), Err(LeadingDimensionTooSmall(8)));
+
+                let syr2 = |incx, incy, lda| unsafe {
+                    dsyr2(
+                        &context,
+                        layout,
+                        uplo,
+                        2,
+                        1.0,
+                        null(),
+                        incx,
+                        null(),
+                        incy,
+                        null_mut(),
+                        lda,
+                    )
+                };
+
+                assert_eq!(syr2(0, 1, 2), Err(ZeroIncrement(6)));
+                assert_eq!(syr2(1, 0, 2), Err(ZeroIncrement(8)));
+                assert_eq!(syr2(1, 1, 1), Err(LeadingDimensionTooSmall(10)));
+            }
+        }
+    }
+
+    #[test]
+    fn triangular_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let trmv = |lda, incx| unsafe {
+                dtrmv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    false,
+                    Diag::NonUnit,
+                    2,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(trmv(1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(trmv(2, 0), Err(ZeroIncrement(9)));
+
+            let trsv = |lda, incx| unsafe {
+                dtrsv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    true,
+                    Diag::Unit,
+                    2,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(trsv(1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(trsv(2, 0), Err(ZeroIncrement(9)));
+        }
+    }
+
+    #[test]
+    fn symv_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let symv = |lda, incx, incy| unsafe {
+                dsymv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    2,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(symv(1, 1, 1), Err(LeadingDimensionTooSmall(6)));
+            assert_eq!(symv(2, 0, 1), Err(ZeroIncrement(8)));
+            assert_eq!(symv(2, 1, 0), Err(ZeroIncrement(11)));
+        }
+    }
+
+    #[test]
+    fn band_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let gbmv = |lda, incx, incy| unsafe {
+                dgbmv(
+                    &context,
+                    layout,
+                    false,
+                    3,
+                    3,
+                    1,
+                    1,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(gbmv(2, 1, 1), Err(LeadingDimensionTooSmall(9)));
+            assert_eq!(gbmv(3, 0, 1), Err(ZeroIncrement(11)));
+            assert_eq!(gbmv(3, 1, 0), Err(ZeroIncrement(14)));
+
+            let sbmv = |lda, incx, incy| unsafe {
+                dsbmv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    3,
+                    1,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(sbmv(1, 1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(sbmv(2, 0, 1), Err(ZeroIncrement(9)));
+            assert_eq!(sbmv(2, 1, 0), Err(ZeroIncrement(12)));
+
+            let tbmv = |lda, incx| unsafe {
+                dtbmv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    false,
+                    Diag::NonUnit,
+                    3,
+                    1,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(tbmv(1, 1), Err(LeadingDimensionTooSmall(8)));
+            assert_eq!(tbmv(2, 0), Err(ZeroIncrement(10)));
+
+            let tbsv = |lda, incx| unsafe {
+                dtbsv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    true,
+                    Diag::Unit,
+                    3,
+                    1,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(tbsv(1, 1), Err(LeadingDimensionTooSmall(8)));
+            assert_eq!(tbsv(2, 0), Err(ZeroIncrement(10)));
+
+            // The band of a 3 x 3 matrix with one diagonal on each side fits
+            // in three rows (column-major) or columns (row-major).
+            let a = [1.0f64; 9];
+            let x = [1.0f64; 3];
+            let mut y = [0.0f64; 3];
+
+            unsafe {
+                assert_eq!(
+                    dgbmv(
+                        &context,
+                        layout,
+                        false,
+                        3,
+                        3,
+                        1,
+                        1,
+                        1.0,
+                        a.as_ptr(),
+                        3,
+                        x.as_ptr(),
+                        1,
+                        0.0,
+                        y.as_mut_ptr(),
+                        1,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(y, [2.0, 3.0, 2.0]);
+        }
+    }
+
+    #[test]
+    fn packed_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            unsafe {
+                assert_eq!(
+                    dspmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        2,
+                        1.0,
+                        null(),
+                        null(),
+                        0,
+                        0.0,
+                        null_mut(),
+                        1,
+                    ),
+                    Err(ZeroIncrement(7))
+                );
+                assert_eq!(
+                    dspmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        2,
+                        1.0,
+                        null(),
+                        null(),
+                        1,
+                        0.0,
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(10))
+                );
+                assert_eq!(
+                    dtpmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        false,
+                        Diag::NonUnit,
+                        2,
+                        null(),
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+                assert_eq!(
+                    dtpsv(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        true,
+                        Diag::Unit,
+                        2,
+                        null(),
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+                assert_eq!(
+                    dspr(&context, layout, Uplo::Upper, 2, 1.0, null(), 0, null_mut(),),
+                    Err(ZeroIncrement(6))
+                );
+                assert_eq!(
+                    dspr2(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        2,
+                        1.0,
+                        null(),
+                        0,
+                        null(),
+                        1,
+                        null_mut(),
+                    ),
+                    Err(ZeroIncrement(6))
+                );
+                assert_eq!(
+                    dspr2(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        2,
+                        1.0,
+                        null(),
+                        1,
+                        null(),
+                        0,
+                        null_mut(),
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,788 @@
+use super::{
+    check_gbmv, check_gemv, check_ger, check_sbmv, check_spmv, check_spr, check_spr2, check_symv,
+    check_syr, check_tbmv, check_tpmv, check_trmv,
+};
+use crate::context::Context;
+use crate::error::BlasError;
+use crate::types::{Diag, Layout, Uplo};
+
+pub unsafe fn sgemv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_gemv(layout, m, n, lda, incx, incy)?;
+
+    crate::sgemv(
+        context, layout, trans, m, n, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn sger(
+    context: &Context,
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ger(layout, m, n, incx, incy, lda)?;
+
+    crate::sger(context, layout, m, n, alpha, x, incx, y, incy, a, lda);
+    Ok(())
+}
+
+pub unsafe fn ssyr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    a: *mut f32,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_syr(layout, n, incx, lda)?;
+
+    crate::ssyr(context, layout, uplo, n, alpha, x, incx, a, lda);
+    Ok(())
+}
+
+pub unsafe fn ssyr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_ger(layout, n, n, incx, incy, lda)?;
+
+    crate::ssyr2(context, layout, uplo, n, alpha, x, incx, y, incy, a, lda);
+    Ok(())
+}
+
+pub unsafe fn strmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_trmv(layout, n, lda, incx)?;
+
+    crate::strmv(context, layout, uplo, trans, diag, n, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn strsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_trmv(layout, n, lda, incx)?;
+
+    crate::strsv(context, layout, uplo, trans, diag, n, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn ssymv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_symv(layout, n, lda, incx, incy)?;
+
+    crate::ssymv(
+        context, layout, uplo, n, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn sgbmv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_gbmv(kl, ku, lda, incx, incy)?;
+
+    crate::sgbmv(
+        context, layout, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn ssbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_sbmv(k, lda, incx, incy)?;
+
+    crate::ssbmv(
+        context, layout, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy,
+    );
+    Ok(())
+}
+
+pub unsafe fn stbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tbmv(k, lda, incx)?;
+
+    crate::stbmv(context, layout, uplo, trans, diag, n, k, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn stbsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tbmv(k, lda, incx)?;
+
+    crate::stbsv(context, layout, uplo, trans, diag, n, k, a, lda, x, incx);
+    Ok(())
+}
+
+pub unsafe fn sspmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_spmv(incx, incy)?;
+
+    crate::sspmv(context, layout, uplo, n, alpha, ap, x, incx, beta, y, incy);
+    Ok(())
+}
+
+pub unsafe fn stpmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f32,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tpmv(incx)?;
+
+    crate::stpmv(context, layout, uplo, trans, diag, n, ap, x, incx);
+    Ok(())
+}
+
+pub unsafe fn stpsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f32,
+    x: *mut f32,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_tpmv(incx)?;
+
+    crate::stpsv(context, layout, uplo, trans, diag, n, ap, x, incx);
+    Ok(())
+}
+
+pub unsafe fn sspr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    check_spr(incx)?;
+
+    crate::sspr(context, layout, uplo, n, alpha, x, incx, ap);
+    Ok(())
+}
+
+pub unsafe fn sspr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    check_spr2(incx, incy)?;
+
+    crate::sspr2(context, layout, uplo, n, alpha, x, incx, y, incy, ap);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+    use BlasError::{LeadingDimensionTooSmall, ZeroIncrement};
+
+    const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+
+    // Smallest leading dimension of a 2 x 3 matrix.
+    fn min_ld(layout: Layout) -> usize {
+        match layout {
+            Layout::ColMajor => 2,
+            Layout::RowMajor => 3,
+        }
+    }
+
+    #[test]
+    fn gemv_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let ld = min_ld(layout);
+
+            for &trans in &[false, true] {
+                let gemv = |lda, incx, incy| unsafe {
+                    sgemv(
+                        &context,
+                        layout,
+                        trans,
+                        2,
+                        3,
+                        1.0,
+                        null(),
+                        lda,
+                        null(),
+                        incx,
+                        0.0,
+                        null_mut(),
+                        incy,
+                    )
+                };
+
+                assert_eq!(gemv(ld - 1, 1, 1), Err(LeadingDimensionTooSmall(7)));
+                assert_eq!(gemv(ld, 0, 1), Err(ZeroIncrement(9)));
+                assert_eq!(gemv(ld, 1, 0), Err(ZeroIncrement(12)));
+                assert_eq!(gemv(ld - 1, 0, 0), Err(LeadingDimensionTooSmall(7)));
+            }
+
+            let a = [1.0f32; 6];
+            let x = [1.0f32; 3];
+            let mut y = [0.0f32; 2];
+
+            unsafe {
+                assert_eq!(
+                    sgemv(
+                        &context,
+                        layout,
+                        false,
+                        2,
+                        3,
+                        1.0,
+                        a.as_ptr(),
+                        ld,
+                        x.as_ptr(),
+                        1,
+                        0.0,
+                        y.as_mut_ptr(),
+                        1,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(y, [3.0, 3.0]);
+        }
+    }
+
+    #[test]
+    fn ger_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let ld = min_ld(layout);
+            let ger = |incx, incy, lda| unsafe {
+                sger(
+                    &context,
+                    layout,
+                    2,
+                    3,
+                    1.0,
+                    null(),
+                    incx,
+                    null(),
+                    incy,
+                    null_mut(),
+                    lda,
+                )
+            };
+
+            assert_eq!(ger(0, 1, ld), Err(ZeroIncrement(6)));
+            assert_eq!(ger(1, 0, ld), Err(ZeroIncrement(8)));
+            assert_eq!(ger(1, 1, ld - 1), Err(LeadingDimensionTooSmall(10)));
+            assert_eq!(ger(0, 0, ld - 1), Err(ZeroIncrement(6)));
+
+            let x = [1.0f32; 2];
+            let y = [1.0f32; 3];
+            let mut a = [0.0f32; 6];
+
+            unsafe {
+                assert_eq!(
+                    sger(
+                        &context,
+                        layout,
+                        2,
+                        3,
+                        1.0,
+                        x.as_ptr(),
+                        1,
+                        y.as_ptr(),
+                        1,
+                        a.as_mut_ptr(),
+                        ld,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(a, [1.0; 6]);
+        }
+    }
+
+    #[test]
+    fn syr_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                let syr = |incx, lda| unsafe {
+                    ssyr(
+                        &context,
+                        layout,
+                        uplo,
+                        2,
+                        1.0,
+                        null(),
+                        incx,
+                        null_mut(),
+                        lda,
+                    )
+                };
+
+                assert_eq!(syr(0, 2), Err(ZeroIncrement(6)));
+                assert_eq!(syr(1, 1), Err(LeadingDimensionTooSmall(8)));
+
+                let syr2 = |incx, incy, lda| unsafe {
+                    ssyr2(
+                        &context,
+                        layout,
+                        uplo,
+                        2,
+                        1.0,
+                        null(),
+                        incx,
+                        null(),
+                        incy,
+                        null_mut(),
+                        lda,
+                    )
+                };
+
+                assert_eq!(syr2(0, 1, 2), Err(ZeroIncrement(6)));
+                assert_eq!(syr2(1, 0, 2), Err(ZeroIncrement(8)));
+                assert_eq!(syr2(1, 1, 1), Err(LeadingDimensionTooSmall(10)));
+            }
+        }
+    }
+
+    #[test]
+    fn triangular_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let trmv = |lda, incx| unsafe {
+                strmv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    false,
+                    Diag::NonUnit,
+                    2,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(trmv(1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(trmv(2, 0), Err(ZeroIncrement(9)));
+
+            let trsv = |lda, incx| unsafe {
+                strsv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    true,
+                    Diag::Unit,
+                    2,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(trsv(1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(trsv(2, 0), Err(ZeroIncrement(9)));
+        }
+    }
+
+    #[test]
+    fn symv_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let symv = |lda, incx, incy| unsafe {
+                ssymv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    2,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(symv(1, 1, 1), Err(LeadingDimensionTooSmall(6)));
+            assert_eq!(symv(2, 0, 1), Err(ZeroIncrement(8)));
+            assert_eq!(symv(2, 1, 0), Err(ZeroIncrement(11)));
+        }
+    }
+
+    #[test]
+    fn band_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            let gbmv = |lda, incx, incy| unsafe {
+                sgbmv(
+                    &context,
+                    layout,
+                    false,
+                    3,
+                    3,
+                    1,
+                    1,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(gbmv(2, 1, 1), Err(LeadingDimensionTooSmall(9)));
+            assert_eq!(gbmv(3, 0, 1), Err(ZeroIncrement(11)));
+            assert_eq!(gbmv(3, 1, 0), Err(ZeroIncrement(14)));
+
+            let sbmv = |lda, incx, incy| unsafe {
+                ssbmv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    3,
+                    1,
+                    1.0,
+                    null(),
+                    lda,
+                    null(),
+                    incx,
+                    0.0,
+                    null_mut(),
+                    incy,
+                )
+            };
+
+            assert_eq!(sbmv(1, 1, 1), Err(LeadingDimensionTooSmall(7)));
+            assert_eq!(sbmv(2, 0, 1), Err(ZeroIncrement(9)));
+            assert_eq!(sbmv(2, 1, 0), Err(ZeroIncrement(12)));
+
+            let tbmv = |lda, incx| unsafe {
+                stbmv(
+                    &context,
+                    layout,
+                    Uplo::Upper,
+                    false,
+                    Diag::NonUnit,
+                    3,
+                    1,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(tbmv(1, 1), Err(LeadingDimensionTooSmall(8)));
+            assert_eq!(tbmv(2, 0), Err(ZeroIncrement(10)));
+
+            let tbsv = |lda, incx| unsafe {
+                stbsv(
+                    &context,
+                    layout,
+                    Uplo::Lower,
+                    true,
+                    Diag::Unit,
+                    3,
+                    1,
+                    null(),
+                    lda,
+                    null_mut(),
+                    incx,
+                )
+            };
+
+            assert_eq!(tbsv(1, 1), Err(LeadingDimensionTooSmall(8)));
+            assert_eq!(tbsv(2, 0), Err(ZeroIncrement(10)));
+
+            // The band of a 3 x 3 matrix with one diagonal on each side fits
+            // in three rows (column-major) or columns (row-major).
+            let a = [1.0f32; 9];
+            let x = [1.0f32; 3];
+            let mut y = [0.0f32; 3];
+
+            unsafe {
+                assert_eq!(
+                    sgbmv(
+                        &context,
+                        layout,
+                        false,
+                        3,
+                        3,
+                        1,
+                        1,
+                        1.0,
+                        a.as_ptr(),
+                        3,
+                        x.as_ptr(),
+                        1,
+                        0.0,
+                        y.as_mut_ptr(),
+                        1,
+                    ),
+                    Ok(())
+                );
+            }
+            assert_eq!(y, [2.0, 3.0, 2.0]);
+        }
+    }
+
+    #[test]
+    fn packed_positions() {
+        let context = Context::new();
+
+        for &layout in &LAYOUTS {
+            unsafe {
+                assert_eq!(
+                    sspmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        2,
+                        1.0,
+                        null(),
+                        null(),
+                        0,
+                        0.0,
+                        null_mut(),
+                        1,
+                    ),
+                    Err(ZeroIncrement(7))
+                );
+                assert_eq!(
+                    sspmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        2,
+                        1.0,
+                        null(),
+                        null(),
+                        1,
+                        0.0,
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(10))
+                );
+                assert_eq!(
+                    stpmv(
+                        &context,
+                        layout,
+                        Uplo::Upper,
+                        false,
+                        Diag::NonUnit,
+                        2,
+                        null(),
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+                assert_eq!(
+                    stpsv(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        true,
+                        Diag::Unit,
+                        2,
+                        null(),
+                        null_mut(),
+                        0,
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+                assert_eq!(
+                    sspr(&context, layout, Uplo::Upper, 2, 1.0, null(), 0, null_mut(),),
+                    Err(ZeroIncrement(6))
+                );
+                assert_eq!(
+                    sspr2(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        2,
+                        1.0,
+                        null(),
+                        0,
+                        null(),
+                        1,
+                        null_mut(),
+                    ),
+                    Err(ZeroIncrement(6))
+                );
+                assert_eq!(
+                    sspr2(
+                        &context,
+                        layout,
+                        Uplo::Lower,
+                        2,
+                        1.0,
+                        null(),
+                        1,
+                        null(),
+                        0,
+                        null_mut(),
+                    ),
+                    Err(ZeroIncrement(8))
+                );
+            }
+        }
+    }
+}
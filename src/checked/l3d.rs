@@ -0,0 +1,112 @@
+use super::check_gemm;
+use crate::context::Context;
+use crate::error::BlasError;
+use crate::types::Layout;
+
+pub unsafe fn dgemm(
+    context: &Context,
+    layout: Layout,
+    transa: bool,
+    transb: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: *const f64,
+    lda: usize,
+    b: *const f64,
+    ldb: usize,
+    beta: f64,
+    c: *mut f64,
+    ldc: usize,
+) -> Result<(), BlasError> {
+    check_gemm(layout, transa, transb, m, n, k, lda, ldb, ldc)?;
+
+    crate::dgemm(
+        context, layout, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr::{null, null_mut};
+    use BlasError::LeadingDimensionTooSmall;
+
+    // Smallest leading dimension of a rows x cols matrix stored as `trans`
+    // says.
+    fn min_ld(layout: Layout, trans: bool, rows: usize, cols: usize) -> usize {
+        match (layout, trans) {
+            (Layout::ColMajor, false) | (Layout::RowMajor, true) => rows,
+            (Layout::ColMajor, true) | (Layout::RowMajor, false) => cols,
+        }
+    }
+
+    #[test]
+    fn gemm_positions() {
+        let context = Context::new();
+        let (m, n, k) = (2, 3, 4);
+
+        for &layout in &[Layout::ColMajor, Layout::RowMajor] {
+            for &transa in &[false, true] {
+                for &transb in &[false, true] {
+                    let lda = min_ld(layout, transa, m, k);
+                    let ldb = min_ld(layout, transb, k, n);
+                    let ldc = min_ld(layout, false, m, n);
+                    let gemm = |lda, ldb, ldc| unsafe {
+                        dgemm(
+                            &context,
+                            layout,
+                            transa,
+                            transb,
+                            m,
+                            n,
+                            k,
+                            1.0,
+                            null(),
+                            lda,
+                            null(),
+                            ldb,
+                            0.0,
+                            null_mut(),
+                            ldc,
+                        )
+                    };
+
+                    assert_eq!(gemm(lda - 1, ldb, ldc), Err(LeadingDimensionTooSmall(9)));
+                    assert_eq!(gemm(lda, ldb - 1, ldc), Err(LeadingDimensionTooSmall(11)));
+                    assert_eq!(gemm(lda, ldb, ldc - 1), Err(LeadingDimensionTooSmall(14)));
+
+                    let a = [1.0f64; 8];
+                    let b = [1.0f64; 12];
+                    let mut c = [0.0f64; 6];
+
+                    unsafe {
+                        assert_eq!(
+                            dgemm(
+                                &context,
+                                layout,
+                                transa,
+                                transb,
+                                m,
+                                n,
+                                k,
+                                1.0,
+                                a.as_ptr(),
+                                lda,
+                                b.as_ptr(),
+                                ldb,
+                                0.0,
+                                c.as_mut_ptr(),
+                                ldc,
+                            ),
+                            Ok(())
+                        );
+                    }
+                    assert_eq!(c, [4.0; 6]);
+                }
+            }
+        }
+    }
+}
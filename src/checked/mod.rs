@@ -0,0 +1,193 @@
+//! Raw-pointer routines that validate their scalar arguments first.
+//!
+//! Each routine has the signature of the one with the same name at the crate
+//! root, but returns a [`BlasError`] instead of running when a leading
+//! dimension is too small or an increment is zero. Pointers and the memory
+//! behind them are not checked.
+
+mod l1d;
+mod l1s;
+mod l2d;
+mod l2s;
+mod l3d;
+mod l3s;
+
+pub use l1d::*;
+pub use l1s::*;
+pub use l2d::*;
+pub use l2s::*;
+pub use l3d::*;
+pub use l3s::*;
+
+use crate::error::BlasError;
+use crate::types::Layout;
+
+fn check_inc(position: usize, inc: isize) -> Result<(), BlasError> {
+    if inc == 0 {
+        return Err(BlasError::ZeroIncrement(position));
+    }
+
+    Ok(())
+}
+
+fn check_ld(
+    position: usize,
+    layout: Layout,
+    rows: usize,
+    cols: usize,
+    ld: usize,
+) -> Result<(), BlasError> {
+    let inner = match layout {
+        Layout::ColMajor => rows,
+        Layout::RowMajor => cols,
+    };
+
+    if ld < inner.max(1) {
+        return Err(BlasError::LeadingDimensionTooSmall(position));
+    }
+
+    Ok(())
+}
+
+// A band with `diagonals` diagonals off the main one needs one row
+// (column-major) or column (row-major) of the array per diagonal.
+fn check_band(position: usize, diagonals: usize, ld: usize) -> Result<(), BlasError> {
+    if ld <= diagonals {
+        return Err(BlasError::LeadingDimensionTooSmall(position));
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_gemv(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    lda: usize,
+    incx: isize,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_ld(7, layout, m, n, lda)?;
+    check_inc(9, incx)?;
+    check_inc(12, incy)
+}
+
+// Also checks the arguments of ssyr2 and dsyr2 with m = n, which sit at the
+// same positions.
+pub(crate) fn check_ger(
+    layout: Layout,
+    m: usize,
+    n: usize,
+    incx: isize,
+    incy: isize,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_inc(6, incx)?;
+    check_inc(8, incy)?;
+    check_ld(10, layout, m, n, lda)
+}
+
+pub(crate) fn check_syr(
+    layout: Layout,
+    n: usize,
+    incx: isize,
+    lda: usize,
+) -> Result<(), BlasError> {
+    check_inc(6, incx)?;
+    check_ld(8, layout, n, n, lda)
+}
+
+// Also checks the arguments of the triangular solves, which sit at the same
+// positions.
+pub(crate) fn check_trmv(
+    layout: Layout,
+    n: usize,
+    lda: usize,
+    incx: isize,
+) -> Result<(), BlasError> {
+    check_ld(7, layout, n, n, lda)?;
+    check_inc(9, incx)
+}
+
+pub(crate) fn check_symv(
+    layout: Layout,
+    n: usize,
+    lda: usize,
+    incx: isize,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_ld(6, layout, n, n, lda)?;
+    check_inc(8, incx)?;
+    check_inc(11, incy)
+}
+
+pub(crate) fn check_gbmv(
+    kl: usize,
+    ku: usize,
+    lda: usize,
+    incx: isize,
+    incy: isize,
+) -> Result<(), BlasError> {
+    check_band(9, kl.saturating_add(ku), lda)?;
+    check_inc(11, incx)?;
+    check_inc(14, incy)
+}
+
+pub(crate) fn check_sbmv(k: usize, lda: usize, incx: isize, incy: isize) -> Result<(), BlasError> {
+    check_band(7, k, lda)?;
+    check_inc(9, incx)?;
+    check_inc(12, incy)
+}
+
+// Also checks the arguments of the band solves, which sit at the same
+// positions.
+pub(crate) fn check_tbmv(k: usize, lda: usize, incx: isize) -> Result<(), BlasError> {
+    check_band(8, k, lda)?;
+    check_inc(10, incx)
+}
+
+pub(crate) fn check_spmv(incx: isize, incy: isize) -> Result<(), BlasError> {
+    check_inc(7, incx)?;
+    check_inc(10, incy)
+}
+
+// Also checks the arguments of the packed solves, which sit at the same
+// positions.
+pub(crate) fn check_tpmv(incx: isize) -> Result<(), BlasError> {
+    check_inc(8, incx)
+}
+
+pub(crate) fn check_spr(incx: isize) -> Result<(), BlasError> {
+    check_inc(6, incx)
+}
+
+pub(crate) fn check_spr2(incx: isize, incy: isize) -> Result<(), BlasError> {
+    check_inc(6, incx)?;
+    check_inc(8, incy)
+}
+
+pub(crate) fn check_gemm(
+    layout: Layout,
+    transa: bool,
+    transb: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    lda: usize,
+    ldb: usize,
+    ldc: usize,
+) -> Result<(), BlasError> {
+    if transa {
+        check_ld(9, layout, k, m, lda)?;
+    } else {
+        check_ld(9, layout, m, k, lda)?;
+    }
+
+    if transb {
+        check_ld(11, layout, n, k, ldb)?;
+    } else {
+        check_ld(11, layout, k, n, ldb)?;
+    }
+
+    check_ld(14, layout, m, n, ldc)
+}
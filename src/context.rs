@@ -1,50 +1,123 @@
 use crate::aligned_alloc::Alloc;
+use crate::cache::{cache_sizes, CacheSizes};
+use crate::kernels::{kernels, GemmKernels, Kernels};
+use std::error::Error;
+use std::fmt;
 use std::mem;
+use std::sync::{mpsc, Mutex};
 use threadpool::ThreadPool;
 
+// Number of matrix elements below which level 2 routines stay on one thread.
+const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Block sizes, packing buffers and thread pool used by the level-3 routines.
+///
+/// A `Context` is `Send` and `Sync` and may be shared between threads. Every
+/// call checks out its own packing buffers for its whole duration, so
+/// concurrent calls through the same context never share scratch memory. The
+/// context keeps as many buffer sets as it has seen concurrent calls and reuses
+/// them afterwards. Concurrent calls also share the thread pool, so they run
+/// correctly but compete for the same worker threads.
 pub struct Context {
+    kernels: &'static Kernels,
     f32_blocksizes: BlockSizes,
     f64_blocksizes: BlockSizes,
     thread_pool: ThreadPool,
-    pa: Alloc,
-    pb: Alloc,
+    pa_size: usize,
+    pb_size: usize,
+    buffers: Mutex<Vec<Buffers>>,
 }
 
+#[derive(Clone, Copy)]
 struct BlockSizes {
     mc: usize,
     kc: usize,
     nc: usize,
 }
 
+impl BlockSizes {
+    fn detect<T>(kernels: &GemmKernels<T>, default_mc: usize) -> BlockSizes {
+        BlockSizes::for_caches(kernels, default_mc, cache_sizes())
+    }
+
+    // Goto-style blocking: a packed kc x nr micro-panel of B fills half of L1,
+    // the packed mc x kc block of A half of L2 and the packed kc x nc panel of
+    // B half of L3. The other halves are left for the operands streamed
+    // through each level. Without cache information the sizes fall back to
+    // mc = `default_mc`, kc = 512 and nc = 4096.
+    fn for_caches<T>(
+        kernels: &GemmKernels<T>,
+        default_mc: usize,
+        cache: Option<CacheSizes>,
+    ) -> BlockSizes {
+        let size = mem::size_of::<T>();
+        let (mr, nr) = (kernels.mr, kernels.nr);
+
+        let cache = match cache {
+            Some(cache) => cache,
+            None => {
+                return BlockSizes {
+                    mc: default_mc,
+                    kc: 512,
+                    nc: 4096 / nr * nr,
+                }
+            }
+        };
+
+        // Taller blocks and wider panels stop paying off long before a large
+        // L2 or L3 is filled, and only grow the buffers every concurrent call
+        // checks out. Some virtual machines also report oversized caches.
+        let kc = (cache.l1 / 2 / (nr * size) / 8 * 8).clamp(64, 512);
+        let mc = (cache.l2 / 2 / (kc * size)).clamp(mr, 2048) / mr * mr;
+        let nc = cache
+            .l3
+            .map_or(4096, |l3| l3 / 2 / (kc * size))
+            .clamp(nr, 8192)
+            / nr
+            * nr;
+
+        BlockSizes { mc, kc, nc }
+    }
+}
+
+struct Buffers {
+    pa: Alloc,
+    pb: Alloc,
+}
+
+pub(crate) struct PackBuffers<'a> {
+    context: &'a Context,
+    buffers: Option<Buffers>,
+}
+
 impl Context {
     pub fn new() -> Context {
-        let f32_blocksizes = BlockSizes {
-            mc: 512,
-            kc: 512,
-            nc: 4096,
-        };
+        ContextBuilder::new()
+            .build()
+            .expect("default context configuration is valid")
+    }
 
-        let f64_blocksizes = BlockSizes {
-            mc: f32_blocksizes.mc / 2,
-            kc: f32_blocksizes.kc,
-            nc: f32_blocksizes.nc,
-        };
+    fn alloc_buffers(&self) -> Buffers {
+        let pa = Alloc::new(self.pa_size);
 
-        let thread_pool = threadpool::Builder::new().build();
+        let pb = Alloc::new(self.pb_size);
 
-        let pa = Alloc::new(f64_blocksizes.mc * f64_blocksizes.kc * mem::size_of::<f64>());
+        Buffers { pa, pb }
+    }
 
-        let pb = Alloc::new(f64_blocksizes.kc * f64_blocksizes.nc * mem::size_of::<f64>());
+    pub(crate) fn buffers(&self) -> PackBuffers<'_> {
+        let buffers = self.buffers.lock().unwrap().pop();
 
-        Context {
-            f32_blocksizes,
-            f64_blocksizes,
-            thread_pool,
-            pa,
-            pb,
+        PackBuffers {
+            context: self,
+            buffers: Some(buffers.unwrap_or_else(|| self.alloc_buffers())),
         }
     }
 
+    pub(crate) fn kernels(&self) -> &'static Kernels {
+        self.kernels
+    }
+
     pub(crate) fn smc(&self) -> usize {
         self.f32_blocksizes.mc
     }
@@ -69,22 +142,6 @@ impl Context {
         self.f64_blocksizes.nc
     }
 
-    pub(crate) fn spa(&self) -> *mut f32 {
-        self.pa.ptr() as *mut f32
-    }
-
-    pub(crate) fn spb(&self) -> *mut f32 {
-        self.pb.ptr() as *mut f32
-    }
-
-    pub(crate) fn dpa(&self) -> *mut f64 {
-        self.pa.ptr() as *mut f64
-    }
-
-    pub(crate) fn dpb(&self) -> *mut f64 {
-        self.pb.ptr() as *mut f64
-    }
-
     pub(crate) fn execute<F: FnOnce(usize) + Send + 'static + Copy>(
         &self,
         start: usize,
@@ -104,13 +161,22 @@ impl Context {
 
         let mut prev_end = 0;
 
+        // Every job holds a sender and nothing is ever sent, so `recv` returns
+        // once all jobs of this call have finished, or unwound. `join` would
+        // wait for the whole pool, and returns early when several callers
+        // join the same pool at once.
+        let (done, finished) = mpsc::channel::<()>();
+
         for _ in 0..thread_count {
             let mut now_end = prev_end + job_size;
             if left_steps > 0 {
                 now_end += 1;
                 left_steps -= 1;
             }
+            let done = done.clone();
             self.thread_pool.execute(move || {
+                let _done = done;
+
                 for j in prev_end..now_end {
                     f(start + j * step);
                 }
@@ -118,6 +184,366 @@ impl Context {
             prev_end = now_end
         }
 
-        self.thread_pool.join();
+        drop(done);
+        let _ = finished.recv();
+    }
+
+    // Runs `f` like `execute`, but on the calling thread when the call touches
+    // too few matrix elements to pay for waking the pool.
+    pub(crate) fn execute_sized<F: FnOnce(usize) + Send + 'static + Copy>(
+        &self,
+        elements: usize,
+        start: usize,
+        end: usize,
+        step: usize,
+        f: F,
+    ) {
+        if elements < PARALLEL_THRESHOLD {
+            for i in (start..end).step_by(step) {
+                f(i);
+            }
+        } else {
+            self.execute(start, end, step, f);
+        }
+    }
+
+    // Runs `column` for every j in 0..n of a triangular matrix. Column j runs
+    // in the same job as column n - 1 - j so that every job touches about the
+    // same number of elements.
+    pub(crate) fn execute_columns<F: FnOnce(usize) + Send + 'static + Copy>(
+        &self,
+        elements: usize,
+        n: usize,
+        column: F,
+    ) {
+        self.execute_sized(elements, 0, n.div_ceil(2), 1, move |j| {
+            column(j);
+
+            if n - 1 - j != j {
+                column(n - 1 - j);
+            }
+        });
+    }
+}
+
+impl<'a> PackBuffers<'a> {
+    fn get(&self) -> &Buffers {
+        self.buffers.as_ref().unwrap()
+    }
+
+    pub(crate) fn spa(&self) -> *mut f32 {
+        self.get().pa.ptr() as *mut f32
+    }
+
+    pub(crate) fn spb(&self) -> *mut f32 {
+        self.get().pb.ptr() as *mut f32
+    }
+
+    pub(crate) fn dpa(&self) -> *mut f64 {
+        self.get().pa.ptr() as *mut f64
+    }
+
+    pub(crate) fn dpb(&self) -> *mut f64 {
+        self.get().pb.ptr() as *mut f64
+    }
+}
+
+impl<'a> Drop for PackBuffers<'a> {
+    fn drop(&mut self) {
+        if let Some(buffers) = self.buffers.take() {
+            self.context.buffers.lock().unwrap().push(buffers);
+        }
+    }
+}
+
+impl Default for Context {
+    fn default() -> Context {
+        Context::new()
+    }
+}
+
+/// Configures and creates a [`Context`].
+///
+/// Every setting is optional and defaults to the value used by
+/// [`Context::new`]. The packing buffers are allocated by [`build`] and are
+/// sized to fit the block sizes chosen for both precisions.
+///
+/// [`build`]: ContextBuilder::build
+#[derive(Default)]
+pub struct ContextBuilder {
+    num_threads: Option<usize>,
+    thread_pool: Option<ThreadPool>,
+    f32_blocksizes: Option<BlockSizes>,
+    f64_blocksizes: Option<BlockSizes>,
+    #[cfg(test)]
+    kernels: Option<&'static Kernels>,
+}
+
+impl ContextBuilder {
+    pub fn new() -> ContextBuilder {
+        ContextBuilder::default()
+    }
+
+    /// Number of worker threads of the pool created by the context.
+    pub fn num_threads(mut self, num_threads: usize) -> ContextBuilder {
+        self.num_threads = Some(num_threads);
+        self
+    }
+
+    /// Runs the level-3 routines on an existing pool instead of creating one.
+    ///
+    /// Jobs submitted to the same pool by other code compete with the routines
+    /// for its workers, and every parallel step waits for its own jobs to run.
+    ///
+    /// For this reason a routine must not be called from inside a job running
+    /// on this pool: its jobs queue behind the callers occupying the workers,
+    /// so the call may never return. Call the routines from outside the pool,
+    /// or give jobs on the pool their own context.
+    pub fn thread_pool(mut self, thread_pool: ThreadPool) -> ContextBuilder {
+        self.thread_pool = Some(thread_pool);
+        self
+    }
+
+    /// Cache block sizes used by `sgemm`.
+    pub fn f32_block_sizes(mut self, mc: usize, kc: usize, nc: usize) -> ContextBuilder {
+        self.f32_blocksizes = Some(BlockSizes { mc, kc, nc });
+        self
+    }
+
+    /// Cache block sizes used by `dgemm`.
+    pub fn f64_block_sizes(mut self, mc: usize, kc: usize, nc: usize) -> ContextBuilder {
+        self.f64_blocksizes = Some(BlockSizes { mc, kc, nc });
+        self
+    }
+
+    // Runs the context on the given kernel table instead of the detected one.
+    #[cfg(test)]
+    pub(crate) fn kernels(mut self, kernels: &'static Kernels) -> ContextBuilder {
+        self.kernels = Some(kernels);
+        self
+    }
+
+    pub fn build(self) -> Result<Context, ContextError> {
+        #[cfg(test)]
+        let kernels = self.kernels.unwrap_or_else(kernels);
+        #[cfg(not(test))]
+        let kernels = kernels();
+
+        let f32_blocksizes = self
+            .f32_blocksizes
+            .unwrap_or_else(|| BlockSizes::detect(&kernels.sgemm, 512));
+
+        let f64_blocksizes = self
+            .f64_blocksizes
+            .unwrap_or_else(|| BlockSizes::detect(&kernels.dgemm, 256));
+
+        let pa_size =
+            buffer_size::<f32>(f32_blocksizes.mc, f32_blocksizes.kc)?
+                .max(buffer_size::<f64>(f64_blocksizes.mc, f64_blocksizes.kc)?);
+
+        let pb_size =
+            buffer_size::<f32>(f32_blocksizes.kc, f32_blocksizes.nc)?
+                .max(buffer_size::<f64>(f64_blocksizes.kc, f64_blocksizes.nc)?);
+
+        let thread_pool = match (self.thread_pool, self.num_threads) {
+            (Some(_), Some(_)) => return Err(ContextError::ThreadPoolAndNumThreads),
+            (_, Some(0)) => return Err(ContextError::ZeroThreads),
+            (Some(thread_pool), None) => thread_pool,
+            (None, Some(num_threads)) => {
+                threadpool::Builder::new().num_threads(num_threads).build()
+            }
+            (None, None) => threadpool::Builder::new().build(),
+        };
+
+        // Allocating the first buffer set here reports block sizes too large
+        // for memory as an error instead of failing in the first call.
+        let buffers = Buffers {
+            pa: Alloc::try_new(pa_size).ok_or(ContextError::AllocationFailed)?,
+            pb: Alloc::try_new(pb_size).ok_or(ContextError::AllocationFailed)?,
+        };
+
+        let context = Context {
+            kernels,
+            f32_blocksizes,
+            f64_blocksizes,
+            thread_pool,
+            pa_size,
+            pb_size,
+            buffers: Mutex::new(vec![buffers]),
+        };
+
+        Ok(context)
+    }
+}
+
+fn buffer_size<T>(rows: usize, cols: usize) -> Result<usize, ContextError> {
+    if rows == 0 || cols == 0 {
+        return Err(ContextError::ZeroBlockSize);
+    }
+
+    rows.checked_mul(cols)
+        .and_then(|len| len.checked_mul(mem::size_of::<T>()))
+        .filter(|&size| size <= isize::MAX as usize)
+        .ok_or(ContextError::BlockSizeTooLarge)
+}
+
+/// Invalid [`ContextBuilder`] configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ContextError {
+    /// A block size is zero.
+    ZeroBlockSize,
+    /// The packing buffers for the block sizes do not fit in memory.
+    BlockSizeTooLarge,
+    /// The thread count is zero.
+    ZeroThreads,
+    /// Both a thread count and an existing thread pool were given.
+    ThreadPoolAndNumThreads,
+    /// The packing buffers could not be allocated.
+    AllocationFailed,
+}
+
+impl fmt::Display for ContextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            ContextError::ZeroBlockSize => "block sizes must be greater than zero",
+            ContextError::BlockSizeTooLarge => "packing buffers for the block sizes are too large",
+            ContextError::ZeroThreads => "thread count must be greater than zero",
+            ContextError::ThreadPoolAndNumThreads => {
+                "thread count cannot be set together with an existing thread pool"
+            }
+            ContextError::AllocationFailed => "packing buffers could not be allocated",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+impl Error for ContextError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernels::available;
+
+    fn caches(l1: usize, l2: usize, l3: Option<usize>) -> Option<CacheSizes> {
+        Some(CacheSizes { l1, l2, l3 })
+    }
+
+    // Checks the block sizes of both precisions of every kernel table against
+    // `check`, which gets mr, nr, the default mc and the block sizes.
+    fn check_block_sizes(
+        cache: Option<CacheSizes>,
+        check: impl Fn(usize, usize, usize, BlockSizes),
+    ) {
+        for kernels in available() {
+            let s = &kernels.sgemm;
+            let d = &kernels.dgemm;
+            check(s.mr, s.nr, 512, BlockSizes::for_caches(s, 512, cache));
+            check(d.mr, d.nr, 256, BlockSizes::for_caches(d, 256, cache));
+        }
+    }
+
+    #[test]
+    fn block_sizes_fall_back_without_cache_sizes() {
+        check_block_sizes(None, |_, nr, default_mc, sizes| {
+            assert_eq!((sizes.mc, sizes.kc), (default_mc, 512));
+            assert_eq!(sizes.nc, 4096 / nr * nr);
+        });
+    }
+
+    #[test]
+    fn block_sizes_are_clamped() {
+        let huge = caches(1 << 30, 1 << 40, Some(1 << 45));
+        check_block_sizes(huge, |mr, nr, _, sizes| {
+            assert_eq!(sizes.kc, 512);
+            assert_eq!(sizes.mc, 2048 / mr * mr);
+            assert_eq!(sizes.nc, 8192 / nr * nr);
+        });
+
+        let tiny = caches(1 << 10, 1 << 10, Some(1 << 10));
+        check_block_sizes(tiny, |mr, nr, _, sizes| {
+            assert_eq!((sizes.mc, sizes.kc, sizes.nc), (mr, 64, nr));
+        });
+    }
+
+    #[test]
+    fn block_sizes_are_multiples_of_the_tile() {
+        let cases = [
+            caches(32 << 10, 256 << 10, Some(8 << 20)),
+            caches(48 << 10, 1280 << 10, Some(30 << 20)),
+            caches(32 << 10, 512 << 10, None),
+            caches(37 << 10, 999 << 10, Some(12_345_678)),
+        ];
+
+        for cache in cases {
+            check_block_sizes(cache, |mr, nr, _, sizes| {
+                assert_eq!(sizes.mc % mr, 0, "{:?}", cache);
+                assert_eq!(sizes.nc % nr, 0, "{:?}", cache);
+                assert_eq!(sizes.kc % 8, 0, "{:?}", cache);
+                assert!(sizes.mc >= mr && sizes.nc >= nr);
+            });
+        }
+    }
+
+    #[test]
+    fn build_rejects_invalid_configurations() {
+        let cases = [
+            (
+                ContextBuilder::new().f32_block_sizes(0, 256, 4096),
+                ContextError::ZeroBlockSize,
+            ),
+            (
+                ContextBuilder::new().f64_block_sizes(256, 256, 0),
+                ContextError::ZeroBlockSize,
+            ),
+            (
+                ContextBuilder::new().f32_block_sizes(usize::MAX / 2, 2, 4096),
+                ContextError::BlockSizeTooLarge,
+            ),
+            (
+                ContextBuilder::new().f64_block_sizes(256, 1 << 30, 1 << 30),
+                ContextError::BlockSizeTooLarge,
+            ),
+            (
+                ContextBuilder::new().num_threads(0),
+                ContextError::ZeroThreads,
+            ),
+            (
+                ContextBuilder::new()
+                    .num_threads(2)
+                    .thread_pool(ThreadPool::new(2)),
+                ContextError::ThreadPoolAndNumThreads,
+            ),
+        ];
+
+        for (builder, error) in cases {
+            assert_eq!(builder.build().err(), Some(error));
+        }
+    }
+
+    // 64 PiB fits in isize but not in any address space.
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn build_reports_failed_allocations() {
+        let result = ContextBuilder::new()
+            .f32_block_sizes(1 << 27, 1 << 27, 4)
+            .build();
+
+        assert_eq!(result.err(), Some(ContextError::AllocationFailed));
+    }
+
+    #[test]
+    fn build_accepts_explicit_settings() {
+        let context = ContextBuilder::new()
+            .num_threads(2)
+            .f32_block_sizes(64, 32, 96)
+            .f64_block_sizes(32, 16, 48)
+            .build()
+            .unwrap();
+
+        assert_eq!((context.smc(), context.skc(), context.snc()), (64, 32, 96));
+        assert_eq!((context.dmc(), context.dkc(), context.dnc()), (32, 16, 48));
+        assert!(!context.buffers().spa().is_null());
+        assert!(!context.buffers().spb().is_null());
     }
 }
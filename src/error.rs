@@ -0,0 +1,58 @@
+use std::error::Error;
+use std::fmt;
+
+/// An argument rejected by a BLAS routine.
+///
+/// Each variant holds the 1-based position of the offending argument, counted
+/// as in the CBLAS signature of the routine and ignoring any [`Context`].
+///
+/// [`Context`]: crate::Context
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlasError {
+    /// A layout or transpose flag is not one of the accepted values.
+    InvalidFlag(usize),
+    /// A dimension is negative.
+    NegativeDimension(usize),
+    /// A leading dimension is smaller than the rows (column-major) or columns
+    /// (row-major) of the matrix it describes.
+    LeadingDimensionTooSmall(usize),
+    /// An increment is zero.
+    ZeroIncrement(usize),
+}
+
+impl BlasError {
+    /// Position of the offending argument.
+    pub fn position(&self) -> usize {
+        match *self {
+            BlasError::InvalidFlag(position)
+            | BlasError::NegativeDimension(position)
+            | BlasError::LeadingDimensionTooSmall(position)
+            | BlasError::ZeroIncrement(position) => position,
+        }
+    }
+
+    #[cfg(feature = "fortran")]
+    pub(crate) fn with_position(self, position: usize) -> BlasError {
+        match self {
+            BlasError::InvalidFlag(_) => BlasError::InvalidFlag(position),
+            BlasError::NegativeDimension(_) => BlasError::NegativeDimension(position),
+            BlasError::LeadingDimensionTooSmall(_) => BlasError::LeadingDimensionTooSmall(position),
+            BlasError::ZeroIncrement(_) => BlasError::ZeroIncrement(position),
+        }
+    }
+}
+
+impl fmt::Display for BlasError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self {
+            BlasError::InvalidFlag(_) => "is not a valid flag",
+            BlasError::NegativeDimension(_) => "is a negative dimension",
+            BlasError::LeadingDimensionTooSmall(_) => "is a leading dimension that is too small",
+            BlasError::ZeroIncrement(_) => "is a zero increment",
+        };
+
+        write!(f, "parameter {} {}", self.position(), reason)
+    }
+}
+
+impl Error for BlasError {}
@@ -0,0 +1,141 @@
+use crate::ffi::dim;
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drot(
+    n: c_int,
+    x: *mut f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+    c: f64,
+    s: f64,
+) {
+    if let Some(n) = dim(n) {
+        crate::drot(n, x, incx as isize, y, incy as isize, c, s);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotg(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    crate::drotg(&mut *a, &mut *b, &mut *c, &mut *s);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotmg(
+    d1: *mut f64,
+    d2: *mut f64,
+    b1: *mut f64,
+    b2: f64,
+    param: *mut f64,
+) {
+    crate::drotmg(
+        &mut *d1,
+        &mut *d2,
+        &mut *b1,
+        b2,
+        &mut *(param as *mut [f64; 5]),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_drotm(
+    n: c_int,
+    x: *mut f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+    param: *const f64,
+) {
+    if let Some(n) = dim(n) {
+        let param = &*(param as *const [f64; 5]);
+        crate::drotm(n, x, incx as isize, y, incy as isize, param);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dswap(n: c_int, x: *mut f64, incx: c_int, y: *mut f64, incy: c_int) {
+    if let Some(n) = dim(n) {
+        crate::dswap(n, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dscal(n: c_int, alpha: f64, x: *mut f64, incx: c_int) {
+    if let Some(n) = dim(n) {
+        crate::dscal(n, alpha, x, incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dcopy(
+    n: c_int,
+    x: *const f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Some(n) = dim(n) {
+        crate::dcopy(n, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_daxpy(
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Some(n) = dim(n) {
+        crate::daxpy(n, alpha, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ddot(
+    n: c_int,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+) -> f64 {
+    match dim(n) {
+        Some(n) => crate::ddot(n, x, incx as isize, y, incy as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dnrm2(n: c_int, x: *const f64, incx: c_int) -> f64 {
+    match dim(n) {
+        Some(n) => crate::dnrm2(n, x, incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dasum(n: c_int, x: *const f64, incx: c_int) -> f64 {
+    match dim(n) {
+        Some(n) => crate::dasum(n, x, incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_idamax(n: c_int, x: *const f64, incx: c_int) -> usize {
+    match dim(n) {
+        Some(n) => crate::idamax(n, x, incx as isize),
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_idamin(n: c_int, x: *const f64, incx: c_int) -> usize {
+    match dim(n) {
+        Some(n) => crate::idamin(n, x, incx as isize),
+        None => 0,
+    }
+}
@@ -0,0 +1,141 @@
+use crate::ffi::dim;
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srot(
+    n: c_int,
+    x: *mut f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+    c: f32,
+    s: f32,
+) {
+    if let Some(n) = dim(n) {
+        crate::srot(n, x, incx as isize, y, incy as isize, c, s);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotg(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    crate::srotg(&mut *a, &mut *b, &mut *c, &mut *s);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotmg(
+    d1: *mut f32,
+    d2: *mut f32,
+    b1: *mut f32,
+    b2: f32,
+    param: *mut f32,
+) {
+    crate::srotmg(
+        &mut *d1,
+        &mut *d2,
+        &mut *b1,
+        b2,
+        &mut *(param as *mut [f32; 5]),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_srotm(
+    n: c_int,
+    x: *mut f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+    param: *const f32,
+) {
+    if let Some(n) = dim(n) {
+        let param = &*(param as *const [f32; 5]);
+        crate::srotm(n, x, incx as isize, y, incy as isize, param);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sswap(n: c_int, x: *mut f32, incx: c_int, y: *mut f32, incy: c_int) {
+    if let Some(n) = dim(n) {
+        crate::sswap(n, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sscal(n: c_int, alpha: f32, x: *mut f32, incx: c_int) {
+    if let Some(n) = dim(n) {
+        crate::sscal(n, alpha, x, incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_scopy(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Some(n) = dim(n) {
+        crate::scopy(n, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_saxpy(
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Some(n) = dim(n) {
+        crate::saxpy(n, alpha, x, incx as isize, y, incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sdot(
+    n: c_int,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+) -> f32 {
+    match dim(n) {
+        Some(n) => crate::sdot(n, x, incx as isize, y, incy as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_snrm2(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    match dim(n) {
+        Some(n) => crate::snrm2(n, x, incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sasum(n: c_int, x: *const f32, incx: c_int) -> f32 {
+    match dim(n) {
+        Some(n) => crate::sasum(n, x, incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_isamax(n: c_int, x: *const f32, incx: c_int) -> usize {
+    match dim(n) {
+        Some(n) => crate::isamax(n, x, incx as isize),
+        None => 0,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_isamin(n: c_int, x: *const f32, incx: c_int) -> usize {
+    match dim(n) {
+        Some(n) => crate::isamin(n, x, incx as isize),
+        None => 0,
+    }
+}
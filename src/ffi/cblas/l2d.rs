@@ -0,0 +1,819 @@
+use super::{diag, layout, trans, uplo, CblasDiag, CblasOrder, CblasTranspose, CblasUplo};
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemv(
+    order: CblasOrder,
+    trans: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Err(error) = dgemv(order, trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_dgemv", error);
+    }
+}
+
+unsafe fn dgemv(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dgemv(
+        context(),
+        layout,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dger(
+    order: CblasOrder,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    if let Err(error) = dger(order, m, n, alpha, x, incx, y, incy, a, lda) {
+        report("cblas_dger", error);
+    }
+}
+
+unsafe fn dger(
+    order: CblasOrder,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+
+    crate::checked::dger(
+        context(),
+        layout,
+        m,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    if let Err(error) = dsyr(order, uplo, n, alpha, x, incx, a, lda) {
+        report("cblas_dsyr", error);
+    }
+}
+
+unsafe fn dsyr(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::dsyr(context(), layout, uplo, n, alpha, x, incx as isize, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsyr2(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) {
+    if let Err(error) = dsyr2(order, uplo, n, alpha, x, incx, y, incy, a, lda) {
+        report("cblas_dsyr2", error);
+    }
+}
+
+unsafe fn dsyr2(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+
+    crate::checked::dsyr2(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtrmv(order, uplo, trans, diag, n, a, lda, x, incx) {
+        report("cblas_dtrmv", error);
+    }
+}
+
+unsafe fn dtrmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dtrmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtrsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtrsv(order, uplo, trans, diag, n, a, lda, x, incx) {
+        report("cblas_dtrsv", error);
+    }
+}
+
+unsafe fn dtrsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dtrsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsymv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Err(error) = dsymv(order, uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_dsymv", error);
+    }
+}
+
+unsafe fn dsymv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::dsymv(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgbmv(
+    order: CblasOrder,
+    trans: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Err(error) = dgbmv(
+        order, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    ) {
+        report("cblas_dgbmv", error);
+    }
+}
+
+unsafe fn dgbmv(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let kl = dim(kl).ok_or(BlasError::NegativeDimension(5))?;
+    let ku = dim(ku).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::dgbmv(
+        context(),
+        layout,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dsbmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Err(error) = dsbmv(order, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_dsbmv", error);
+    }
+}
+
+unsafe fn dsbmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dsbmv(
+        context(),
+        layout,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtbmv(order, uplo, trans, diag, n, k, a, lda, x, incx) {
+        report("cblas_dtbmv", error);
+    }
+}
+
+unsafe fn dtbmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::dtbmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtbsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtbsv(order, uplo, trans, diag, n, k, a, lda, x, incx) {
+        report("cblas_dtbsv", error);
+    }
+}
+
+unsafe fn dtbsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::dtbsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) {
+    if let Err(error) = dspmv(order, uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        report("cblas_dspmv", error);
+    }
+}
+
+unsafe fn dspmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::dspmv(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtpmv(order, uplo, trans, diag, n, ap, x, incx) {
+        report("cblas_dtpmv", error);
+    }
+}
+
+unsafe fn dtpmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    crate::checked::dtpmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dtpsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) {
+    if let Err(error) = dtpsv(order, uplo, trans, diag, n, ap, x, incx) {
+        report("cblas_dtpsv", error);
+    }
+}
+
+unsafe fn dtpsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    crate::checked::dtpsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    ap: *mut f64,
+) {
+    if let Err(error) = dspr(order, uplo, n, alpha, x, incx, ap) {
+        report("cblas_dspr", error);
+    }
+}
+
+unsafe fn dspr(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::dspr(context(), layout, uplo, n, alpha, x, incx as isize, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dspr2(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    ap: *mut f64,
+) {
+    if let Err(error) = dspr2(order, uplo, n, alpha, x, incx, y, incy, ap) {
+        report("cblas_dspr2", error);
+    }
+}
+
+unsafe fn dspr2(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::dspr2(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+}
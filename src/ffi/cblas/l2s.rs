@@ -0,0 +1,819 @@
+use super::{diag, layout, trans, uplo, CblasDiag, CblasOrder, CblasTranspose, CblasUplo};
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgemv(
+    order: CblasOrder,
+    trans: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Err(error) = sgemv(order, trans, m, n, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_sgemv", error);
+    }
+}
+
+unsafe fn sgemv(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::sgemv(
+        context(),
+        layout,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sger(
+    order: CblasOrder,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    if let Err(error) = sger(order, m, n, alpha, x, incx, y, incy, a, lda) {
+        report("cblas_sger", error);
+    }
+}
+
+unsafe fn sger(
+    order: CblasOrder,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+
+    crate::checked::sger(
+        context(),
+        layout,
+        m,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    if let Err(error) = ssyr(order, uplo, n, alpha, x, incx, a, lda) {
+        report("cblas_ssyr", error);
+    }
+}
+
+unsafe fn ssyr(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::ssyr(context(), layout, uplo, n, alpha, x, incx as isize, a, lda)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssyr2(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) {
+    if let Err(error) = ssyr2(order, uplo, n, alpha, x, incx, y, incy, a, lda) {
+        report("cblas_ssyr2", error);
+    }
+}
+
+unsafe fn ssyr2(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+
+    crate::checked::ssyr2(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = strmv(order, uplo, trans, diag, n, a, lda, x, incx) {
+        report("cblas_strmv", error);
+    }
+}
+
+unsafe fn strmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::strmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_strsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = strsv(order, uplo, trans, diag, n, a, lda, x, incx) {
+        report("cblas_strsv", error);
+    }
+}
+
+unsafe fn strsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::strsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssymv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Err(error) = ssymv(order, uplo, n, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_ssymv", error);
+    }
+}
+
+unsafe fn ssymv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::ssymv(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sgbmv(
+    order: CblasOrder,
+    trans: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Err(error) = sgbmv(
+        order, trans, m, n, kl, ku, alpha, a, lda, x, incx, beta, y, incy,
+    ) {
+        report("cblas_sgbmv", error);
+    }
+}
+
+unsafe fn sgbmv(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let kl = dim(kl).ok_or(BlasError::NegativeDimension(5))?;
+    let ku = dim(ku).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::sgbmv(
+        context(),
+        layout,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_ssbmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Err(error) = ssbmv(order, uplo, n, k, alpha, a, lda, x, incx, beta, y, incy) {
+        report("cblas_ssbmv", error);
+    }
+}
+
+unsafe fn ssbmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::ssbmv(
+        context(),
+        layout,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = stbmv(order, uplo, trans, diag, n, k, a, lda, x, incx) {
+        report("cblas_stbmv", error);
+    }
+}
+
+unsafe fn stbmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::stbmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stbsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = stbsv(order, uplo, trans, diag, n, k, a, lda, x, incx) {
+        report("cblas_stbsv", error);
+    }
+}
+
+unsafe fn stbsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::stbsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) {
+    if let Err(error) = sspmv(order, uplo, n, alpha, ap, x, incx, beta, y, incy) {
+        report("cblas_sspmv", error);
+    }
+}
+
+unsafe fn sspmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::sspmv(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpmv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = stpmv(order, uplo, trans, diag, n, ap, x, incx) {
+        report("cblas_stpmv", error);
+    }
+}
+
+unsafe fn stpmv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    crate::checked::stpmv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_stpsv(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    trans: CblasTranspose,
+    diag: CblasDiag,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) {
+    if let Err(error) = stpsv(order, uplo, trans, diag, n, ap, x, incx) {
+        report("cblas_stpsv", error);
+    }
+}
+
+unsafe fn stpsv(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    transa: CblasTranspose,
+    unit: CblasDiag,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(3))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(4))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+
+    crate::checked::stpsv(
+        context(),
+        layout,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    ap: *mut f32,
+) {
+    if let Err(error) = sspr(order, uplo, n, alpha, x, incx, ap) {
+        report("cblas_sspr", error);
+    }
+}
+
+unsafe fn sspr(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::sspr(context(), layout, uplo, n, alpha, x, incx as isize, ap)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_sspr2(
+    order: CblasOrder,
+    uplo: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    ap: *mut f32,
+) {
+    if let Err(error) = sspr2(order, uplo, n, alpha, x, incx, y, incy, ap) {
+        report("cblas_sspr2", error);
+    }
+}
+
+unsafe fn sspr2(
+    order: CblasOrder,
+    triangle: CblasUplo,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(2))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    crate::checked::sspr2(
+        context(),
+        layout,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+}
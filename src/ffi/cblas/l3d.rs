@@ -0,0 +1,75 @@
+use super::{layout, trans, CblasOrder, CblasTranspose};
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn cblas_dgemm(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    transb: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) {
+    if let Err(error) = dgemm(
+        order, transa, transb, m, n, k, alpha, a, lda, b, ldb, beta, c, ldc,
+    ) {
+        report("cblas_dgemm", error);
+    }
+}
+
+unsafe fn dgemm(
+    order: CblasOrder,
+    transa: CblasTranspose,
+    transb: CblasTranspose,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let layout = layout(order).ok_or(BlasError::InvalidFlag(1))?;
+    let transa = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let transb = trans(transb).ok_or(BlasError::InvalidFlag(3))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(4))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(5))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(6))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+    let ldb = dim(ldb).ok_or(BlasError::LeadingDimensionTooSmall(11))?;
+    let ldc = dim(ldc).ok_or(BlasError::LeadingDimensionTooSmall(14))?;
+
+    crate::checked::dgemm(
+        context(),
+        layout,
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+}
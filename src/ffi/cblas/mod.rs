@@ -0,0 +1,66 @@
+//! `cblas_*` symbols with the signatures of the reference `cblas.h`.
+//!
+//! Level 1 calls with a negative size do nothing, as in the reference BLAS.
+//! Other routines report invalid arguments through the
+//! [`ErrorHandler`](crate::ErrorHandler) and return.
+
+mod l1d;
+mod l1s;
+mod l2d;
+mod l2s;
+mod l3d;
+mod l3s;
+
+use crate::types::{Diag, Layout, Uplo};
+use std::os::raw::c_int;
+
+type CblasOrder = c_int;
+type CblasTranspose = c_int;
+type CblasUplo = c_int;
+type CblasDiag = c_int;
+
+const CBLAS_ROW_MAJOR: CblasOrder = 101;
+const CBLAS_COL_MAJOR: CblasOrder = 102;
+
+const CBLAS_NO_TRANS: CblasTranspose = 111;
+const CBLAS_TRANS: CblasTranspose = 112;
+const CBLAS_CONJ_TRANS: CblasTranspose = 113;
+
+const CBLAS_UPPER: CblasUplo = 121;
+const CBLAS_LOWER: CblasUplo = 122;
+
+const CBLAS_NON_UNIT: CblasDiag = 131;
+const CBLAS_UNIT: CblasDiag = 132;
+
+fn layout(order: CblasOrder) -> Option<Layout> {
+    match order {
+        CBLAS_ROW_MAJOR => Some(Layout::RowMajor),
+        CBLAS_COL_MAJOR => Some(Layout::ColMajor),
+        _ => None,
+    }
+}
+
+// The conjugate transpose of a real matrix is its transpose.
+fn trans(trans: CblasTranspose) -> Option<bool> {
+    match trans {
+        CBLAS_NO_TRANS => Some(false),
+        CBLAS_TRANS | CBLAS_CONJ_TRANS => Some(true),
+        _ => None,
+    }
+}
+
+fn uplo(uplo: CblasUplo) -> Option<Uplo> {
+    match uplo {
+        CBLAS_UPPER => Some(Uplo::Upper),
+        CBLAS_LOWER => Some(Uplo::Lower),
+        _ => None,
+    }
+}
+
+fn diag(diag: CblasDiag) -> Option<Diag> {
+    match diag {
+        CBLAS_NON_UNIT => Some(Diag::NonUnit),
+        CBLAS_UNIT => Some(Diag::Unit),
+        _ => None,
+    }
+}
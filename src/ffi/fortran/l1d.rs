@@ -0,0 +1,154 @@
+use crate::ffi::dim;
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn drot_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+    c: *const f64,
+    s: *const f64,
+) {
+    if let Some(n) = dim(*n) {
+        crate::drot(n, x, *incx as isize, y, *incy as isize, *c, *s);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotg_(a: *mut f64, b: *mut f64, c: *mut f64, s: *mut f64) {
+    crate::drotg(&mut *a, &mut *b, &mut *c, &mut *s);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotmg_(
+    d1: *mut f64,
+    d2: *mut f64,
+    x1: *mut f64,
+    y1: *const f64,
+    param: *mut f64,
+) {
+    crate::drotmg(
+        &mut *d1,
+        &mut *d2,
+        &mut *x1,
+        *y1,
+        &mut *(param as *mut [f64; 5]),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn drotm_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+    param: *const f64,
+) {
+    if let Some(n) = dim(*n) {
+        let param = &*(param as *const [f64; 5]);
+        crate::drotm(n, x, *incx as isize, y, *incy as isize, param);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dswap_(
+    n: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::dswap(n, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dscal_(
+    n: *const c_int,
+    alpha: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::dscal(n, *alpha, x, *incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dcopy_(
+    n: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::dcopy(n, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn daxpy_(
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::daxpy(n, *alpha, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ddot_(
+    n: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+) -> f64 {
+    match dim(*n) {
+        Some(n) => crate::ddot(n, x, *incx as isize, y, *incy as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dnrm2_(n: *const c_int, x: *const f64, incx: *const c_int) -> f64 {
+    match dim(*n) {
+        Some(n) => crate::dnrm2(n, x, *incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dasum_(n: *const c_int, x: *const f64, incx: *const c_int) -> f64 {
+    match dim(*n) {
+        Some(n) => crate::dasum(n, x, *incx as isize),
+        None => 0.,
+    }
+}
+
+// Fortran indices are one-based, with zero left for an empty vector.
+#[no_mangle]
+pub unsafe extern "C" fn idamax_(n: *const c_int, x: *const f64, incx: *const c_int) -> c_int {
+    match dim(*n) {
+        Some(n) if n > 0 && *incx > 0 => crate::idamax(n, x, *incx as isize) as c_int + 1,
+        _ => 0,
+    }
+}
+
+// Fortran indices are one-based, with zero left for an empty vector.
+#[no_mangle]
+pub unsafe extern "C" fn idamin_(n: *const c_int, x: *const f64, incx: *const c_int) -> c_int {
+    match dim(*n) {
+        Some(n) if n > 0 && *incx > 0 => crate::idamin(n, x, *incx as isize) as c_int + 1,
+        _ => 0,
+    }
+}
@@ -0,0 +1,154 @@
+use crate::ffi::dim;
+use std::os::raw::c_int;
+
+#[no_mangle]
+pub unsafe extern "C" fn srot_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+    c: *const f32,
+    s: *const f32,
+) {
+    if let Some(n) = dim(*n) {
+        crate::srot(n, x, *incx as isize, y, *incy as isize, *c, *s);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotg_(a: *mut f32, b: *mut f32, c: *mut f32, s: *mut f32) {
+    crate::srotg(&mut *a, &mut *b, &mut *c, &mut *s);
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotmg_(
+    d1: *mut f32,
+    d2: *mut f32,
+    x1: *mut f32,
+    y1: *const f32,
+    param: *mut f32,
+) {
+    crate::srotmg(
+        &mut *d1,
+        &mut *d2,
+        &mut *x1,
+        *y1,
+        &mut *(param as *mut [f32; 5]),
+    );
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn srotm_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+    param: *const f32,
+) {
+    if let Some(n) = dim(*n) {
+        let param = &*(param as *const [f32; 5]);
+        crate::srotm(n, x, *incx as isize, y, *incy as isize, param);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sswap_(
+    n: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::sswap(n, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sscal_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::sscal(n, *alpha, x, *incx as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn scopy_(
+    n: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::scopy(n, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn saxpy_(
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Some(n) = dim(*n) {
+        crate::saxpy(n, *alpha, x, *incx as isize, y, *incy as isize);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sdot_(
+    n: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+) -> f32 {
+    match dim(*n) {
+        Some(n) => crate::sdot(n, x, *incx as isize, y, *incy as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn snrm2_(n: *const c_int, x: *const f32, incx: *const c_int) -> f32 {
+    match dim(*n) {
+        Some(n) => crate::snrm2(n, x, *incx as isize),
+        None => 0.,
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sasum_(n: *const c_int, x: *const f32, incx: *const c_int) -> f32 {
+    match dim(*n) {
+        Some(n) => crate::sasum(n, x, *incx as isize),
+        None => 0.,
+    }
+}
+
+// Fortran indices are one-based, with zero left for an empty vector.
+#[no_mangle]
+pub unsafe extern "C" fn isamax_(n: *const c_int, x: *const f32, incx: *const c_int) -> c_int {
+    match dim(*n) {
+        Some(n) if n > 0 && *incx > 0 => crate::isamax(n, x, *incx as isize) as c_int + 1,
+        _ => 0,
+    }
+}
+
+// Fortran indices are one-based, with zero left for an empty vector.
+#[no_mangle]
+pub unsafe extern "C" fn isamin_(n: *const c_int, x: *const f32, incx: *const c_int) -> c_int {
+    match dim(*n) {
+        Some(n) if n > 0 && *incx > 0 => crate::isamin(n, x, *incx as isize) as c_int + 1,
+        _ => 0,
+    }
+}
@@ -0,0 +1,807 @@
+use super::{diag, trans, uplo};
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use crate::types::Layout;
+use std::os::raw::{c_char, c_int};
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Err(error) = dgemv(*trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("DGEMV", error);
+    }
+}
+
+unsafe fn dgemv(
+    transa: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    // The checked routine counts the layout argument this interface lacks.
+    crate::checked::dgemv(
+        context(),
+        Layout::ColMajor,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dger_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    if let Err(error) = dger(*m, *n, *alpha, x, *incx, y, *incy, a, *lda) {
+        report("DGER", error);
+    }
+}
+
+unsafe fn dger(
+    m: c_int,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let m = dim(m).ok_or(BlasError::NegativeDimension(1))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::dger(
+        context(),
+        Layout::ColMajor,
+        m,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    if let Err(error) = dsyr(*uplo, *n, *alpha, x, *incx, a, *lda) {
+        report("DSYR", error);
+    }
+}
+
+unsafe fn dsyr(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dsyr(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    a: *mut f64,
+    lda: *const c_int,
+) {
+    if let Err(error) = dsyr2(*uplo, *n, *alpha, x, *incx, y, *incy, a, *lda) {
+        report("DSYR2", error);
+    }
+}
+
+unsafe fn dsyr2(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    a: *mut f64,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::dsyr2(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtrmv(*uplo, *trans, *diag, *n, a, *lda, x, *incx) {
+        report("DTRMV", error);
+    }
+}
+
+unsafe fn dtrmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::dtrmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtrsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtrsv(*uplo, *trans, *diag, *n, a, *lda, x, *incx) {
+        report("DTRSV", error);
+    }
+}
+
+unsafe fn dtrsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::dtrsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Err(error) = dsymv(*uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("DSYMV", error);
+    }
+}
+
+unsafe fn dsymv(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(5))?;
+
+    crate::checked::dsymv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Err(error) = dgbmv(
+        *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ) {
+        report("DGBMV", error);
+    }
+}
+
+unsafe fn dgbmv(
+    transa: c_char,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+    let kl = dim(kl).ok_or(BlasError::NegativeDimension(4))?;
+    let ku = dim(ku).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::dgbmv(
+        context(),
+        Layout::ColMajor,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dsbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Err(error) = dsbmv(*uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("DSBMV", error);
+    }
+}
+
+unsafe fn dsbmv(
+    triangle: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::dsbmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtbmv(*uplo, *trans, *diag, *n, *k, a, *lda, x, *incx) {
+        report("DTBMV", error);
+    }
+}
+
+unsafe fn dtbmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dtbmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f64,
+    lda: *const c_int,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtbsv(*uplo, *trans, *diag, *n, *k, a, *lda, x, *incx) {
+        report("DTBSV", error);
+    }
+}
+
+unsafe fn dtbsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const f64,
+    lda: c_int,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::dtbsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    beta: *const f64,
+    y: *mut f64,
+    incy: *const c_int,
+) {
+    if let Err(error) = dspmv(*uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy) {
+        report("DSPMV", error);
+    }
+}
+
+unsafe fn dspmv(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    ap: *const f64,
+    x: *const f64,
+    incx: c_int,
+    beta: f64,
+    y: *mut f64,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::dspmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtpmv(*uplo, *trans, *diag, *n, ap, x, *incx) {
+        report("DTPMV", error);
+    }
+}
+
+unsafe fn dtpmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    crate::checked::dtpmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dtpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: *const c_int,
+) {
+    if let Err(error) = dtpsv(*uplo, *trans, *diag, *n, ap, x, *incx) {
+        report("DTPSV", error);
+    }
+}
+
+unsafe fn dtpsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    ap: *const f64,
+    x: *mut f64,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    crate::checked::dtpsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    ap: *mut f64,
+) {
+    if let Err(error) = dspr(*uplo, *n, *alpha, x, *incx, ap) {
+        report("DSPR", error);
+    }
+}
+
+unsafe fn dspr(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::dspr(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        ap,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn dspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f64,
+    x: *const f64,
+    incx: *const c_int,
+    y: *const f64,
+    incy: *const c_int,
+    ap: *mut f64,
+) {
+    if let Err(error) = dspr2(*uplo, *n, *alpha, x, *incx, y, *incy, ap) {
+        report("DSPR2", error);
+    }
+}
+
+unsafe fn dspr2(
+    triangle: c_char,
+    n: c_int,
+    alpha: f64,
+    x: *const f64,
+    incx: c_int,
+    y: *const f64,
+    incy: c_int,
+    ap: *mut f64,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::dspr2(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
@@ -0,0 +1,807 @@
+use super::{diag, trans, uplo};
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use crate::types::Layout;
+use std::os::raw::{c_char, c_int};
+
+#[no_mangle]
+pub unsafe extern "C" fn sgemv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Err(error) = sgemv(*trans, *m, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("SGEMV", error);
+    }
+}
+
+unsafe fn sgemv(
+    transa: c_char,
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    // The checked routine counts the layout argument this interface lacks.
+    crate::checked::sgemv(
+        context(),
+        Layout::ColMajor,
+        trans,
+        m,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sger_(
+    m: *const c_int,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    if let Err(error) = sger(*m, *n, *alpha, x, *incx, y, *incy, a, *lda) {
+        report("SGER", error);
+    }
+}
+
+unsafe fn sger(
+    m: c_int,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let m = dim(m).ok_or(BlasError::NegativeDimension(1))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::sger(
+        context(),
+        Layout::ColMajor,
+        m,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    if let Err(error) = ssyr(*uplo, *n, *alpha, x, *incx, a, *lda) {
+        report("SSYR", error);
+    }
+}
+
+unsafe fn ssyr(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::ssyr(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssyr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    a: *mut f32,
+    lda: *const c_int,
+) {
+    if let Err(error) = ssyr2(*uplo, *n, *alpha, x, *incx, y, *incy, a, *lda) {
+        report("SSYR2", error);
+    }
+}
+
+unsafe fn ssyr2(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    a: *mut f32,
+    lda: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(9))?;
+
+    crate::checked::ssyr2(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        a,
+        lda,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = strmv(*uplo, *trans, *diag, *n, a, *lda, x, *incx) {
+        report("STRMV", error);
+    }
+}
+
+unsafe fn strmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::strmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = strsv(*uplo, *trans, *diag, *n, a, *lda, x, *incx) {
+        report("STRSV", error);
+    }
+}
+
+unsafe fn strsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::strsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssymv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Err(error) = ssymv(*uplo, *n, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("SSYMV", error);
+    }
+}
+
+unsafe fn ssymv(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(5))?;
+
+    crate::checked::ssymv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sgbmv_(
+    trans: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    kl: *const c_int,
+    ku: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Err(error) = sgbmv(
+        *trans, *m, *n, *kl, *ku, *alpha, a, *lda, x, *incx, *beta, y, *incy,
+    ) {
+        report("SGBMV", error);
+    }
+}
+
+unsafe fn sgbmv(
+    transa: c_char,
+    m: c_int,
+    n: c_int,
+    kl: c_int,
+    ku: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(2))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(3))?;
+    let kl = dim(kl).ok_or(BlasError::NegativeDimension(4))?;
+    let ku = dim(ku).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+
+    crate::checked::sgbmv(
+        context(),
+        Layout::ColMajor,
+        trans,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn ssbmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Err(error) = ssbmv(*uplo, *n, *k, *alpha, a, *lda, x, *incx, *beta, y, *incy) {
+        report("SSBMV", error);
+    }
+}
+
+unsafe fn ssbmv(
+    triangle: c_char,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(3))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(6))?;
+
+    crate::checked::ssbmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stbmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = stbmv(*uplo, *trans, *diag, *n, *k, a, *lda, x, *incx) {
+        report("STBMV", error);
+    }
+}
+
+unsafe fn stbmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::stbmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stbsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    k: *const c_int,
+    a: *const f32,
+    lda: *const c_int,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = stbsv(*uplo, *trans, *diag, *n, *k, a, *lda, x, *incx) {
+        report("STBSV", error);
+    }
+}
+
+unsafe fn stbsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    k: c_int,
+    a: *const f32,
+    lda: c_int,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(7))?;
+
+    crate::checked::stbsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        k,
+        a,
+        lda,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspmv_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    beta: *const f32,
+    y: *mut f32,
+    incy: *const c_int,
+) {
+    if let Err(error) = sspmv(*uplo, *n, *alpha, ap, x, *incx, *beta, y, *incy) {
+        report("SSPMV", error);
+    }
+}
+
+unsafe fn sspmv(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: c_int,
+    beta: f32,
+    y: *mut f32,
+    incy: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::sspmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        ap,
+        x,
+        incx as isize,
+        beta,
+        y,
+        incy as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stpmv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = stpmv(*uplo, *trans, *diag, *n, ap, x, *incx) {
+        report("STPMV", error);
+    }
+}
+
+unsafe fn stpmv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    crate::checked::stpmv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn stpsv_(
+    uplo: *const c_char,
+    trans: *const c_char,
+    diag: *const c_char,
+    n: *const c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: *const c_int,
+) {
+    if let Err(error) = stpsv(*uplo, *trans, *diag, *n, ap, x, *incx) {
+        report("STPSV", error);
+    }
+}
+
+unsafe fn stpsv(
+    triangle: c_char,
+    transa: c_char,
+    unit: c_char,
+    n: c_int,
+    ap: *const f32,
+    x: *mut f32,
+    incx: c_int,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+    let trans = trans(transa).ok_or(BlasError::InvalidFlag(2))?;
+    let diag = diag(unit).ok_or(BlasError::InvalidFlag(3))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+
+    crate::checked::stpsv(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        trans,
+        diag,
+        n,
+        ap,
+        x,
+        incx as isize,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspr_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    ap: *mut f32,
+) {
+    if let Err(error) = sspr(*uplo, *n, *alpha, x, *incx, ap) {
+        report("SSPR", error);
+    }
+}
+
+unsafe fn sspr(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::sspr(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        ap,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn sspr2_(
+    uplo: *const c_char,
+    n: *const c_int,
+    alpha: *const f32,
+    x: *const f32,
+    incx: *const c_int,
+    y: *const f32,
+    incy: *const c_int,
+    ap: *mut f32,
+) {
+    if let Err(error) = sspr2(*uplo, *n, *alpha, x, *incx, y, *incy, ap) {
+        report("SSPR2", error);
+    }
+}
+
+unsafe fn sspr2(
+    triangle: c_char,
+    n: c_int,
+    alpha: f32,
+    x: *const f32,
+    incx: c_int,
+    y: *const f32,
+    incy: c_int,
+    ap: *mut f32,
+) -> Result<(), BlasError> {
+    let uplo = uplo(triangle).ok_or(BlasError::InvalidFlag(1))?;
+
+    let n = dim(n).ok_or(BlasError::NegativeDimension(2))?;
+
+    crate::checked::sspr2(
+        context(),
+        Layout::ColMajor,
+        uplo,
+        n,
+        alpha,
+        x,
+        incx as isize,
+        y,
+        incy as isize,
+        ap,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
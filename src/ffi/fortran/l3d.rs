@@ -0,0 +1,75 @@
+use super::trans;
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use crate::types::Layout;
+use std::os::raw::{c_char, c_int};
+
+#[no_mangle]
+pub unsafe extern "C" fn dgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f64,
+    a: *const f64,
+    lda: *const c_int,
+    b: *const f64,
+    ldb: *const c_int,
+    beta: *const f64,
+    c: *mut f64,
+    ldc: *const c_int,
+) {
+    if let Err(error) = dgemm(
+        *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ) {
+        report("DGEMM", error);
+    }
+}
+
+unsafe fn dgemm(
+    transa: c_char,
+    transb: c_char,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f64,
+    a: *const f64,
+    lda: c_int,
+    b: *const f64,
+    ldb: c_int,
+    beta: f64,
+    c: *mut f64,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let transa = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+    let transb = trans(transb).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+    let ldb = dim(ldb).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+    let ldc = dim(ldc).ok_or(BlasError::LeadingDimensionTooSmall(13))?;
+
+    // The checked routine counts the layout argument this interface lacks.
+    crate::checked::dgemm(
+        context(),
+        Layout::ColMajor,
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
@@ -0,0 +1,75 @@
+use super::trans;
+use crate::error::BlasError;
+use crate::ffi::{context, dim, report};
+use crate::types::Layout;
+use std::os::raw::{c_char, c_int};
+
+#[no_mangle]
+pub unsafe extern "C" fn sgemm_(
+    transa: *const c_char,
+    transb: *const c_char,
+    m: *const c_int,
+    n: *const c_int,
+    k: *const c_int,
+    alpha: *const f32,
+    a: *const f32,
+    lda: *const c_int,
+    b: *const f32,
+    ldb: *const c_int,
+    beta: *const f32,
+    c: *mut f32,
+    ldc: *const c_int,
+) {
+    if let Err(error) = sgemm(
+        *transa, *transb, *m, *n, *k, *alpha, a, *lda, b, *ldb, *beta, c, *ldc,
+    ) {
+        report("SGEMM", error);
+    }
+}
+
+unsafe fn sgemm(
+    transa: c_char,
+    transb: c_char,
+    m: c_int,
+    n: c_int,
+    k: c_int,
+    alpha: f32,
+    a: *const f32,
+    lda: c_int,
+    b: *const f32,
+    ldb: c_int,
+    beta: f32,
+    c: *mut f32,
+    ldc: c_int,
+) -> Result<(), BlasError> {
+    let transa = trans(transa).ok_or(BlasError::InvalidFlag(1))?;
+    let transb = trans(transb).ok_or(BlasError::InvalidFlag(2))?;
+
+    let m = dim(m).ok_or(BlasError::NegativeDimension(3))?;
+    let n = dim(n).ok_or(BlasError::NegativeDimension(4))?;
+    let k = dim(k).ok_or(BlasError::NegativeDimension(5))?;
+
+    let lda = dim(lda).ok_or(BlasError::LeadingDimensionTooSmall(8))?;
+    let ldb = dim(ldb).ok_or(BlasError::LeadingDimensionTooSmall(10))?;
+    let ldc = dim(ldc).ok_or(BlasError::LeadingDimensionTooSmall(13))?;
+
+    // The checked routine counts the layout argument this interface lacks.
+    crate::checked::sgemm(
+        context(),
+        Layout::ColMajor,
+        transa,
+        transb,
+        m,
+        n,
+        k,
+        alpha,
+        a,
+        lda,
+        b,
+        ldb,
+        beta,
+        c,
+        ldc,
+    )
+    .map_err(|error| error.with_position(error.position() - 1))
+}
@@ -0,0 +1,42 @@
+//! Fortran 77 BLAS symbols (`sgemm_`, `daxpy_`, ...) as emitted by gfortran.
+//!
+//! Every argument is passed by reference, and the hidden length arguments
+//! that follow character arguments are ignored. Real functions return their
+//! value directly, following the gfortran convention rather than f2c.
+//! Level 1 calls with a negative size do nothing, as in the reference BLAS.
+//! Other routines report invalid arguments through the
+//! [`ErrorHandler`](crate::ErrorHandler) and return.
+
+mod l1d;
+mod l1s;
+mod l2d;
+mod l2s;
+mod l3d;
+mod l3s;
+
+use crate::types::{Diag, Uplo};
+use std::os::raw::c_char;
+
+fn trans(trans: c_char) -> Option<bool> {
+    match trans as u8 {
+        b'N' | b'n' => Some(false),
+        b'T' | b't' | b'C' | b'c' => Some(true),
+        _ => None,
+    }
+}
+
+fn uplo(uplo: c_char) -> Option<Uplo> {
+    match uplo as u8 {
+        b'U' | b'u' => Some(Uplo::Upper),
+        b'L' | b'l' => Some(Uplo::Lower),
+        _ => None,
+    }
+}
+
+fn diag(diag: c_char) -> Option<Diag> {
+    match diag as u8 {
+        b'N' | b'n' => Some(Diag::NonUnit),
+        b'U' | b'u' => Some(Diag::Unit),
+        _ => None,
+    }
+}
@@ -0,0 +1,53 @@
+//! Foreign entry points that let the crate be linked in place of a system
+//! BLAS. Every call runs on one process-wide [`Context`], created on first use.
+
+#[cfg(feature = "cblas")]
+mod cblas;
+#[cfg(feature = "fortran")]
+mod fortran;
+
+use crate::context::Context;
+use crate::error::BlasError;
+use std::os::raw::c_int;
+use std::sync::{OnceLock, PoisonError, RwLock};
+
+/// Called with the routine name when a foreign entry point rejects an
+/// argument, in place of the `xerbla` of a reference BLAS.
+pub type ErrorHandler = fn(routine: &str, error: BlasError);
+
+static ERROR_HANDLER: RwLock<ErrorHandler> = RwLock::new(print_error);
+
+/// Replaces the [`ErrorHandler`]. The default prints the reference BLAS
+/// message to stderr. The rejected call returns once the handler does.
+pub fn set_error_handler(handler: ErrorHandler) {
+    *ERROR_HANDLER
+        .write()
+        .unwrap_or_else(PoisonError::into_inner) = handler;
+}
+
+fn print_error(routine: &str, error: BlasError) {
+    eprintln!(
+        " ** On entry to {} parameter number {:2} had an illegal value",
+        routine,
+        error.position()
+    );
+}
+
+fn report(routine: &str, error: BlasError) {
+    let handler = *ERROR_HANDLER.read().unwrap_or_else(PoisonError::into_inner);
+    handler(routine, error);
+}
+
+fn context() -> &'static Context {
+    static CONTEXT: OnceLock<Context> = OnceLock::new();
+
+    CONTEXT.get_or_init(Context::new)
+}
+
+fn dim(n: c_int) -> Option<usize> {
+    if n < 0 {
+        None
+    } else {
+        Some(n as usize)
+    }
+}
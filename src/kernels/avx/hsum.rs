@@ -21,3 +21,43 @@ pub unsafe fn hsum_pd(v: __m256d) -> f64 {
     let h64 = _mm_unpackhi_pd(vsum, vsum);
     _mm_cvtsd_f64(_mm_add_sd(vsum, h64))
 }
+
+#[inline(always)]
+pub unsafe fn hmax_ps(v: __m256) -> f32 {
+    let qhigh = _mm256_extractf128_ps(v, 1);
+    let qlow = _mm256_castps256_ps128(v);
+    let qmax = _mm_max_ps(qhigh, qlow);
+    let dhigh = _mm_movehl_ps(qmax, qmax);
+    let dmax = _mm_max_ps(dhigh, qmax);
+    let high = _mm_shuffle_ps(dmax, dmax, 1);
+    _mm_cvtss_f32(_mm_max_ss(high, dmax))
+}
+
+#[inline(always)]
+pub unsafe fn hmin_ps(v: __m256) -> f32 {
+    let qhigh = _mm256_extractf128_ps(v, 1);
+    let qlow = _mm256_castps256_ps128(v);
+    let qmin = _mm_min_ps(qhigh, qlow);
+    let dhigh = _mm_movehl_ps(qmin, qmin);
+    let dmin = _mm_min_ps(dhigh, qmin);
+    let high = _mm_shuffle_ps(dmin, dmin, 1);
+    _mm_cvtss_f32(_mm_min_ss(high, dmin))
+}
+
+#[inline(always)]
+pub unsafe fn hmax_pd(v: __m256d) -> f64 {
+    let vhigh = _mm256_extractf128_pd(v, 1);
+    let vlow = _mm256_castpd256_pd128(v);
+    let vmax = _mm_max_pd(vlow, vhigh);
+    let h64 = _mm_unpackhi_pd(vmax, vmax);
+    _mm_cvtsd_f64(_mm_max_sd(vmax, h64))
+}
+
+#[inline(always)]
+pub unsafe fn hmin_pd(v: __m256d) -> f64 {
+    let vhigh = _mm256_extractf128_pd(v, 1);
+    let vlow = _mm256_castpd256_pd128(v);
+    let vmin = _mm_min_pd(vlow, vhigh);
+    let h64 = _mm_unpackhi_pd(vmin, vmin);
+    _mm_cvtsd_f64(_mm_min_sd(vmin, h64))
+}
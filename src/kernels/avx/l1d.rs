@@ -1,396 +1,729 @@
 use super::fma::{fmadd_pd, fmsub_pd};
-use super::hsum::hsum_pd;
+use super::hsum::{hmax_pd, hmin_pd, hsum_pd};
 use super::intrinsics::*;
-
-pub unsafe fn drot(
-    n: usize,
-    mut x: *mut f64,
-    incx: usize,
-    mut y: *mut f64,
-    incy: usize,
-    c: f64,
-    s: f64,
-) {
-    if incx == 1 && incy == 1 {
-        let c0 = _mm256_broadcast_sd(&c);
-        let s0 = _mm256_broadcast_sd(&s);
-
-        for _ in 0..n / 16 {
-            let x0 = _mm256_load_pd(x);
-            let y0 = _mm256_load_pd(y);
-            let x1 = _mm256_load_pd(x.add(4));
-            let y1 = _mm256_load_pd(y.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let y2 = _mm256_load_pd(y.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-            let y3 = _mm256_load_pd(y.add(12));
-
-            _mm256_store_pd(x, fmadd_pd(c0, x0, _mm256_mul_pd(s0, y0)));
-            _mm256_store_pd(y, fmsub_pd(c0, y0, _mm256_mul_pd(s0, x0)));
-            _mm256_store_pd(x.add(4), fmadd_pd(c0, x1, _mm256_mul_pd(s0, y1)));
-            _mm256_store_pd(y.add(4), fmsub_pd(c0, y1, _mm256_mul_pd(s0, x1)));
-            _mm256_store_pd(x.add(8), fmadd_pd(c0, x2, _mm256_mul_pd(s0, y2)));
-            _mm256_store_pd(y.add(8), fmsub_pd(c0, y2, _mm256_mul_pd(s0, x2)));
-            _mm256_store_pd(x.add(12), fmadd_pd(c0, x3, _mm256_mul_pd(s0, y3)));
-            _mm256_store_pd(y.add(12), fmsub_pd(c0, y3, _mm256_mul_pd(s0, x3)));
-
-            x = x.add(16);
-            y = y.add(16);
+use crate::kernels::generic::l1d::{
+    dnrm2_acc, dnrm2_combine, dnrm2_sumsq_ok, SBIG, SSML, TBIG, TSML,
+};
+
+kernels! {
+    pub unsafe fn drot(
+        n: usize,
+        mut x: *mut f64,
+        incx: isize,
+        mut y: *mut f64,
+        incy: isize,
+        c: f64,
+        s: f64,
+    ) {
+        if incx == 1 && incy == 1 {
+            let c0 = _mm256_broadcast_sd(&c);
+            let s0 = _mm256_broadcast_sd(&s);
+
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+
+                _mm256_storeu_pd(x, fmadd_pd(c0, x0, _mm256_mul_pd(s0, y0)));
+                _mm256_storeu_pd(y, fmsub_pd(c0, y0, _mm256_mul_pd(s0, x0)));
+                _mm256_storeu_pd(x.add(4), fmadd_pd(c0, x1, _mm256_mul_pd(s0, y1)));
+                _mm256_storeu_pd(y.add(4), fmsub_pd(c0, y1, _mm256_mul_pd(s0, x1)));
+                _mm256_storeu_pd(x.add(8), fmadd_pd(c0, x2, _mm256_mul_pd(s0, y2)));
+                _mm256_storeu_pd(y.add(8), fmsub_pd(c0, y2, _mm256_mul_pd(s0, x2)));
+                _mm256_storeu_pd(x.add(12), fmadd_pd(c0, x3, _mm256_mul_pd(s0, y3)));
+                _mm256_storeu_pd(y.add(12), fmsub_pd(c0, y3, _mm256_mul_pd(s0, x3)));
+
+                x = x.add(16);
+                y = y.add(16);
+            }
+
+            for _ in 0..n % 16 {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = c * x0 + s * y0;
+                *y = c * y0 - s * x0;
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = c * x0 + s * y0;
+                *y = c * y0 - s * x0;
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
+    }
 
-        for _ in 0..n % 16 {
-            let x0 = *x;
-            let y0 = *y;
-
-            *x = c * x0 + s * y0;
-            *y = c * y0 - s * x0;
-
-            x = x.add(1);
-            y = y.add(1);
+    pub unsafe fn drotm(
+        n: usize,
+        mut x: *mut f64,
+        incx: isize,
+        mut y: *mut f64,
+        incy: isize,
+        h11: f64,
+        h21: f64,
+        h12: f64,
+        h22: f64,
+    ) {
+        if incx == 1 && incy == 1 {
+            let h110 = _mm256_broadcast_sd(&h11);
+            let h210 = _mm256_broadcast_sd(&h21);
+            let h120 = _mm256_broadcast_sd(&h12);
+            let h220 = _mm256_broadcast_sd(&h22);
+
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+
+                _mm256_storeu_pd(x, fmadd_pd(h110, x0, _mm256_mul_pd(h120, y0)));
+                _mm256_storeu_pd(y, fmadd_pd(h210, x0, _mm256_mul_pd(h220, y0)));
+                _mm256_storeu_pd(x.add(4), fmadd_pd(h110, x1, _mm256_mul_pd(h120, y1)));
+                _mm256_storeu_pd(y.add(4), fmadd_pd(h210, x1, _mm256_mul_pd(h220, y1)));
+                _mm256_storeu_pd(x.add(8), fmadd_pd(h110, x2, _mm256_mul_pd(h120, y2)));
+                _mm256_storeu_pd(y.add(8), fmadd_pd(h210, x2, _mm256_mul_pd(h220, y2)));
+                _mm256_storeu_pd(x.add(12), fmadd_pd(h110, x3, _mm256_mul_pd(h120, y3)));
+                _mm256_storeu_pd(y.add(12), fmadd_pd(h210, x3, _mm256_mul_pd(h220, y3)));
+
+                x = x.add(16);
+                y = y.add(16);
+            }
+
+            for _ in 0..n % 16 {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = h11 * x0 + h12 * y0;
+                *y = h21 * x0 + h22 * y0;
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = h11 * x0 + h12 * y0;
+                *y = h21 * x0 + h22 * y0;
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
-    } else {
-        for _ in 0..n {
-            let x0 = *x;
-            let y0 = *y;
-
-            *x = c * x0 + s * y0;
-            *y = c * y0 - s * x0;
+    }
 
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn dswap(n: usize, mut x: *mut f64, incx: isize, mut y: *mut f64, incy: isize) {
+        if incx == 1 && incy == 1 {
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+
+                _mm256_storeu_pd(x, y0);
+                _mm256_storeu_pd(y, x0);
+                _mm256_storeu_pd(x.add(4), y1);
+                _mm256_storeu_pd(y.add(4), x1);
+                _mm256_storeu_pd(x.add(8), y2);
+                _mm256_storeu_pd(y.add(8), x2);
+                _mm256_storeu_pd(x.add(12), y3);
+                _mm256_storeu_pd(y.add(12), x3);
+
+                x = x.add(16);
+                y = y.add(16);
+            }
+
+            for _ in 0..n % 16 {
+                std::ptr::swap(x, y);
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                std::ptr::swap(x, y);
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
     }
-}
 
-pub unsafe fn dswap(n: usize, mut x: *mut f64, incx: usize, mut y: *mut f64, incy: usize) {
-    if incx == 1 && incy == 1 {
-        for _ in 0..n / 16 {
-            let x0 = _mm256_load_pd(x);
-            let y0 = _mm256_load_pd(y);
-            let x1 = _mm256_load_pd(x.add(4));
-            let y1 = _mm256_load_pd(y.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let y2 = _mm256_load_pd(y.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-            let y3 = _mm256_load_pd(y.add(12));
-
-            _mm256_store_pd(x, y0);
-            _mm256_store_pd(y, x0);
-            _mm256_store_pd(x.add(4), y1);
-            _mm256_store_pd(y.add(4), x1);
-            _mm256_store_pd(x.add(8), y2);
-            _mm256_store_pd(y.add(8), x2);
-            _mm256_store_pd(x.add(12), y3);
-            _mm256_store_pd(y.add(12), x3);
-
-            x = x.add(16);
-            y = y.add(16);
+    pub unsafe fn dscal(n: usize, a: f64, mut x: *mut f64, incx: isize) {
+        if incx == 1 {
+            let a0 = _mm256_broadcast_sd(&a);
+            for _ in 0..n / 32 {
+                let mut x0 = _mm256_loadu_pd(x);
+                let mut x1 = _mm256_loadu_pd(x.add(4));
+                let mut x2 = _mm256_loadu_pd(x.add(8));
+                let mut x3 = _mm256_loadu_pd(x.add(12));
+                let mut x4 = _mm256_loadu_pd(x.add(16));
+                let mut x5 = _mm256_loadu_pd(x.add(20));
+                let mut x6 = _mm256_loadu_pd(x.add(24));
+                let mut x7 = _mm256_loadu_pd(x.add(28));
+
+                x0 = _mm256_mul_pd(a0, x0);
+                x1 = _mm256_mul_pd(a0, x1);
+                x2 = _mm256_mul_pd(a0, x2);
+                x3 = _mm256_mul_pd(a0, x3);
+                x4 = _mm256_mul_pd(a0, x4);
+                x5 = _mm256_mul_pd(a0, x5);
+                x6 = _mm256_mul_pd(a0, x6);
+                x7 = _mm256_mul_pd(a0, x7);
+
+                _mm256_storeu_pd(x, x0);
+                _mm256_storeu_pd(x.add(4), x1);
+                _mm256_storeu_pd(x.add(8), x2);
+                _mm256_storeu_pd(x.add(12), x3);
+                _mm256_storeu_pd(x.add(16), x4);
+                _mm256_storeu_pd(x.add(20), x5);
+                _mm256_storeu_pd(x.add(24), x6);
+                _mm256_storeu_pd(x.add(28), x7);
+
+                x = x.add(32);
+            }
+            for _ in 0..n % 32 {
+                *x *= a;
+                x = x.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *x *= a;
+                x = x.wrapping_offset(incx);
+            }
         }
+    }
 
-        for _ in 0..n % 16 {
-            let x0 = *x;
-
-            *x = *y;
-            *y = x0;
-
-            x = x.add(1);
-            y = y.add(1);
+    pub unsafe fn dcopy(n: usize, mut x: *const f64, incx: isize, mut y: *mut f64, incy: isize) {
+        if incx == 1 && incy == 1 {
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_pd(x);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let x4 = _mm256_loadu_pd(x.add(16));
+                let x5 = _mm256_loadu_pd(x.add(20));
+                let x6 = _mm256_loadu_pd(x.add(24));
+                let x7 = _mm256_loadu_pd(x.add(28));
+
+                _mm256_storeu_pd(y, x0);
+                _mm256_storeu_pd(y.add(4), x1);
+                _mm256_storeu_pd(y.add(8), x2);
+                _mm256_storeu_pd(y.add(12), x3);
+                _mm256_storeu_pd(y.add(16), x4);
+                _mm256_storeu_pd(y.add(20), x5);
+                _mm256_storeu_pd(y.add(24), x6);
+                _mm256_storeu_pd(y.add(28), x7);
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+            for _ in 0..n % 32 {
+                *y = *x;
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *y = *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
-    } else {
-        for _ in 0..n {
-            let x0 = *x;
-
-            *x = *y;
-            *y = x0;
+    }
 
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn daxpy(
+        n: usize,
+        a: f64,
+        mut x: *const f64,
+        incx: isize,
+        mut y: *mut f64,
+        incy: isize,
+    ) {
+        if incx == 1 && incy == 1 {
+            let a0 = _mm256_broadcast_sd(&a);
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+
+                _mm256_storeu_pd(y, fmadd_pd(a0, x0, y0));
+                _mm256_storeu_pd(y.add(4), fmadd_pd(a0, x1, y1));
+                _mm256_storeu_pd(y.add(8), fmadd_pd(a0, x2, y2));
+                _mm256_storeu_pd(y.add(12), fmadd_pd(a0, x3, y3));
+
+                x = x.add(16);
+                y = y.add(16);
+            }
+            for _ in 0..n % 16 {
+                *y += a * *x;
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *y += a * *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
     }
-}
 
-pub unsafe fn dscal(n: usize, a: f64, mut x: *mut f64, incx: usize) {
-    if incx == 1 {
-        let a0 = _mm256_broadcast_sd(&a);
-        for _ in 0..n / 32 {
-            let mut x0 = _mm256_load_pd(x);
-            let mut x1 = _mm256_load_pd(x.add(4));
-            let mut x2 = _mm256_load_pd(x.add(8));
-            let mut x3 = _mm256_load_pd(x.add(12));
-            let mut x4 = _mm256_load_pd(x.add(16));
-            let mut x5 = _mm256_load_pd(x.add(20));
-            let mut x6 = _mm256_load_pd(x.add(24));
-            let mut x7 = _mm256_load_pd(x.add(28));
-
-            x0 = _mm256_mul_pd(a0, x0);
-            x1 = _mm256_mul_pd(a0, x1);
-            x2 = _mm256_mul_pd(a0, x2);
-            x3 = _mm256_mul_pd(a0, x3);
-            x4 = _mm256_mul_pd(a0, x4);
-            x5 = _mm256_mul_pd(a0, x5);
-            x6 = _mm256_mul_pd(a0, x6);
-            x7 = _mm256_mul_pd(a0, x7);
-
-            _mm256_store_pd(x, x0);
-            _mm256_store_pd(x.add(4), x1);
-            _mm256_store_pd(x.add(8), x2);
-            _mm256_store_pd(x.add(12), x3);
-            _mm256_store_pd(x.add(16), x4);
-            _mm256_store_pd(x.add(20), x5);
-            _mm256_store_pd(x.add(24), x6);
-            _mm256_store_pd(x.add(28), x7);
-
-            x = x.add(32);
-        }
-        for _ in 0..n % 32 {
-            *x *= a;
-            x = x.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *x *= a;
-            x = x.add(incx);
+    pub unsafe fn ddot(
+        n: usize,
+        mut x: *const f64,
+        incx: isize,
+        mut y: *const f64,
+        incy: isize,
+    ) -> f64 {
+        if incx == 1 && incy == 1 {
+            let mut acc0 = _mm256_setzero_pd();
+            let mut acc1 = _mm256_setzero_pd();
+            let mut acc2 = _mm256_setzero_pd();
+            let mut acc3 = _mm256_setzero_pd();
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+
+                acc0 = fmadd_pd(x0, y0, acc0);
+                acc1 = fmadd_pd(x1, y1, acc1);
+                acc2 = fmadd_pd(x2, y2, acc2);
+                acc3 = fmadd_pd(x3, y3, acc3);
+
+                x = x.add(16);
+                y = y.add(16);
+            }
+            acc0 = _mm256_add_pd(acc0, acc1);
+            acc2 = _mm256_add_pd(acc2, acc3);
+            acc0 = _mm256_add_pd(acc0, acc2);
+
+            let mut acc = hsum_pd(acc0);
+            for _ in 0..n % 16 {
+                acc += *x * *y;
+                x = x.add(1);
+                y = y.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += *x * *y;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
+            acc
         }
     }
-}
 
-pub unsafe fn dcopy(n: usize, mut x: *const f64, incx: usize, mut y: *mut f64, incy: usize) {
-    if incx == 1 && incy == 1 {
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_pd(x);
-            let x1 = _mm256_load_pd(x.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-            let x4 = _mm256_load_pd(x.add(16));
-            let x5 = _mm256_load_pd(x.add(20));
-            let x6 = _mm256_load_pd(x.add(24));
-            let x7 = _mm256_load_pd(x.add(28));
-
-            _mm256_store_pd(y, x0);
-            _mm256_store_pd(y.add(4), x1);
-            _mm256_store_pd(y.add(8), x2);
-            _mm256_store_pd(y.add(12), x3);
-            _mm256_store_pd(y.add(16), x4);
-            _mm256_store_pd(y.add(20), x5);
-            _mm256_store_pd(y.add(24), x6);
-            _mm256_store_pd(y.add(28), x7);
-
-            x = x.add(32);
-            y = y.add(32);
-        }
-        for _ in 0..n % 32 {
-            *y = *x;
-            x = x.add(1);
-            y = y.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *y = *x;
-            x = x.add(incx);
-            y = y.add(incy);
+    // z must not overlap x or y.
+    pub unsafe fn ddotaxpy(
+        n: usize,
+        alpha: f64,
+        mut x: *const f64,
+        incx: isize,
+        mut y: *const f64,
+        incy: isize,
+        mut z: *mut f64,
+        incz: isize,
+    ) -> f64 {
+        if incx == 1 && incy == 1 && incz == 1 {
+            let a0 = _mm256_broadcast_sd(&alpha);
+            let mut acc0 = _mm256_setzero_pd();
+            let mut acc1 = _mm256_setzero_pd();
+            let mut acc2 = _mm256_setzero_pd();
+            let mut acc3 = _mm256_setzero_pd();
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let y0 = _mm256_loadu_pd(y);
+                let z0 = _mm256_loadu_pd(z);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let y1 = _mm256_loadu_pd(y.add(4));
+                let z1 = _mm256_loadu_pd(z.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let y2 = _mm256_loadu_pd(y.add(8));
+                let z2 = _mm256_loadu_pd(z.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+                let y3 = _mm256_loadu_pd(y.add(12));
+                let z3 = _mm256_loadu_pd(z.add(12));
+
+                acc0 = fmadd_pd(x0, y0, acc0);
+                acc1 = fmadd_pd(x1, y1, acc1);
+                acc2 = fmadd_pd(x2, y2, acc2);
+                acc3 = fmadd_pd(x3, y3, acc3);
+
+                _mm256_storeu_pd(z, fmadd_pd(a0, x0, z0));
+                _mm256_storeu_pd(z.add(4), fmadd_pd(a0, x1, z1));
+                _mm256_storeu_pd(z.add(8), fmadd_pd(a0, x2, z2));
+                _mm256_storeu_pd(z.add(12), fmadd_pd(a0, x3, z3));
+
+                x = x.add(16);
+                y = y.add(16);
+                z = z.add(16);
+            }
+            acc0 = _mm256_add_pd(acc0, acc1);
+            acc2 = _mm256_add_pd(acc2, acc3);
+            acc0 = _mm256_add_pd(acc0, acc2);
+
+            let mut acc = hsum_pd(acc0);
+            for _ in 0..n % 16 {
+                acc += *x * *y;
+                *z += alpha * *x;
+                x = x.add(1);
+                y = y.add(1);
+                z = z.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += *x * *y;
+                *z += alpha * *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+                z = z.wrapping_offset(incz);
+            }
+            acc
         }
     }
-}
 
-pub unsafe fn daxpy(
-    n: usize,
-    a: f64,
-    mut x: *const f64,
-    incx: usize,
-    mut y: *mut f64,
-    incy: usize,
-) {
-    if incx == 1 && incy == 1 {
-        let a0 = _mm256_broadcast_sd(&a);
-        for _ in 0..n / 16 {
-            let x0 = _mm256_load_pd(x);
-            let y0 = _mm256_load_pd(y);
-            let x1 = _mm256_load_pd(x.add(4));
-            let y1 = _mm256_load_pd(y.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let y2 = _mm256_load_pd(y.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-            let y3 = _mm256_load_pd(y.add(12));
-
-            _mm256_store_pd(y, fmadd_pd(a0, x0, y0));
-            _mm256_store_pd(y.add(4), fmadd_pd(a0, x1, y1));
-            _mm256_store_pd(y.add(8), fmadd_pd(a0, x2, y2));
-            _mm256_store_pd(y.add(12), fmadd_pd(a0, x3, y3));
-
-            x = x.add(16);
-            y = y.add(16);
-        }
-        for _ in 0..n % 16 {
-            *y += a * *x;
-            x = x.add(1);
-            y = y.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *y += a * *x;
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn dnrm2(n: usize, mut x: *const f64, incx: isize) -> f64 {
+        if incx == 1 {
+            let base = x;
+
+            let mut acc0 = _mm256_setzero_pd();
+            let mut acc1 = _mm256_setzero_pd();
+            let mut acc2 = _mm256_setzero_pd();
+            let mut acc3 = _mm256_setzero_pd();
+            for _ in 0..n / 16 {
+                let x0 = _mm256_loadu_pd(x);
+                let x1 = _mm256_loadu_pd(x.add(4));
+                let x2 = _mm256_loadu_pd(x.add(8));
+                let x3 = _mm256_loadu_pd(x.add(12));
+
+                acc0 = fmadd_pd(x0, x0, acc0);
+                acc1 = fmadd_pd(x1, x1, acc1);
+                acc2 = fmadd_pd(x2, x2, acc2);
+                acc3 = fmadd_pd(x3, x3, acc3);
+
+                x = x.add(16);
+            }
+            acc0 = _mm256_add_pd(acc0, acc1);
+            acc2 = _mm256_add_pd(acc2, acc3);
+            acc0 = _mm256_add_pd(acc0, acc2);
+
+            let mut acc = hsum_pd(acc0);
+            for _ in 0..n % 16 {
+                let x0 = *x;
+                acc += x0 * x0;
+                x = x.add(1);
+            }
+
+            if dnrm2_sumsq_ok(n, acc) {
+                return acc.sqrt();
+            }
+
+            dnrm2_blue(n, base)
+        } else {
+            let (mut asml, mut amed, mut abig) = (0., 0., 0.);
+            for _ in 0..n {
+                dnrm2_acc(*x, &mut asml, &mut amed, &mut abig);
+                x = x.wrapping_offset(incx);
+            }
+            dnrm2_combine(asml, amed, abig)
         }
     }
-}
 
-pub unsafe fn ddot(
-    n: usize,
-    mut x: *const f64,
-    incx: usize,
-    mut y: *const f64,
-    incy: usize,
-) -> f64 {
-    if incx == 1 && incy == 1 {
-        let mut acc0 = _mm256_setzero_pd();
-        let mut acc1 = _mm256_setzero_pd();
-        let mut acc2 = _mm256_setzero_pd();
-        let mut acc3 = _mm256_setzero_pd();
-        for _ in 0..n / 16 {
-            let x0 = _mm256_load_pd(x);
-            let y0 = _mm256_load_pd(y);
-            let x1 = _mm256_load_pd(x.add(4));
-            let y1 = _mm256_load_pd(y.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let y2 = _mm256_load_pd(y.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-            let y3 = _mm256_load_pd(y.add(12));
-
-            acc0 = fmadd_pd(x0, y0, acc0);
-            acc1 = fmadd_pd(x1, y1, acc1);
-            acc2 = fmadd_pd(x2, y2, acc2);
-            acc3 = fmadd_pd(x3, y3, acc3);
-
-            x = x.add(16);
-            y = y.add(16);
+    // Unit-stride Blue's algorithm, for vectors whose plain sum of squares
+    // overflowed or lost too much to underflow.
+    unsafe fn dnrm2_blue(n: usize, mut x: *const f64) -> f64 {
+        let mask = _mm256_broadcast_sd(&f64::from_bits(0x7FFF_FFFF_FFFF_FFFF));
+        let tsml = _mm256_broadcast_sd(&TSML);
+        let tbig = _mm256_broadcast_sd(&TBIG);
+        let ssml = _mm256_broadcast_sd(&SSML);
+        let sbig = _mm256_broadcast_sd(&SBIG);
+
+        let mut asml0 = _mm256_setzero_pd();
+        let mut amed0 = _mm256_setzero_pd();
+        let mut abig0 = _mm256_setzero_pd();
+        for _ in 0..n / 4 {
+            let x0 = _mm256_and_pd(mask, _mm256_loadu_pd(x));
+
+            // NaNs compare false and are summed with the medium values.
+            let big = _mm256_cmp_pd(x0, tbig, _CMP_GT_OQ);
+            let sml = _mm256_cmp_pd(x0, tsml, _CMP_LT_OQ);
+
+            let xb = _mm256_and_pd(big, _mm256_mul_pd(x0, sbig));
+            let xs = _mm256_and_pd(sml, _mm256_mul_pd(x0, ssml));
+            let xm = _mm256_andnot_pd(_mm256_or_pd(big, sml), x0);
+
+            abig0 = fmadd_pd(xb, xb, abig0);
+            asml0 = fmadd_pd(xs, xs, asml0);
+            amed0 = fmadd_pd(xm, xm, amed0);
+
+            x = x.add(4);
         }
-        acc0 = _mm256_add_pd(acc0, acc1);
-        acc2 = _mm256_add_pd(acc2, acc3);
-        acc0 = _mm256_add_pd(acc0, acc2);
 
-        let mut acc = hsum_pd(acc0);
-        for _ in 0..n % 16 {
-            acc += *x * *y;
+        let mut asml = hsum_pd(asml0);
+        let mut amed = hsum_pd(amed0);
+        let mut abig = hsum_pd(abig0);
+        for _ in 0..n % 4 {
+            dnrm2_acc(*x, &mut asml, &mut amed, &mut abig);
             x = x.add(1);
-            y = y.add(1);
-        }
-        acc
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            acc += *x * *y;
-            x = x.add(incx);
-            y = y.add(incy);
         }
-        acc
+
+        dnrm2_combine(asml, amed, abig)
     }
-}
 
-pub unsafe fn dnrm2(n: usize, mut x: *const f64, incx: usize) -> f64 {
-    if incx == 1 {
-        let mut acc0 = _mm256_setzero_pd();
-        let mut acc1 = _mm256_setzero_pd();
-        let mut acc2 = _mm256_setzero_pd();
-        let mut acc3 = _mm256_setzero_pd();
-        for _ in 0..n / 16 {
-            let x0 = _mm256_load_pd(x);
-            let x1 = _mm256_load_pd(x.add(4));
-            let x2 = _mm256_load_pd(x.add(8));
-            let x3 = _mm256_load_pd(x.add(12));
-
-            acc0 = fmadd_pd(x0, x0, acc0);
-            acc1 = fmadd_pd(x1, x1, acc1);
-            acc2 = fmadd_pd(x2, x2, acc2);
-            acc3 = fmadd_pd(x3, x3, acc3);
-
-            x = x.add(16);
+    pub unsafe fn dasum(n: usize, mut x: *const f64, incx: isize) -> f64 {
+        if incx == 1 {
+            let mask = _mm256_broadcast_sd(&f64::from_bits(0x7FFF_FFFF_FFFF_FFFF));
+
+            let mut acc0 = _mm256_setzero_pd();
+            let mut acc1 = _mm256_setzero_pd();
+            let mut acc2 = _mm256_setzero_pd();
+            let mut acc3 = _mm256_setzero_pd();
+            let mut acc4 = _mm256_setzero_pd();
+            let mut acc5 = _mm256_setzero_pd();
+            let mut acc6 = _mm256_setzero_pd();
+            let mut acc7 = _mm256_setzero_pd();
+            for _ in 0..n / 32 {
+                let mut x0 = _mm256_loadu_pd(x);
+                let mut x1 = _mm256_loadu_pd(x.add(4));
+                let mut x2 = _mm256_loadu_pd(x.add(8));
+                let mut x3 = _mm256_loadu_pd(x.add(12));
+                let mut x4 = _mm256_loadu_pd(x.add(16));
+                let mut x5 = _mm256_loadu_pd(x.add(20));
+                let mut x6 = _mm256_loadu_pd(x.add(24));
+                let mut x7 = _mm256_loadu_pd(x.add(28));
+
+                x0 = _mm256_and_pd(mask, x0);
+                x1 = _mm256_and_pd(mask, x1);
+                x2 = _mm256_and_pd(mask, x2);
+                x3 = _mm256_and_pd(mask, x3);
+                x4 = _mm256_and_pd(mask, x4);
+                x5 = _mm256_and_pd(mask, x5);
+                x6 = _mm256_and_pd(mask, x6);
+                x7 = _mm256_and_pd(mask, x7);
+
+                acc0 = _mm256_add_pd(acc0, x0);
+                acc1 = _mm256_add_pd(acc1, x1);
+                acc2 = _mm256_add_pd(acc2, x2);
+                acc3 = _mm256_add_pd(acc3, x3);
+                acc4 = _mm256_add_pd(acc4, x4);
+                acc5 = _mm256_add_pd(acc5, x5);
+                acc6 = _mm256_add_pd(acc6, x6);
+                acc7 = _mm256_add_pd(acc7, x7);
+
+                x = x.add(32);
+            }
+            acc0 = _mm256_add_pd(acc0, acc1);
+            acc2 = _mm256_add_pd(acc2, acc3);
+            acc4 = _mm256_add_pd(acc4, acc5);
+            acc6 = _mm256_add_pd(acc6, acc7);
+
+            acc0 = _mm256_add_pd(acc0, acc2);
+            acc4 = _mm256_add_pd(acc4, acc6);
+
+            acc0 = _mm256_add_pd(acc0, acc4);
+
+            let mut acc = hsum_pd(acc0);
+            for _ in 0..n % 32 {
+                acc += (*x).abs();
+                x = x.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += (*x).abs();
+                x = x.wrapping_offset(incx);
+            }
+            acc
         }
-        acc0 = _mm256_add_pd(acc0, acc1);
-        acc2 = _mm256_add_pd(acc2, acc3);
-        acc0 = _mm256_add_pd(acc0, acc2);
-
-        let mut acc = hsum_pd(acc0);
-        for _ in 0..n % 16 {
-            let x0 = *x;
-            acc += x0 * x0;
-            x = x.add(1);
-        }
-        acc.sqrt()
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            let x0 = *x;
-            acc += x0 * x0;
-            x = x.add(incx);
-        }
-        acc.sqrt()
     }
-}
-
-pub unsafe fn dasum(n: usize, mut x: *const f64, incx: usize) -> f64 {
-    if incx == 1 {
-        let mask = _mm256_broadcast_sd(&f64::from_bits(0x7FFF_FFFF_FFFF_FFFF));
 
-        let mut acc0 = _mm256_setzero_pd();
-        let mut acc1 = _mm256_setzero_pd();
-        let mut acc2 = _mm256_setzero_pd();
-        let mut acc3 = _mm256_setzero_pd();
-        let mut acc4 = _mm256_setzero_pd();
-        let mut acc5 = _mm256_setzero_pd();
-        let mut acc6 = _mm256_setzero_pd();
-        let mut acc7 = _mm256_setzero_pd();
-        for _ in 0..n / 32 {
-            let mut x0 = _mm256_load_pd(x);
-            let mut x1 = _mm256_load_pd(x.add(4));
-            let mut x2 = _mm256_load_pd(x.add(8));
-            let mut x3 = _mm256_load_pd(x.add(12));
-            let mut x4 = _mm256_load_pd(x.add(16));
-            let mut x5 = _mm256_load_pd(x.add(20));
-            let mut x6 = _mm256_load_pd(x.add(24));
-            let mut x7 = _mm256_load_pd(x.add(28));
-
-            x0 = _mm256_and_pd(mask, x0);
-            x1 = _mm256_and_pd(mask, x1);
-            x2 = _mm256_and_pd(mask, x2);
-            x3 = _mm256_and_pd(mask, x3);
-            x4 = _mm256_and_pd(mask, x4);
-            x5 = _mm256_and_pd(mask, x5);
-            x6 = _mm256_and_pd(mask, x6);
-            x7 = _mm256_and_pd(mask, x7);
-
-            acc0 = _mm256_add_pd(acc0, x0);
-            acc1 = _mm256_add_pd(acc1, x1);
-            acc2 = _mm256_add_pd(acc2, x2);
-            acc3 = _mm256_add_pd(acc3, x3);
-            acc4 = _mm256_add_pd(acc4, x4);
-            acc5 = _mm256_add_pd(acc5, x5);
-            acc6 = _mm256_add_pd(acc6, x6);
-            acc7 = _mm256_add_pd(acc7, x7);
-
-            x = x.add(32);
+    pub unsafe fn idamax(n: usize, x: *const f64, incx: isize) -> usize {
+        if incx == 1 {
+            // The strided search below starts from |x[0]| and never moves past a
+            // NaN there, as in the reference BLAS.
+            if (*x).is_nan() {
+                return 0;
+            }
+
+            let mask = _mm256_broadcast_sd(&f64::from_bits(0x7FFF_FFFF_FFFF_FFFF));
+
+            let mut max0 = _mm256_setzero_pd();
+            let mut max1 = max0;
+            let mut max2 = max0;
+            let mut max3 = max0;
+            let mut px = x;
+            for _ in 0..n / 16 {
+                let x0 = _mm256_and_pd(mask, _mm256_loadu_pd(px));
+                let x1 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(4)));
+                let x2 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(8)));
+                let x3 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(12)));
+
+                // NaNs in x are passed over, as the second operand is returned.
+                max0 = _mm256_max_pd(x0, max0);
+                max1 = _mm256_max_pd(x1, max1);
+                max2 = _mm256_max_pd(x2, max2);
+                max3 = _mm256_max_pd(x3, max3);
+
+                px = px.add(16);
+            }
+            max0 = _mm256_max_pd(max0, max1);
+            max2 = _mm256_max_pd(max2, max3);
+            max0 = _mm256_max_pd(max0, max2);
+
+            let mut max = hmax_pd(max0);
+            for _ in 0..n % 16 {
+                let x0 = (*px).abs();
+                if x0 > max {
+                    max = x0;
+                }
+                px = px.add(1);
+            }
+
+            // Rescan for the first element reaching the extremum.
+            let max0 = _mm256_broadcast_sd(&max);
+            let mut i = 0;
+            while i + 4 <= n {
+                let x0 = _mm256_and_pd(mask, _mm256_loadu_pd(x.add(i)));
+                let eq = _mm256_movemask_pd(_mm256_cmp_pd(x0, max0, _CMP_EQ_OQ));
+                if eq != 0 {
+                    return i + eq.trailing_zeros() as usize;
+                }
+                i += 4;
+            }
+            while i < n {
+                if (*x.add(i)).abs() == max {
+                    return i;
+                }
+                i += 1;
+            }
+            0
+        } else {
+            let mut imax = 0;
+            let mut max = (*x).abs();
+            let mut px = x;
+            for i in 1..n {
+                px = px.wrapping_offset(incx);
+                let x0 = (*px).abs();
+                if x0 > max {
+                    imax = i;
+                    max = x0;
+                }
+            }
+            imax
         }
-        acc0 = _mm256_add_pd(acc0, acc1);
-        acc2 = _mm256_add_pd(acc2, acc3);
-        acc4 = _mm256_add_pd(acc4, acc5);
-        acc6 = _mm256_add_pd(acc6, acc7);
-
-        acc0 = _mm256_add_pd(acc0, acc2);
-        acc4 = _mm256_add_pd(acc4, acc6);
-
-        acc0 = _mm256_add_pd(acc0, acc4);
+    }
 
-        let mut acc = hsum_pd(acc0);
-        for _ in 0..n % 32 {
-            acc += (*x).abs();
-            x = x.add(1);
-        }
-        acc
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            acc += (*x).abs();
-            x = x.add(incx);
+    pub unsafe fn idamin(n: usize, x: *const f64, incx: isize) -> usize {
+        if incx == 1 {
+            // The strided search below starts from |x[0]| and never moves past a
+            // NaN there, as in the reference BLAS.
+            if (*x).is_nan() {
+                return 0;
+            }
+
+            let mask = _mm256_broadcast_sd(&f64::from_bits(0x7FFF_FFFF_FFFF_FFFF));
+
+            let mut min0 = _mm256_broadcast_sd(&f64::INFINITY);
+            let mut min1 = min0;
+            let mut min2 = min0;
+            let mut min3 = min0;
+            let mut px = x;
+            for _ in 0..n / 16 {
+                let x0 = _mm256_and_pd(mask, _mm256_loadu_pd(px));
+                let x1 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(4)));
+                let x2 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(8)));
+                let x3 = _mm256_and_pd(mask, _mm256_loadu_pd(px.add(12)));
+
+                // NaNs in x are passed over, as the second operand is returned.
+                min0 = _mm256_min_pd(x0, min0);
+                min1 = _mm256_min_pd(x1, min1);
+                min2 = _mm256_min_pd(x2, min2);
+                min3 = _mm256_min_pd(x3, min3);
+
+                px = px.add(16);
+            }
+            min0 = _mm256_min_pd(min0, min1);
+            min2 = _mm256_min_pd(min2, min3);
+            min0 = _mm256_min_pd(min0, min2);
+
+            let mut min = hmin_pd(min0);
+            for _ in 0..n % 16 {
+                let x0 = (*px).abs();
+                if x0 < min {
+                    min = x0;
+                }
+                px = px.add(1);
+            }
+
+            // Rescan for the first element reaching the extremum.
+            let min0 = _mm256_broadcast_sd(&min);
+            let mut i = 0;
+            while i + 4 <= n {
+                let x0 = _mm256_and_pd(mask, _mm256_loadu_pd(x.add(i)));
+                let eq = _mm256_movemask_pd(_mm256_cmp_pd(x0, min0, _CMP_EQ_OQ));
+                if eq != 0 {
+                    return i + eq.trailing_zeros() as usize;
+                }
+                i += 4;
+            }
+            while i < n {
+                if (*x.add(i)).abs() == min {
+                    return i;
+                }
+                i += 1;
+            }
+            0
+        } else {
+            let mut imin = 0;
+            let mut min = (*x).abs();
+            let mut px = x;
+            for i in 1..n {
+                px = px.wrapping_offset(incx);
+                let x0 = (*px).abs();
+                if x0 < min {
+                    imin = i;
+                    min = x0;
+                }
+            }
+            imin
         }
-        acc
     }
 }
@@ -1,396 +1,729 @@
 use super::fma::{fmadd_ps, fmsub_ps};
-use super::hsum::hsum_ps;
+use super::hsum::{hmax_ps, hmin_ps, hsum_ps};
 use super::intrinsics::*;
-
-pub unsafe fn srot(
-    n: usize,
-    mut x: *mut f32,
-    incx: usize,
-    mut y: *mut f32,
-    incy: usize,
-    c: f32,
-    s: f32,
-) {
-    if incx == 1 && incy == 1 {
-        let c0 = _mm256_broadcast_ss(&c);
-        let s0 = _mm256_broadcast_ss(&s);
-
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_ps(x);
-            let y0 = _mm256_load_ps(y);
-            let x1 = _mm256_load_ps(x.add(8));
-            let y1 = _mm256_load_ps(y.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let y2 = _mm256_load_ps(y.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-            let y3 = _mm256_load_ps(y.add(24));
-
-            _mm256_store_ps(x, fmadd_ps(c0, x0, _mm256_mul_ps(s0, y0)));
-            _mm256_store_ps(y, fmsub_ps(c0, y0, _mm256_mul_ps(s0, x0)));
-            _mm256_store_ps(x.add(8), fmadd_ps(c0, x1, _mm256_mul_ps(s0, y1)));
-            _mm256_store_ps(y.add(8), fmsub_ps(c0, y1, _mm256_mul_ps(s0, x1)));
-            _mm256_store_ps(x.add(16), fmadd_ps(c0, x2, _mm256_mul_ps(s0, y2)));
-            _mm256_store_ps(y.add(16), fmsub_ps(c0, y2, _mm256_mul_ps(s0, x2)));
-            _mm256_store_ps(x.add(24), fmadd_ps(c0, x3, _mm256_mul_ps(s0, y3)));
-            _mm256_store_ps(y.add(24), fmsub_ps(c0, y3, _mm256_mul_ps(s0, x3)));
-
-            x = x.add(32);
-            y = y.add(32);
+use crate::kernels::generic::l1s::{
+    snrm2_acc, snrm2_combine, snrm2_sumsq_ok, SBIG, SSML, TBIG, TSML,
+};
+
+kernels! {
+    pub unsafe fn srot(
+        n: usize,
+        mut x: *mut f32,
+        incx: isize,
+        mut y: *mut f32,
+        incy: isize,
+        c: f32,
+        s: f32,
+    ) {
+        if incx == 1 && incy == 1 {
+            let c0 = _mm256_broadcast_ss(&c);
+            let s0 = _mm256_broadcast_ss(&s);
+
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+
+                _mm256_storeu_ps(x, fmadd_ps(c0, x0, _mm256_mul_ps(s0, y0)));
+                _mm256_storeu_ps(y, fmsub_ps(c0, y0, _mm256_mul_ps(s0, x0)));
+                _mm256_storeu_ps(x.add(8), fmadd_ps(c0, x1, _mm256_mul_ps(s0, y1)));
+                _mm256_storeu_ps(y.add(8), fmsub_ps(c0, y1, _mm256_mul_ps(s0, x1)));
+                _mm256_storeu_ps(x.add(16), fmadd_ps(c0, x2, _mm256_mul_ps(s0, y2)));
+                _mm256_storeu_ps(y.add(16), fmsub_ps(c0, y2, _mm256_mul_ps(s0, x2)));
+                _mm256_storeu_ps(x.add(24), fmadd_ps(c0, x3, _mm256_mul_ps(s0, y3)));
+                _mm256_storeu_ps(y.add(24), fmsub_ps(c0, y3, _mm256_mul_ps(s0, x3)));
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+
+            for _ in 0..n % 32 {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = c * x0 + s * y0;
+                *y = c * y0 - s * x0;
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = c * x0 + s * y0;
+                *y = c * y0 - s * x0;
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
+    }
 
-        for _ in 0..n % 32 {
-            let x0 = *x;
-            let y0 = *y;
-
-            *x = c * x0 + s * y0;
-            *y = c * y0 - s * x0;
-
-            x = x.add(1);
-            y = y.add(1);
+    pub unsafe fn srotm(
+        n: usize,
+        mut x: *mut f32,
+        incx: isize,
+        mut y: *mut f32,
+        incy: isize,
+        h11: f32,
+        h21: f32,
+        h12: f32,
+        h22: f32,
+    ) {
+        if incx == 1 && incy == 1 {
+            let h110 = _mm256_broadcast_ss(&h11);
+            let h210 = _mm256_broadcast_ss(&h21);
+            let h120 = _mm256_broadcast_ss(&h12);
+            let h220 = _mm256_broadcast_ss(&h22);
+
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+
+                _mm256_storeu_ps(x, fmadd_ps(h110, x0, _mm256_mul_ps(h120, y0)));
+                _mm256_storeu_ps(y, fmadd_ps(h210, x0, _mm256_mul_ps(h220, y0)));
+                _mm256_storeu_ps(x.add(8), fmadd_ps(h110, x1, _mm256_mul_ps(h120, y1)));
+                _mm256_storeu_ps(y.add(8), fmadd_ps(h210, x1, _mm256_mul_ps(h220, y1)));
+                _mm256_storeu_ps(x.add(16), fmadd_ps(h110, x2, _mm256_mul_ps(h120, y2)));
+                _mm256_storeu_ps(y.add(16), fmadd_ps(h210, x2, _mm256_mul_ps(h220, y2)));
+                _mm256_storeu_ps(x.add(24), fmadd_ps(h110, x3, _mm256_mul_ps(h120, y3)));
+                _mm256_storeu_ps(y.add(24), fmadd_ps(h210, x3, _mm256_mul_ps(h220, y3)));
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+
+            for _ in 0..n % 32 {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = h11 * x0 + h12 * y0;
+                *y = h21 * x0 + h22 * y0;
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                let x0 = *x;
+                let y0 = *y;
+
+                *x = h11 * x0 + h12 * y0;
+                *y = h21 * x0 + h22 * y0;
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
-    } else {
-        for _ in 0..n {
-            let x0 = *x;
-            let y0 = *y;
-
-            *x = c * x0 + s * y0;
-            *y = c * y0 - s * x0;
+    }
 
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn sswap(n: usize, mut x: *mut f32, incx: isize, mut y: *mut f32, incy: isize) {
+        if incx == 1 && incy == 1 {
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+
+                _mm256_storeu_ps(x, y0);
+                _mm256_storeu_ps(y, x0);
+                _mm256_storeu_ps(x.add(8), y1);
+                _mm256_storeu_ps(y.add(8), x1);
+                _mm256_storeu_ps(x.add(16), y2);
+                _mm256_storeu_ps(y.add(16), x2);
+                _mm256_storeu_ps(x.add(24), y3);
+                _mm256_storeu_ps(y.add(24), x3);
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+
+            for _ in 0..n % 32 {
+                std::ptr::swap(x, y);
+
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                std::ptr::swap(x, y);
+
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
     }
-}
 
-pub unsafe fn sswap(n: usize, mut x: *mut f32, incx: usize, mut y: *mut f32, incy: usize) {
-    if incx == 1 && incy == 1 {
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_ps(x);
-            let y0 = _mm256_load_ps(y);
-            let x1 = _mm256_load_ps(x.add(8));
-            let y1 = _mm256_load_ps(y.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let y2 = _mm256_load_ps(y.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-            let y3 = _mm256_load_ps(y.add(24));
-
-            _mm256_store_ps(x, y0);
-            _mm256_store_ps(y, x0);
-            _mm256_store_ps(x.add(8), y1);
-            _mm256_store_ps(y.add(8), x1);
-            _mm256_store_ps(x.add(16), y2);
-            _mm256_store_ps(y.add(16), x2);
-            _mm256_store_ps(x.add(24), y3);
-            _mm256_store_ps(y.add(24), x3);
-
-            x = x.add(32);
-            y = y.add(32);
+    pub unsafe fn sscal(n: usize, a: f32, mut x: *mut f32, incx: isize) {
+        if incx == 1 {
+            let a0 = _mm256_broadcast_ss(&a);
+            for _ in 0..n / 64 {
+                let mut x0 = _mm256_loadu_ps(x);
+                let mut x1 = _mm256_loadu_ps(x.add(8));
+                let mut x2 = _mm256_loadu_ps(x.add(16));
+                let mut x3 = _mm256_loadu_ps(x.add(24));
+                let mut x4 = _mm256_loadu_ps(x.add(32));
+                let mut x5 = _mm256_loadu_ps(x.add(40));
+                let mut x6 = _mm256_loadu_ps(x.add(48));
+                let mut x7 = _mm256_loadu_ps(x.add(56));
+
+                x0 = _mm256_mul_ps(a0, x0);
+                x1 = _mm256_mul_ps(a0, x1);
+                x2 = _mm256_mul_ps(a0, x2);
+                x3 = _mm256_mul_ps(a0, x3);
+                x4 = _mm256_mul_ps(a0, x4);
+                x5 = _mm256_mul_ps(a0, x5);
+                x6 = _mm256_mul_ps(a0, x6);
+                x7 = _mm256_mul_ps(a0, x7);
+
+                _mm256_storeu_ps(x, x0);
+                _mm256_storeu_ps(x.add(8), x1);
+                _mm256_storeu_ps(x.add(16), x2);
+                _mm256_storeu_ps(x.add(24), x3);
+                _mm256_storeu_ps(x.add(32), x4);
+                _mm256_storeu_ps(x.add(40), x5);
+                _mm256_storeu_ps(x.add(48), x6);
+                _mm256_storeu_ps(x.add(56), x7);
+
+                x = x.add(64);
+            }
+            for _ in 0..n % 64 {
+                *x *= a;
+                x = x.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *x *= a;
+                x = x.wrapping_offset(incx);
+            }
         }
+    }
 
-        for _ in 0..n % 32 {
-            let x0 = *x;
-
-            *x = *y;
-            *y = x0;
-
-            x = x.add(1);
-            y = y.add(1);
+    pub unsafe fn scopy(n: usize, mut x: *const f32, incx: isize, mut y: *mut f32, incy: isize) {
+        if incx == 1 && incy == 1 {
+            for _ in 0..n / 64 {
+                let x0 = _mm256_loadu_ps(x);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let x4 = _mm256_loadu_ps(x.add(32));
+                let x5 = _mm256_loadu_ps(x.add(40));
+                let x6 = _mm256_loadu_ps(x.add(48));
+                let x7 = _mm256_loadu_ps(x.add(56));
+
+                _mm256_storeu_ps(y, x0);
+                _mm256_storeu_ps(y.add(8), x1);
+                _mm256_storeu_ps(y.add(16), x2);
+                _mm256_storeu_ps(y.add(24), x3);
+                _mm256_storeu_ps(y.add(32), x4);
+                _mm256_storeu_ps(y.add(40), x5);
+                _mm256_storeu_ps(y.add(48), x6);
+                _mm256_storeu_ps(y.add(56), x7);
+
+                x = x.add(64);
+                y = y.add(64);
+            }
+            for _ in 0..n % 64 {
+                *y = *x;
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *y = *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
-    } else {
-        for _ in 0..n {
-            let x0 = *x;
-
-            *x = *y;
-            *y = x0;
+    }
 
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn saxpy(
+        n: usize,
+        a: f32,
+        mut x: *const f32,
+        incx: isize,
+        mut y: *mut f32,
+        incy: isize,
+    ) {
+        if incx == 1 && incy == 1 {
+            let a0 = _mm256_broadcast_ss(&a);
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+
+                _mm256_storeu_ps(y, fmadd_ps(a0, x0, y0));
+                _mm256_storeu_ps(y.add(8), fmadd_ps(a0, x1, y1));
+                _mm256_storeu_ps(y.add(16), fmadd_ps(a0, x2, y2));
+                _mm256_storeu_ps(y.add(24), fmadd_ps(a0, x3, y3));
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+            for _ in 0..n % 32 {
+                *y += a * *x;
+                x = x.add(1);
+                y = y.add(1);
+            }
+        } else {
+            for _ in 0..n {
+                *y += a * *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
         }
     }
-}
 
-pub unsafe fn sscal(n: usize, a: f32, mut x: *mut f32, incx: usize) {
-    if incx == 1 {
-        let a0 = _mm256_broadcast_ss(&a);
-        for _ in 0..n / 64 {
-            let mut x0 = _mm256_load_ps(x);
-            let mut x1 = _mm256_load_ps(x.add(8));
-            let mut x2 = _mm256_load_ps(x.add(16));
-            let mut x3 = _mm256_load_ps(x.add(24));
-            let mut x4 = _mm256_load_ps(x.add(32));
-            let mut x5 = _mm256_load_ps(x.add(40));
-            let mut x6 = _mm256_load_ps(x.add(48));
-            let mut x7 = _mm256_load_ps(x.add(56));
-
-            x0 = _mm256_mul_ps(a0, x0);
-            x1 = _mm256_mul_ps(a0, x1);
-            x2 = _mm256_mul_ps(a0, x2);
-            x3 = _mm256_mul_ps(a0, x3);
-            x4 = _mm256_mul_ps(a0, x4);
-            x5 = _mm256_mul_ps(a0, x5);
-            x6 = _mm256_mul_ps(a0, x6);
-            x7 = _mm256_mul_ps(a0, x7);
-
-            _mm256_store_ps(x, x0);
-            _mm256_store_ps(x.add(8), x1);
-            _mm256_store_ps(x.add(16), x2);
-            _mm256_store_ps(x.add(24), x3);
-            _mm256_store_ps(x.add(32), x4);
-            _mm256_store_ps(x.add(40), x5);
-            _mm256_store_ps(x.add(48), x6);
-            _mm256_store_ps(x.add(56), x7);
-
-            x = x.add(64);
-        }
-        for _ in 0..n % 64 {
-            *x *= a;
-            x = x.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *x *= a;
-            x = x.add(incx);
+    pub unsafe fn sdot(
+        n: usize,
+        mut x: *const f32,
+        incx: isize,
+        mut y: *const f32,
+        incy: isize,
+    ) -> f32 {
+        if incx == 1 && incy == 1 {
+            let mut acc0 = _mm256_setzero_ps();
+            let mut acc1 = _mm256_setzero_ps();
+            let mut acc2 = _mm256_setzero_ps();
+            let mut acc3 = _mm256_setzero_ps();
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+
+                acc0 = fmadd_ps(x0, y0, acc0);
+                acc1 = fmadd_ps(x1, y1, acc1);
+                acc2 = fmadd_ps(x2, y2, acc2);
+                acc3 = fmadd_ps(x3, y3, acc3);
+
+                x = x.add(32);
+                y = y.add(32);
+            }
+            acc0 = _mm256_add_ps(acc0, acc1);
+            acc2 = _mm256_add_ps(acc2, acc3);
+            acc0 = _mm256_add_ps(acc0, acc2);
+
+            let mut acc = hsum_ps(acc0);
+            for _ in 0..n % 32 {
+                acc += *x * *y;
+                x = x.add(1);
+                y = y.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += *x * *y;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+            }
+            acc
         }
     }
-}
 
-pub unsafe fn scopy(n: usize, mut x: *const f32, incx: usize, mut y: *mut f32, incy: usize) {
-    if incx == 1 && incy == 1 {
-        for _ in 0..n / 64 {
-            let x0 = _mm256_load_ps(x);
-            let x1 = _mm256_load_ps(x.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-            let x4 = _mm256_load_ps(x.add(32));
-            let x5 = _mm256_load_ps(x.add(40));
-            let x6 = _mm256_load_ps(x.add(48));
-            let x7 = _mm256_load_ps(x.add(56));
-
-            _mm256_store_ps(y, x0);
-            _mm256_store_ps(y.add(8), x1);
-            _mm256_store_ps(y.add(16), x2);
-            _mm256_store_ps(y.add(24), x3);
-            _mm256_store_ps(y.add(32), x4);
-            _mm256_store_ps(y.add(40), x5);
-            _mm256_store_ps(y.add(48), x6);
-            _mm256_store_ps(y.add(56), x7);
-
-            x = x.add(64);
-            y = y.add(64);
-        }
-        for _ in 0..n % 64 {
-            *y = *x;
-            x = x.add(1);
-            y = y.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *y = *x;
-            x = x.add(incx);
-            y = y.add(incy);
+    // z must not overlap x or y.
+    pub unsafe fn sdotaxpy(
+        n: usize,
+        alpha: f32,
+        mut x: *const f32,
+        incx: isize,
+        mut y: *const f32,
+        incy: isize,
+        mut z: *mut f32,
+        incz: isize,
+    ) -> f32 {
+        if incx == 1 && incy == 1 && incz == 1 {
+            let a0 = _mm256_broadcast_ss(&alpha);
+            let mut acc0 = _mm256_setzero_ps();
+            let mut acc1 = _mm256_setzero_ps();
+            let mut acc2 = _mm256_setzero_ps();
+            let mut acc3 = _mm256_setzero_ps();
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let y0 = _mm256_loadu_ps(y);
+                let z0 = _mm256_loadu_ps(z);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let y1 = _mm256_loadu_ps(y.add(8));
+                let z1 = _mm256_loadu_ps(z.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let y2 = _mm256_loadu_ps(y.add(16));
+                let z2 = _mm256_loadu_ps(z.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+                let y3 = _mm256_loadu_ps(y.add(24));
+                let z3 = _mm256_loadu_ps(z.add(24));
+
+                acc0 = fmadd_ps(x0, y0, acc0);
+                acc1 = fmadd_ps(x1, y1, acc1);
+                acc2 = fmadd_ps(x2, y2, acc2);
+                acc3 = fmadd_ps(x3, y3, acc3);
+
+                _mm256_storeu_ps(z, fmadd_ps(a0, x0, z0));
+                _mm256_storeu_ps(z.add(8), fmadd_ps(a0, x1, z1));
+                _mm256_storeu_ps(z.add(16), fmadd_ps(a0, x2, z2));
+                _mm256_storeu_ps(z.add(24), fmadd_ps(a0, x3, z3));
+
+                x = x.add(32);
+                y = y.add(32);
+                z = z.add(32);
+            }
+            acc0 = _mm256_add_ps(acc0, acc1);
+            acc2 = _mm256_add_ps(acc2, acc3);
+            acc0 = _mm256_add_ps(acc0, acc2);
+
+            let mut acc = hsum_ps(acc0);
+            for _ in 0..n % 32 {
+                acc += *x * *y;
+                *z += alpha * *x;
+                x = x.add(1);
+                y = y.add(1);
+                z = z.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += *x * *y;
+                *z += alpha * *x;
+                x = x.wrapping_offset(incx);
+                y = y.wrapping_offset(incy);
+                z = z.wrapping_offset(incz);
+            }
+            acc
         }
     }
-}
 
-pub unsafe fn saxpy(
-    n: usize,
-    a: f32,
-    mut x: *const f32,
-    incx: usize,
-    mut y: *mut f32,
-    incy: usize,
-) {
-    if incx == 1 && incy == 1 {
-        let a0 = _mm256_broadcast_ss(&a);
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_ps(x);
-            let y0 = _mm256_load_ps(y);
-            let x1 = _mm256_load_ps(x.add(8));
-            let y1 = _mm256_load_ps(y.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let y2 = _mm256_load_ps(y.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-            let y3 = _mm256_load_ps(y.add(24));
-
-            _mm256_store_ps(y, fmadd_ps(a0, x0, y0));
-            _mm256_store_ps(y.add(8), fmadd_ps(a0, x1, y1));
-            _mm256_store_ps(y.add(16), fmadd_ps(a0, x2, y2));
-            _mm256_store_ps(y.add(24), fmadd_ps(a0, x3, y3));
-
-            x = x.add(32);
-            y = y.add(32);
-        }
-        for _ in 0..n % 32 {
-            *y += a * *x;
-            x = x.add(1);
-            y = y.add(1);
-        }
-    } else {
-        for _ in 0..n {
-            *y += a * *x;
-            x = x.add(incx);
-            y = y.add(incy);
+    pub unsafe fn snrm2(n: usize, mut x: *const f32, incx: isize) -> f32 {
+        if incx == 1 {
+            let base = x;
+
+            let mut acc0 = _mm256_setzero_ps();
+            let mut acc1 = _mm256_setzero_ps();
+            let mut acc2 = _mm256_setzero_ps();
+            let mut acc3 = _mm256_setzero_ps();
+            for _ in 0..n / 32 {
+                let x0 = _mm256_loadu_ps(x);
+                let x1 = _mm256_loadu_ps(x.add(8));
+                let x2 = _mm256_loadu_ps(x.add(16));
+                let x3 = _mm256_loadu_ps(x.add(24));
+
+                acc0 = fmadd_ps(x0, x0, acc0);
+                acc1 = fmadd_ps(x1, x1, acc1);
+                acc2 = fmadd_ps(x2, x2, acc2);
+                acc3 = fmadd_ps(x3, x3, acc3);
+
+                x = x.add(32);
+            }
+            acc0 = _mm256_add_ps(acc0, acc1);
+            acc2 = _mm256_add_ps(acc2, acc3);
+            acc0 = _mm256_add_ps(acc0, acc2);
+
+            let mut acc = hsum_ps(acc0);
+            for _ in 0..n % 32 {
+                let x0 = *x;
+                acc += x0 * x0;
+                x = x.add(1);
+            }
+
+            if snrm2_sumsq_ok(n, acc) {
+                return acc.sqrt();
+            }
+
+            snrm2_blue(n, base)
+        } else {
+            let (mut asml, mut amed, mut abig) = (0., 0., 0.);
+            for _ in 0..n {
+                snrm2_acc(*x, &mut asml, &mut amed, &mut abig);
+                x = x.wrapping_offset(incx);
+            }
+            snrm2_combine(asml, amed, abig)
         }
     }
-}
 
-pub unsafe fn sdot(
-    n: usize,
-    mut x: *const f32,
-    incx: usize,
-    mut y: *const f32,
-    incy: usize,
-) -> f32 {
-    if incx == 1 && incy == 1 {
-        let mut acc0 = _mm256_setzero_ps();
-        let mut acc1 = _mm256_setzero_ps();
-        let mut acc2 = _mm256_setzero_ps();
-        let mut acc3 = _mm256_setzero_ps();
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_ps(x);
-            let y0 = _mm256_load_ps(y);
-            let x1 = _mm256_load_ps(x.add(8));
-            let y1 = _mm256_load_ps(y.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let y2 = _mm256_load_ps(y.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-            let y3 = _mm256_load_ps(y.add(24));
-
-            acc0 = fmadd_ps(x0, y0, acc0);
-            acc1 = fmadd_ps(x1, y1, acc1);
-            acc2 = fmadd_ps(x2, y2, acc2);
-            acc3 = fmadd_ps(x3, y3, acc3);
-
-            x = x.add(32);
-            y = y.add(32);
+    // Unit-stride Blue's algorithm, for vectors whose plain sum of squares
+    // overflowed or lost too much to underflow.
+    unsafe fn snrm2_blue(n: usize, mut x: *const f32) -> f32 {
+        let mask = _mm256_broadcast_ss(&f32::from_bits(0x7FFF_FFFF));
+        let tsml = _mm256_broadcast_ss(&TSML);
+        let tbig = _mm256_broadcast_ss(&TBIG);
+        let ssml = _mm256_broadcast_ss(&SSML);
+        let sbig = _mm256_broadcast_ss(&SBIG);
+
+        let mut asml0 = _mm256_setzero_ps();
+        let mut amed0 = _mm256_setzero_ps();
+        let mut abig0 = _mm256_setzero_ps();
+        for _ in 0..n / 8 {
+            let x0 = _mm256_and_ps(mask, _mm256_loadu_ps(x));
+
+            // NaNs compare false and are summed with the medium values.
+            let big = _mm256_cmp_ps(x0, tbig, _CMP_GT_OQ);
+            let sml = _mm256_cmp_ps(x0, tsml, _CMP_LT_OQ);
+
+            let xb = _mm256_and_ps(big, _mm256_mul_ps(x0, sbig));
+            let xs = _mm256_and_ps(sml, _mm256_mul_ps(x0, ssml));
+            let xm = _mm256_andnot_ps(_mm256_or_ps(big, sml), x0);
+
+            abig0 = fmadd_ps(xb, xb, abig0);
+            asml0 = fmadd_ps(xs, xs, asml0);
+            amed0 = fmadd_ps(xm, xm, amed0);
+
+            x = x.add(8);
         }
-        acc0 = _mm256_add_ps(acc0, acc1);
-        acc2 = _mm256_add_ps(acc2, acc3);
-        acc0 = _mm256_add_ps(acc0, acc2);
 
-        let mut acc = hsum_ps(acc0);
-        for _ in 0..n % 32 {
-            acc += *x * *y;
+        let mut asml = hsum_ps(asml0);
+        let mut amed = hsum_ps(amed0);
+        let mut abig = hsum_ps(abig0);
+        for _ in 0..n % 8 {
+            snrm2_acc(*x, &mut asml, &mut amed, &mut abig);
             x = x.add(1);
-            y = y.add(1);
-        }
-        acc
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            acc += *x * *y;
-            x = x.add(incx);
-            y = y.add(incy);
         }
-        acc
+
+        snrm2_combine(asml, amed, abig)
     }
-}
 
-pub unsafe fn snrm2(n: usize, mut x: *const f32, incx: usize) -> f32 {
-    if incx == 1 {
-        let mut acc0 = _mm256_setzero_ps();
-        let mut acc1 = _mm256_setzero_ps();
-        let mut acc2 = _mm256_setzero_ps();
-        let mut acc3 = _mm256_setzero_ps();
-        for _ in 0..n / 32 {
-            let x0 = _mm256_load_ps(x);
-            let x1 = _mm256_load_ps(x.add(8));
-            let x2 = _mm256_load_ps(x.add(16));
-            let x3 = _mm256_load_ps(x.add(24));
-
-            acc0 = fmadd_ps(x0, x0, acc0);
-            acc1 = fmadd_ps(x1, x1, acc1);
-            acc2 = fmadd_ps(x2, x2, acc2);
-            acc3 = fmadd_ps(x3, x3, acc3);
-
-            x = x.add(32);
+    pub unsafe fn sasum(n: usize, mut x: *const f32, incx: isize) -> f32 {
+        if incx == 1 {
+            let mask = _mm256_broadcast_ss(&f32::from_bits(0x7FFF_FFFF));
+
+            let mut acc0 = _mm256_setzero_ps();
+            let mut acc1 = _mm256_setzero_ps();
+            let mut acc2 = _mm256_setzero_ps();
+            let mut acc3 = _mm256_setzero_ps();
+            let mut acc4 = _mm256_setzero_ps();
+            let mut acc5 = _mm256_setzero_ps();
+            let mut acc6 = _mm256_setzero_ps();
+            let mut acc7 = _mm256_setzero_ps();
+            for _ in 0..n / 64 {
+                let mut x0 = _mm256_loadu_ps(x);
+                let mut x1 = _mm256_loadu_ps(x.add(8));
+                let mut x2 = _mm256_loadu_ps(x.add(16));
+                let mut x3 = _mm256_loadu_ps(x.add(24));
+                let mut x4 = _mm256_loadu_ps(x.add(32));
+                let mut x5 = _mm256_loadu_ps(x.add(40));
+                let mut x6 = _mm256_loadu_ps(x.add(48));
+                let mut x7 = _mm256_loadu_ps(x.add(56));
+
+                x0 = _mm256_and_ps(mask, x0);
+                x1 = _mm256_and_ps(mask, x1);
+                x2 = _mm256_and_ps(mask, x2);
+                x3 = _mm256_and_ps(mask, x3);
+                x4 = _mm256_and_ps(mask, x4);
+                x5 = _mm256_and_ps(mask, x5);
+                x6 = _mm256_and_ps(mask, x6);
+                x7 = _mm256_and_ps(mask, x7);
+
+                acc0 = _mm256_add_ps(acc0, x0);
+                acc1 = _mm256_add_ps(acc1, x1);
+                acc2 = _mm256_add_ps(acc2, x2);
+                acc3 = _mm256_add_ps(acc3, x3);
+                acc4 = _mm256_add_ps(acc4, x4);
+                acc5 = _mm256_add_ps(acc5, x5);
+                acc6 = _mm256_add_ps(acc6, x6);
+                acc7 = _mm256_add_ps(acc7, x7);
+
+                x = x.add(64);
+            }
+            acc0 = _mm256_add_ps(acc0, acc1);
+            acc2 = _mm256_add_ps(acc2, acc3);
+            acc4 = _mm256_add_ps(acc4, acc5);
+            acc6 = _mm256_add_ps(acc6, acc7);
+
+            acc0 = _mm256_add_ps(acc0, acc2);
+            acc4 = _mm256_add_ps(acc4, acc6);
+
+            acc0 = _mm256_add_ps(acc0, acc4);
+
+            let mut acc = hsum_ps(acc0);
+            for _ in 0..n % 64 {
+                acc += (*x).abs();
+                x = x.add(1);
+            }
+            acc
+        } else {
+            let mut acc = 0.0;
+            for _ in 0..n {
+                acc += (*x).abs();
+                x = x.wrapping_offset(incx);
+            }
+            acc
         }
-        acc0 = _mm256_add_ps(acc0, acc1);
-        acc2 = _mm256_add_ps(acc2, acc3);
-        acc0 = _mm256_add_ps(acc0, acc2);
-
-        let mut acc = hsum_ps(acc0);
-        for _ in 0..n % 32 {
-            let x0 = *x;
-            acc += x0 * x0;
-            x = x.add(1);
-        }
-        acc.sqrt()
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            let x0 = *x;
-            acc += x0 * x0;
-            x = x.add(incx);
-        }
-        acc.sqrt()
     }
-}
-
-pub unsafe fn sasum(n: usize, mut x: *const f32, incx: usize) -> f32 {
-    if incx == 1 {
-        let mask = _mm256_broadcast_ss(&f32::from_bits(0x7FFF_FFFF));
 
-        let mut acc0 = _mm256_setzero_ps();
-        let mut acc1 = _mm256_setzero_ps();
-        let mut acc2 = _mm256_setzero_ps();
-        let mut acc3 = _mm256_setzero_ps();
-        let mut acc4 = _mm256_setzero_ps();
-        let mut acc5 = _mm256_setzero_ps();
-        let mut acc6 = _mm256_setzero_ps();
-        let mut acc7 = _mm256_setzero_ps();
-        for _ in 0..n / 64 {
-            let mut x0 = _mm256_load_ps(x);
-            let mut x1 = _mm256_load_ps(x.add(8));
-            let mut x2 = _mm256_load_ps(x.add(16));
-            let mut x3 = _mm256_load_ps(x.add(24));
-            let mut x4 = _mm256_load_ps(x.add(32));
-            let mut x5 = _mm256_load_ps(x.add(40));
-            let mut x6 = _mm256_load_ps(x.add(48));
-            let mut x7 = _mm256_load_ps(x.add(56));
-
-            x0 = _mm256_and_ps(mask, x0);
-            x1 = _mm256_and_ps(mask, x1);
-            x2 = _mm256_and_ps(mask, x2);
-            x3 = _mm256_and_ps(mask, x3);
-            x4 = _mm256_and_ps(mask, x4);
-            x5 = _mm256_and_ps(mask, x5);
-            x6 = _mm256_and_ps(mask, x6);
-            x7 = _mm256_and_ps(mask, x7);
-
-            acc0 = _mm256_add_ps(acc0, x0);
-            acc1 = _mm256_add_ps(acc1, x1);
-            acc2 = _mm256_add_ps(acc2, x2);
-            acc3 = _mm256_add_ps(acc3, x3);
-            acc4 = _mm256_add_ps(acc4, x4);
-            acc5 = _mm256_add_ps(acc5, x5);
-            acc6 = _mm256_add_ps(acc6, x6);
-            acc7 = _mm256_add_ps(acc7, x7);
-
-            x = x.add(64);
+    pub unsafe fn isamax(n: usize, x: *const f32, incx: isize) -> usize {
+        if incx == 1 {
+            // The strided search below starts from |x[0]| and never moves past a
+            // NaN there, as in the reference BLAS.
+            if (*x).is_nan() {
+                return 0;
+            }
+
+            let mask = _mm256_broadcast_ss(&f32::from_bits(0x7FFF_FFFF));
+
+            let mut max0 = _mm256_setzero_ps();
+            let mut max1 = max0;
+            let mut max2 = max0;
+            let mut max3 = max0;
+            let mut px = x;
+            for _ in 0..n / 32 {
+                let x0 = _mm256_and_ps(mask, _mm256_loadu_ps(px));
+                let x1 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(8)));
+                let x2 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(16)));
+                let x3 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(24)));
+
+                // NaNs in x are passed over, as the second operand is returned.
+                max0 = _mm256_max_ps(x0, max0);
+                max1 = _mm256_max_ps(x1, max1);
+                max2 = _mm256_max_ps(x2, max2);
+                max3 = _mm256_max_ps(x3, max3);
+
+                px = px.add(32);
+            }
+            max0 = _mm256_max_ps(max0, max1);
+            max2 = _mm256_max_ps(max2, max3);
+            max0 = _mm256_max_ps(max0, max2);
+
+            let mut max = hmax_ps(max0);
+            for _ in 0..n % 32 {
+                let x0 = (*px).abs();
+                if x0 > max {
+                    max = x0;
+                }
+                px = px.add(1);
+            }
+
+            // Rescan for the first element reaching the extremum.
+            let max0 = _mm256_broadcast_ss(&max);
+            let mut i = 0;
+            while i + 8 <= n {
+                let x0 = _mm256_and_ps(mask, _mm256_loadu_ps(x.add(i)));
+                let eq = _mm256_movemask_ps(_mm256_cmp_ps(x0, max0, _CMP_EQ_OQ));
+                if eq != 0 {
+                    return i + eq.trailing_zeros() as usize;
+                }
+                i += 8;
+            }
+            while i < n {
+                if (*x.add(i)).abs() == max {
+                    return i;
+                }
+                i += 1;
+            }
+            0
+        } else {
+            let mut imax = 0;
+            let mut max = (*x).abs();
+            let mut px = x;
+            for i in 1..n {
+                px = px.wrapping_offset(incx);
+                let x0 = (*px).abs();
+                if x0 > max {
+                    imax = i;
+                    max = x0;
+                }
+            }
+            imax
         }
-        acc0 = _mm256_add_ps(acc0, acc1);
-        acc2 = _mm256_add_ps(acc2, acc3);
-        acc4 = _mm256_add_ps(acc4, acc5);
-        acc6 = _mm256_add_ps(acc6, acc7);
-
-        acc0 = _mm256_add_ps(acc0, acc2);
-        acc4 = _mm256_add_ps(acc4, acc6);
-
-        acc0 = _mm256_add_ps(acc0, acc4);
+    }
 
-        let mut acc = hsum_ps(acc0);
-        for _ in 0..n % 64 {
-            acc += (*x).abs();
-            x = x.add(1);
-        }
-        acc
-    } else {
-        let mut acc = 0.0;
-        for _ in 0..n {
-            acc += (*x).abs();
-            x = x.add(incx);
+    pub unsafe fn isamin(n: usize, x: *const f32, incx: isize) -> usize {
+        if incx == 1 {
+            // The strided search below starts from |x[0]| and never moves past a
+            // NaN there, as in the reference BLAS.
+            if (*x).is_nan() {
+                return 0;
+            }
+
+            let mask = _mm256_broadcast_ss(&f32::from_bits(0x7FFF_FFFF));
+
+            let mut min0 = _mm256_broadcast_ss(&f32::INFINITY);
+            let mut min1 = min0;
+            let mut min2 = min0;
+            let mut min3 = min0;
+            let mut px = x;
+            for _ in 0..n / 32 {
+                let x0 = _mm256_and_ps(mask, _mm256_loadu_ps(px));
+                let x1 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(8)));
+                let x2 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(16)));
+                let x3 = _mm256_and_ps(mask, _mm256_loadu_ps(px.add(24)));
+
+                // NaNs in x are passed over, as the second operand is returned.
+                min0 = _mm256_min_ps(x0, min0);
+                min1 = _mm256_min_ps(x1, min1);
+                min2 = _mm256_min_ps(x2, min2);
+                min3 = _mm256_min_ps(x3, min3);
+
+                px = px.add(32);
+            }
+            min0 = _mm256_min_ps(min0, min1);
+            min2 = _mm256_min_ps(min2, min3);
+            min0 = _mm256_min_ps(min0, min2);
+
+            let mut min = hmin_ps(min0);
+            for _ in 0..n % 32 {
+                let x0 = (*px).abs();
+                if x0 < min {
+                    min = x0;
+                }
+                px = px.add(1);
+            }
+
+            // Rescan for the first element reaching the extremum.
+            let min0 = _mm256_broadcast_ss(&min);
+            let mut i = 0;
+            while i + 8 <= n {
+                let x0 = _mm256_and_ps(mask, _mm256_loadu_ps(x.add(i)));
+                let eq = _mm256_movemask_ps(_mm256_cmp_ps(x0, min0, _CMP_EQ_OQ));
+                if eq != 0 {
+                    return i + eq.trailing_zeros() as usize;
+                }
+                i += 8;
+            }
+            while i < n {
+                if (*x.add(i)).abs() == min {
+                    return i;
+                }
+                i += 1;
+            }
+            0
+        } else {
+            let mut imin = 0;
+            let mut min = (*x).abs();
+            let mut px = x;
+            for i in 1..n {
+                px = px.wrapping_offset(incx);
+                let x0 = (*px).abs();
+                if x0 < min {
+                    imin = i;
+                    min = x0;
+                }
+            }
+            imin
         }
-        acc
     }
 }
@@ -0,0 +1,5 @@
+#[cfg(target_arch = "x86")]
+pub use std::arch::x86::*;
+
+#[cfg(target_arch = "x86_64")]
+pub use std::arch::x86_64::*;
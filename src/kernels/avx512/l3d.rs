@@ -0,0 +1,306 @@
+use super::intrinsics::*;
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn dgemm_ukr_16x12(
+    k: usize,
+    alpha: f64,
+    pa: *const f64,
+    pb: *const f64,
+    beta: f64,
+    c: *mut f64,
+    ldc: usize,
+) {
+    let mut mt00 = _mm512_setzero_pd();
+    let mut mt01 = _mm512_setzero_pd();
+    let mut mt02 = _mm512_setzero_pd();
+    let mut mt03 = _mm512_setzero_pd();
+    let mut mt04 = _mm512_setzero_pd();
+    let mut mt05 = _mm512_setzero_pd();
+    let mut mt06 = _mm512_setzero_pd();
+    let mut mt07 = _mm512_setzero_pd();
+    let mut mt08 = _mm512_setzero_pd();
+    let mut mt09 = _mm512_setzero_pd();
+    let mut mt0a = _mm512_setzero_pd();
+    let mut mt0b = _mm512_setzero_pd();
+    let mut mt10 = _mm512_setzero_pd();
+    let mut mt11 = _mm512_setzero_pd();
+    let mut mt12 = _mm512_setzero_pd();
+    let mut mt13 = _mm512_setzero_pd();
+    let mut mt14 = _mm512_setzero_pd();
+    let mut mt15 = _mm512_setzero_pd();
+    let mut mt16 = _mm512_setzero_pd();
+    let mut mt17 = _mm512_setzero_pd();
+    let mut mt18 = _mm512_setzero_pd();
+    let mut mt19 = _mm512_setzero_pd();
+    let mut mt1a = _mm512_setzero_pd();
+    let mut mt1b = _mm512_setzero_pd();
+
+    let mut pa = pa;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        let a0 = _mm512_load_pd(pa);
+        let a1 = _mm512_load_pd(pa.add(8));
+
+        let b0 = _mm512_set1_pd(*pb);
+        mt00 = _mm512_fmadd_pd(a0, b0, mt00);
+        mt10 = _mm512_fmadd_pd(a1, b0, mt10);
+        let b1 = _mm512_set1_pd(*pb.add(1));
+        mt01 = _mm512_fmadd_pd(a0, b1, mt01);
+        mt11 = _mm512_fmadd_pd(a1, b1, mt11);
+        let b2 = _mm512_set1_pd(*pb.add(2));
+        mt02 = _mm512_fmadd_pd(a0, b2, mt02);
+        mt12 = _mm512_fmadd_pd(a1, b2, mt12);
+        let b3 = _mm512_set1_pd(*pb.add(3));
+        mt03 = _mm512_fmadd_pd(a0, b3, mt03);
+        mt13 = _mm512_fmadd_pd(a1, b3, mt13);
+        let b4 = _mm512_set1_pd(*pb.add(4));
+        mt04 = _mm512_fmadd_pd(a0, b4, mt04);
+        mt14 = _mm512_fmadd_pd(a1, b4, mt14);
+        let b5 = _mm512_set1_pd(*pb.add(5));
+        mt05 = _mm512_fmadd_pd(a0, b5, mt05);
+        mt15 = _mm512_fmadd_pd(a1, b5, mt15);
+        let b6 = _mm512_set1_pd(*pb.add(6));
+        mt06 = _mm512_fmadd_pd(a0, b6, mt06);
+        mt16 = _mm512_fmadd_pd(a1, b6, mt16);
+        let b7 = _mm512_set1_pd(*pb.add(7));
+        mt07 = _mm512_fmadd_pd(a0, b7, mt07);
+        mt17 = _mm512_fmadd_pd(a1, b7, mt17);
+        let b8 = _mm512_set1_pd(*pb.add(8));
+        mt08 = _mm512_fmadd_pd(a0, b8, mt08);
+        mt18 = _mm512_fmadd_pd(a1, b8, mt18);
+        let b9 = _mm512_set1_pd(*pb.add(9));
+        mt09 = _mm512_fmadd_pd(a0, b9, mt09);
+        mt19 = _mm512_fmadd_pd(a1, b9, mt19);
+        let ba = _mm512_set1_pd(*pb.add(10));
+        mt0a = _mm512_fmadd_pd(a0, ba, mt0a);
+        mt1a = _mm512_fmadd_pd(a1, ba, mt1a);
+        let bb = _mm512_set1_pd(*pb.add(11));
+        mt0b = _mm512_fmadd_pd(a0, bb, mt0b);
+        mt1b = _mm512_fmadd_pd(a1, bb, mt1b);
+
+        pa = pa.add(16);
+        pb = pb.add(12);
+    }
+
+    let alpha = _mm512_set1_pd(alpha);
+
+    mt00 = _mm512_mul_pd(alpha, mt00);
+    mt01 = _mm512_mul_pd(alpha, mt01);
+    mt02 = _mm512_mul_pd(alpha, mt02);
+    mt03 = _mm512_mul_pd(alpha, mt03);
+    mt04 = _mm512_mul_pd(alpha, mt04);
+    mt05 = _mm512_mul_pd(alpha, mt05);
+    mt06 = _mm512_mul_pd(alpha, mt06);
+    mt07 = _mm512_mul_pd(alpha, mt07);
+    mt08 = _mm512_mul_pd(alpha, mt08);
+    mt09 = _mm512_mul_pd(alpha, mt09);
+    mt0a = _mm512_mul_pd(alpha, mt0a);
+    mt0b = _mm512_mul_pd(alpha, mt0b);
+    mt10 = _mm512_mul_pd(alpha, mt10);
+    mt11 = _mm512_mul_pd(alpha, mt11);
+    mt12 = _mm512_mul_pd(alpha, mt12);
+    mt13 = _mm512_mul_pd(alpha, mt13);
+    mt14 = _mm512_mul_pd(alpha, mt14);
+    mt15 = _mm512_mul_pd(alpha, mt15);
+    mt16 = _mm512_mul_pd(alpha, mt16);
+    mt17 = _mm512_mul_pd(alpha, mt17);
+    mt18 = _mm512_mul_pd(alpha, mt18);
+    mt19 = _mm512_mul_pd(alpha, mt19);
+    mt1a = _mm512_mul_pd(alpha, mt1a);
+    mt1b = _mm512_mul_pd(alpha, mt1b);
+
+    let ccol0 = c;
+    let ccol1 = c.add(ldc);
+    let ccol2 = c.add(ldc * 2);
+    let ccol3 = c.add(ldc * 3);
+    let ccol4 = c.add(ldc * 4);
+    let ccol5 = c.add(ldc * 5);
+    let ccol6 = c.add(ldc * 6);
+    let ccol7 = c.add(ldc * 7);
+    let ccol8 = c.add(ldc * 8);
+    let ccol9 = c.add(ldc * 9);
+    let ccola = c.add(ldc * 10);
+    let ccolb = c.add(ldc * 11);
+
+    if beta != 0.0 {
+        let beta = _mm512_set1_pd(beta);
+
+        mt00 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol0), mt00);
+        mt01 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol1), mt01);
+        mt02 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol2), mt02);
+        mt03 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol3), mt03);
+        mt04 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol4), mt04);
+        mt05 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol5), mt05);
+        mt06 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol6), mt06);
+        mt07 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol7), mt07);
+        mt08 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol8), mt08);
+        mt09 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol9), mt09);
+        mt0a = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccola), mt0a);
+        mt0b = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccolb), mt0b);
+        mt10 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol0.add(8)), mt10);
+        mt11 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol1.add(8)), mt11);
+        mt12 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol2.add(8)), mt12);
+        mt13 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol3.add(8)), mt13);
+        mt14 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol4.add(8)), mt14);
+        mt15 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol5.add(8)), mt15);
+        mt16 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol6.add(8)), mt16);
+        mt17 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol7.add(8)), mt17);
+        mt18 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol8.add(8)), mt18);
+        mt19 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccol9.add(8)), mt19);
+        mt1a = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccola.add(8)), mt1a);
+        mt1b = _mm512_fmadd_pd(beta, _mm512_loadu_pd(ccolb.add(8)), mt1b);
+    }
+
+    _mm512_storeu_pd(ccol0, mt00);
+    _mm512_storeu_pd(ccol1, mt01);
+    _mm512_storeu_pd(ccol2, mt02);
+    _mm512_storeu_pd(ccol3, mt03);
+    _mm512_storeu_pd(ccol4, mt04);
+    _mm512_storeu_pd(ccol5, mt05);
+    _mm512_storeu_pd(ccol6, mt06);
+    _mm512_storeu_pd(ccol7, mt07);
+    _mm512_storeu_pd(ccol8, mt08);
+    _mm512_storeu_pd(ccol9, mt09);
+    _mm512_storeu_pd(ccola, mt0a);
+    _mm512_storeu_pd(ccolb, mt0b);
+    _mm512_storeu_pd(ccol0.add(8), mt10);
+    _mm512_storeu_pd(ccol1.add(8), mt11);
+    _mm512_storeu_pd(ccol2.add(8), mt12);
+    _mm512_storeu_pd(ccol3.add(8), mt13);
+    _mm512_storeu_pd(ccol4.add(8), mt14);
+    _mm512_storeu_pd(ccol5.add(8), mt15);
+    _mm512_storeu_pd(ccol6.add(8), mt16);
+    _mm512_storeu_pd(ccol7.add(8), mt17);
+    _mm512_storeu_pd(ccol8.add(8), mt18);
+    _mm512_storeu_pd(ccol9.add(8), mt19);
+    _mm512_storeu_pd(ccola.add(8), mt1a);
+    _mm512_storeu_pd(ccolb.add(8), mt1b);
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn dgemm_sup_16x1(
+    k: usize,
+    alpha: f64,
+    pa: *const f64,
+    b: *const f64,
+    rsb: usize,
+    beta: f64,
+    c: *mut f64,
+) {
+    let mut mt0 = _mm512_setzero_pd();
+    let mut mt1 = _mm512_setzero_pd();
+
+    let mut pa = pa;
+    let mut b = b;
+
+    for _ in 0..k {
+        let a0 = _mm512_load_pd(pa);
+        let a1 = _mm512_load_pd(pa.add(8));
+
+        let b0 = _mm512_set1_pd(*b);
+
+        mt0 = _mm512_fmadd_pd(a0, b0, mt0);
+        mt1 = _mm512_fmadd_pd(a1, b0, mt1);
+
+        pa = pa.add(16);
+        b = b.add(rsb);
+    }
+
+    let alpha = _mm512_set1_pd(alpha);
+
+    mt0 = _mm512_mul_pd(alpha, mt0);
+    mt1 = _mm512_mul_pd(alpha, mt1);
+
+    if beta != 0.0 {
+        let beta = _mm512_set1_pd(beta);
+
+        mt0 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(c), mt0);
+        mt1 = _mm512_fmadd_pd(beta, _mm512_loadu_pd(c.add(8)), mt1);
+    }
+
+    _mm512_storeu_pd(c, mt0);
+    _mm512_storeu_pd(c.add(8), mt1);
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn dgemm_sup_1x12(
+    k: usize,
+    alpha: f64,
+    a: *const f64,
+    csa: usize,
+    pb: *const f64,
+    beta: f64,
+    c: *mut f64,
+    ldc: usize,
+) {
+    let mut mt = [0.0; 12];
+
+    let mut a = a;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        let a0 = *a;
+        let b = &*(pb as *const [f64; 12]);
+
+        for (mt0, b0) in mt.iter_mut().zip(b) {
+            *mt0 += a0 * b0;
+        }
+
+        a = a.add(csa);
+        pb = pb.add(12);
+    }
+
+    for (j, mt0) in mt.iter().enumerate() {
+        let c = c.add(j * ldc);
+        let mut elem = alpha * mt0;
+
+        if beta != 0.0 {
+            elem += beta * *c;
+        }
+
+        *c = elem;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn dgemm_pa_16x(k: usize, a: *const f64, rsa: usize, csa: usize, pa: *mut f64) {
+    if rsa == 1 {
+        let mut a = a;
+        let mut pa = pa;
+
+        for _ in 0..k {
+            _mm512_store_pd(pa, _mm512_loadu_pd(a));
+            _mm512_store_pd(pa.add(8), _mm512_loadu_pd(a.add(8)));
+
+            pa = pa.add(16);
+            a = a.add(csa);
+        }
+    } else {
+        for i in 0..16 {
+            let mut a = a.add(i * rsa);
+            let mut pa = pa.add(i);
+
+            for _ in 0..k {
+                *pa = *a;
+
+                pa = pa.add(16);
+                a = a.add(csa);
+            }
+        }
+    }
+}
+
+pub(crate) unsafe fn dgemm_pb_x12(k: usize, b: *const f64, rsb: usize, csb: usize, pb: *mut f64) {
+    let mut b = b;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        for j in 0..12 {
+            *pb.add(j) = *b.add(j * csb);
+        }
+
+        b = b.add(rsb);
+        pb = pb.add(12);
+    }
+}
@@ -0,0 +1,306 @@
+use super::intrinsics::*;
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn sgemm_ukr_32x12(
+    k: usize,
+    alpha: f32,
+    pa: *const f32,
+    pb: *const f32,
+    beta: f32,
+    c: *mut f32,
+    ldc: usize,
+) {
+    let mut mt00 = _mm512_setzero_ps();
+    let mut mt01 = _mm512_setzero_ps();
+    let mut mt02 = _mm512_setzero_ps();
+    let mut mt03 = _mm512_setzero_ps();
+    let mut mt04 = _mm512_setzero_ps();
+    let mut mt05 = _mm512_setzero_ps();
+    let mut mt06 = _mm512_setzero_ps();
+    let mut mt07 = _mm512_setzero_ps();
+    let mut mt08 = _mm512_setzero_ps();
+    let mut mt09 = _mm512_setzero_ps();
+    let mut mt0a = _mm512_setzero_ps();
+    let mut mt0b = _mm512_setzero_ps();
+    let mut mt10 = _mm512_setzero_ps();
+    let mut mt11 = _mm512_setzero_ps();
+    let mut mt12 = _mm512_setzero_ps();
+    let mut mt13 = _mm512_setzero_ps();
+    let mut mt14 = _mm512_setzero_ps();
+    let mut mt15 = _mm512_setzero_ps();
+    let mut mt16 = _mm512_setzero_ps();
+    let mut mt17 = _mm512_setzero_ps();
+    let mut mt18 = _mm512_setzero_ps();
+    let mut mt19 = _mm512_setzero_ps();
+    let mut mt1a = _mm512_setzero_ps();
+    let mut mt1b = _mm512_setzero_ps();
+
+    let mut pa = pa;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        let a0 = _mm512_load_ps(pa);
+        let a1 = _mm512_load_ps(pa.add(16));
+
+        let b0 = _mm512_set1_ps(*pb);
+        mt00 = _mm512_fmadd_ps(a0, b0, mt00);
+        mt10 = _mm512_fmadd_ps(a1, b0, mt10);
+        let b1 = _mm512_set1_ps(*pb.add(1));
+        mt01 = _mm512_fmadd_ps(a0, b1, mt01);
+        mt11 = _mm512_fmadd_ps(a1, b1, mt11);
+        let b2 = _mm512_set1_ps(*pb.add(2));
+        mt02 = _mm512_fmadd_ps(a0, b2, mt02);
+        mt12 = _mm512_fmadd_ps(a1, b2, mt12);
+        let b3 = _mm512_set1_ps(*pb.add(3));
+        mt03 = _mm512_fmadd_ps(a0, b3, mt03);
+        mt13 = _mm512_fmadd_ps(a1, b3, mt13);
+        let b4 = _mm512_set1_ps(*pb.add(4));
+        mt04 = _mm512_fmadd_ps(a0, b4, mt04);
+        mt14 = _mm512_fmadd_ps(a1, b4, mt14);
+        let b5 = _mm512_set1_ps(*pb.add(5));
+        mt05 = _mm512_fmadd_ps(a0, b5, mt05);
+        mt15 = _mm512_fmadd_ps(a1, b5, mt15);
+        let b6 = _mm512_set1_ps(*pb.add(6));
+        mt06 = _mm512_fmadd_ps(a0, b6, mt06);
+        mt16 = _mm512_fmadd_ps(a1, b6, mt16);
+        let b7 = _mm512_set1_ps(*pb.add(7));
+        mt07 = _mm512_fmadd_ps(a0, b7, mt07);
+        mt17 = _mm512_fmadd_ps(a1, b7, mt17);
+        let b8 = _mm512_set1_ps(*pb.add(8));
+        mt08 = _mm512_fmadd_ps(a0, b8, mt08);
+        mt18 = _mm512_fmadd_ps(a1, b8, mt18);
+        let b9 = _mm512_set1_ps(*pb.add(9));
+        mt09 = _mm512_fmadd_ps(a0, b9, mt09);
+        mt19 = _mm512_fmadd_ps(a1, b9, mt19);
+        let ba = _mm512_set1_ps(*pb.add(10));
+        mt0a = _mm512_fmadd_ps(a0, ba, mt0a);
+        mt1a = _mm512_fmadd_ps(a1, ba, mt1a);
+        let bb = _mm512_set1_ps(*pb.add(11));
+        mt0b = _mm512_fmadd_ps(a0, bb, mt0b);
+        mt1b = _mm512_fmadd_ps(a1, bb, mt1b);
+
+        pa = pa.add(32);
+        pb = pb.add(12);
+    }
+
+    let alpha = _mm512_set1_ps(alpha);
+
+    mt00 = _mm512_mul_ps(alpha, mt00);
+    mt01 = _mm512_mul_ps(alpha, mt01);
+    mt02 = _mm512_mul_ps(alpha, mt02);
+    mt03 = _mm512_mul_ps(alpha, mt03);
+    mt04 = _mm512_mul_ps(alpha, mt04);
+    mt05 = _mm512_mul_ps(alpha, mt05);
+    mt06 = _mm512_mul_ps(alpha, mt06);
+    mt07 = _mm512_mul_ps(alpha, mt07);
+    mt08 = _mm512_mul_ps(alpha, mt08);
+    mt09 = _mm512_mul_ps(alpha, mt09);
+    mt0a = _mm512_mul_ps(alpha, mt0a);
+    mt0b = _mm512_mul_ps(alpha, mt0b);
+    mt10 = _mm512_mul_ps(alpha, mt10);
+    mt11 = _mm512_mul_ps(alpha, mt11);
+    mt12 = _mm512_mul_ps(alpha, mt12);
+    mt13 = _mm512_mul_ps(alpha, mt13);
+    mt14 = _mm512_mul_ps(alpha, mt14);
+    mt15 = _mm512_mul_ps(alpha, mt15);
+    mt16 = _mm512_mul_ps(alpha, mt16);
+    mt17 = _mm512_mul_ps(alpha, mt17);
+    mt18 = _mm512_mul_ps(alpha, mt18);
+    mt19 = _mm512_mul_ps(alpha, mt19);
+    mt1a = _mm512_mul_ps(alpha, mt1a);
+    mt1b = _mm512_mul_ps(alpha, mt1b);
+
+    let ccol0 = c;
+    let ccol1 = c.add(ldc);
+    let ccol2 = c.add(ldc * 2);
+    let ccol3 = c.add(ldc * 3);
+    let ccol4 = c.add(ldc * 4);
+    let ccol5 = c.add(ldc * 5);
+    let ccol6 = c.add(ldc * 6);
+    let ccol7 = c.add(ldc * 7);
+    let ccol8 = c.add(ldc * 8);
+    let ccol9 = c.add(ldc * 9);
+    let ccola = c.add(ldc * 10);
+    let ccolb = c.add(ldc * 11);
+
+    if beta != 0.0 {
+        let beta = _mm512_set1_ps(beta);
+
+        mt00 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol0), mt00);
+        mt01 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol1), mt01);
+        mt02 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol2), mt02);
+        mt03 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol3), mt03);
+        mt04 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol4), mt04);
+        mt05 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol5), mt05);
+        mt06 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol6), mt06);
+        mt07 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol7), mt07);
+        mt08 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol8), mt08);
+        mt09 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol9), mt09);
+        mt0a = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccola), mt0a);
+        mt0b = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccolb), mt0b);
+        mt10 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol0.add(16)), mt10);
+        mt11 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol1.add(16)), mt11);
+        mt12 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol2.add(16)), mt12);
+        mt13 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol3.add(16)), mt13);
+        mt14 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol4.add(16)), mt14);
+        mt15 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol5.add(16)), mt15);
+        mt16 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol6.add(16)), mt16);
+        mt17 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol7.add(16)), mt17);
+        mt18 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol8.add(16)), mt18);
+        mt19 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccol9.add(16)), mt19);
+        mt1a = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccola.add(16)), mt1a);
+        mt1b = _mm512_fmadd_ps(beta, _mm512_loadu_ps(ccolb.add(16)), mt1b);
+    }
+
+    _mm512_storeu_ps(ccol0, mt00);
+    _mm512_storeu_ps(ccol1, mt01);
+    _mm512_storeu_ps(ccol2, mt02);
+    _mm512_storeu_ps(ccol3, mt03);
+    _mm512_storeu_ps(ccol4, mt04);
+    _mm512_storeu_ps(ccol5, mt05);
+    _mm512_storeu_ps(ccol6, mt06);
+    _mm512_storeu_ps(ccol7, mt07);
+    _mm512_storeu_ps(ccol8, mt08);
+    _mm512_storeu_ps(ccol9, mt09);
+    _mm512_storeu_ps(ccola, mt0a);
+    _mm512_storeu_ps(ccolb, mt0b);
+    _mm512_storeu_ps(ccol0.add(16), mt10);
+    _mm512_storeu_ps(ccol1.add(16), mt11);
+    _mm512_storeu_ps(ccol2.add(16), mt12);
+    _mm512_storeu_ps(ccol3.add(16), mt13);
+    _mm512_storeu_ps(ccol4.add(16), mt14);
+    _mm512_storeu_ps(ccol5.add(16), mt15);
+    _mm512_storeu_ps(ccol6.add(16), mt16);
+    _mm512_storeu_ps(ccol7.add(16), mt17);
+    _mm512_storeu_ps(ccol8.add(16), mt18);
+    _mm512_storeu_ps(ccol9.add(16), mt19);
+    _mm512_storeu_ps(ccola.add(16), mt1a);
+    _mm512_storeu_ps(ccolb.add(16), mt1b);
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn sgemm_sup_32x1(
+    k: usize,
+    alpha: f32,
+    pa: *const f32,
+    b: *const f32,
+    rsb: usize,
+    beta: f32,
+    c: *mut f32,
+) {
+    let mut mt0 = _mm512_setzero_ps();
+    let mut mt1 = _mm512_setzero_ps();
+
+    let mut pa = pa;
+    let mut b = b;
+
+    for _ in 0..k {
+        let a0 = _mm512_load_ps(pa);
+        let a1 = _mm512_load_ps(pa.add(16));
+
+        let b0 = _mm512_set1_ps(*b);
+
+        mt0 = _mm512_fmadd_ps(a0, b0, mt0);
+        mt1 = _mm512_fmadd_ps(a1, b0, mt1);
+
+        pa = pa.add(32);
+        b = b.add(rsb);
+    }
+
+    let alpha = _mm512_set1_ps(alpha);
+
+    mt0 = _mm512_mul_ps(alpha, mt0);
+    mt1 = _mm512_mul_ps(alpha, mt1);
+
+    if beta != 0.0 {
+        let beta = _mm512_set1_ps(beta);
+
+        mt0 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(c), mt0);
+        mt1 = _mm512_fmadd_ps(beta, _mm512_loadu_ps(c.add(16)), mt1);
+    }
+
+    _mm512_storeu_ps(c, mt0);
+    _mm512_storeu_ps(c.add(16), mt1);
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn sgemm_sup_1x12(
+    k: usize,
+    alpha: f32,
+    a: *const f32,
+    csa: usize,
+    pb: *const f32,
+    beta: f32,
+    c: *mut f32,
+    ldc: usize,
+) {
+    let mut mt = [0.0; 12];
+
+    let mut a = a;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        let a0 = *a;
+        let b = &*(pb as *const [f32; 12]);
+
+        for (mt0, b0) in mt.iter_mut().zip(b) {
+            *mt0 += a0 * b0;
+        }
+
+        a = a.add(csa);
+        pb = pb.add(12);
+    }
+
+    for (j, mt0) in mt.iter().enumerate() {
+        let c = c.add(j * ldc);
+        let mut elem = alpha * mt0;
+
+        if beta != 0.0 {
+            elem += beta * *c;
+        }
+
+        *c = elem;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn sgemm_pa_32x(k: usize, a: *const f32, rsa: usize, csa: usize, pa: *mut f32) {
+    if rsa == 1 {
+        let mut a = a;
+        let mut pa = pa;
+
+        for _ in 0..k {
+            _mm512_store_ps(pa, _mm512_loadu_ps(a));
+            _mm512_store_ps(pa.add(16), _mm512_loadu_ps(a.add(16)));
+
+            pa = pa.add(32);
+            a = a.add(csa);
+        }
+    } else {
+        for i in 0..32 {
+            let mut a = a.add(i * rsa);
+            let mut pa = pa.add(i);
+
+            for _ in 0..k {
+                *pa = *a;
+
+                pa = pa.add(32);
+                a = a.add(csa);
+            }
+        }
+    }
+}
+
+pub(crate) unsafe fn sgemm_pb_x12(k: usize, b: *const f32, rsb: usize, csb: usize, pb: *mut f32) {
+    let mut b = b;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        for j in 0..12 {
+            *pb.add(j) = *b.add(j * csb);
+        }
+
+        b = b.add(rsb);
+        pb = pb.add(12);
+    }
+}
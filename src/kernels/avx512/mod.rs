@@ -0,0 +1,55 @@
+mod intrinsics;
+pub mod l3d;
+pub mod l3s;
+
+use super::avx::{l1d, l1s};
+use super::{GemmKernels, Kernels, L1Kernels};
+
+pub(crate) static KERNELS: Kernels = Kernels {
+    l1s: L1Kernels {
+        rot: l1s::srot,
+        rotm: l1s::srotm,
+        swap: l1s::sswap,
+        scal: l1s::sscal,
+        copy: l1s::scopy,
+        axpy: l1s::saxpy,
+        dot: l1s::sdot,
+        dotaxpy: l1s::sdotaxpy,
+        nrm2: l1s::snrm2,
+        asum: l1s::sasum,
+        iamax: l1s::isamax,
+        iamin: l1s::isamin,
+    },
+    l1d: L1Kernels {
+        rot: l1d::drot,
+        rotm: l1d::drotm,
+        swap: l1d::dswap,
+        scal: l1d::dscal,
+        copy: l1d::dcopy,
+        axpy: l1d::daxpy,
+        dot: l1d::ddot,
+        dotaxpy: l1d::ddotaxpy,
+        nrm2: l1d::dnrm2,
+        asum: l1d::dasum,
+        iamax: l1d::idamax,
+        iamin: l1d::idamin,
+    },
+    sgemm: GemmKernels {
+        mr: 32,
+        nr: 12,
+        ukr: l3s::sgemm_ukr_32x12,
+        sup0: l3s::sgemm_sup_32x1,
+        sup1: l3s::sgemm_sup_1x12,
+        pa: l3s::sgemm_pa_32x,
+        pb: l3s::sgemm_pb_x12,
+    },
+    dgemm: GemmKernels {
+        mr: 16,
+        nr: 12,
+        ukr: l3d::dgemm_ukr_16x12,
+        sup0: l3d::dgemm_sup_16x1,
+        sup1: l3d::dgemm_sup_1x12,
+        pa: l3d::dgemm_pa_16x,
+        pb: l3d::dgemm_pb_x12,
+    },
+};
@@ -0,0 +1,80 @@
+// The AVX kernels compiled for CPUs that have AVX but not FMA, such as Sandy
+// Bridge and Ivy Bridge. Fused multiply-adds become a multiply and an add.
+macro_rules! kernels {
+    ($($item:item)*) => {
+        $(
+            #[target_feature(enable = "avx")]
+            $item
+        )*
+    };
+}
+
+#[path = "mul_add.rs"]
+mod fma;
+
+// The same sources as `avx`, expanded under this module's `kernels` macro.
+#[path = "../avx/l1d.rs"]
+#[allow(clippy::duplicate_mod)]
+pub mod l1d;
+#[path = "../avx/l1s.rs"]
+#[allow(clippy::duplicate_mod)]
+pub mod l1s;
+#[path = "../avx/l3d.rs"]
+#[allow(clippy::duplicate_mod)]
+pub mod l3d;
+#[path = "../avx/l3s.rs"]
+#[allow(clippy::duplicate_mod)]
+pub mod l3s;
+
+use super::avx::{hsum, intrinsics};
+use super::generic;
+use super::{GemmKernels, Kernels, L1Kernels};
+
+pub(crate) static KERNELS: Kernels = Kernels {
+    l1s: L1Kernels {
+        rot: l1s::srot,
+        rotm: l1s::srotm,
+        swap: l1s::sswap,
+        scal: l1s::sscal,
+        copy: l1s::scopy,
+        axpy: l1s::saxpy,
+        dot: l1s::sdot,
+        dotaxpy: l1s::sdotaxpy,
+        nrm2: l1s::snrm2,
+        asum: l1s::sasum,
+        iamax: l1s::isamax,
+        iamin: l1s::isamin,
+    },
+    l1d: L1Kernels {
+        rot: l1d::drot,
+        rotm: l1d::drotm,
+        swap: l1d::dswap,
+        scal: l1d::dscal,
+        copy: l1d::dcopy,
+        axpy: l1d::daxpy,
+        dot: l1d::ddot,
+        dotaxpy: l1d::ddotaxpy,
+        nrm2: l1d::dnrm2,
+        asum: l1d::dasum,
+        iamax: l1d::idamax,
+        iamin: l1d::idamin,
+    },
+    sgemm: GemmKernels {
+        mr: 16,
+        nr: 4,
+        ukr: l3s::sgemm_ukr_16x4,
+        sup0: l3s::sgemm_sup_16x1,
+        sup1: generic::l3s::sgemm_sup_1x4,
+        pa: l3s::sgemm_pa_16x,
+        pb: generic::l3s::sgemm_pb_x4,
+    },
+    dgemm: GemmKernels {
+        mr: 8,
+        nr: 4,
+        ukr: l3d::dgemm_ukr_8x4,
+        sup0: l3d::dgemm_sup_8x1,
+        sup1: generic::l3d::dgemm_sup_1x4,
+        pa: l3d::dgemm_pa_8x,
+        pb: generic::l3d::dgemm_pb_x4,
+    },
+};
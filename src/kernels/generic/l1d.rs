@@ -0,0 +1,431 @@
+use std::slice;
+
+pub unsafe fn drot(
+    n: usize,
+    mut x: *mut f64,
+    incx: isize,
+    mut y: *mut f64,
+    incy: isize,
+    c: f64,
+    s: f64,
+) {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts_mut(x, n);
+        let y = slice::from_raw_parts_mut(y, n);
+
+        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+            let x0 = *x;
+            let y0 = *y;
+
+            *x = c * x0 + s * y0;
+            *y = c * y0 - s * x0;
+        }
+    } else {
+        for _ in 0..n {
+            let x0 = *x;
+            let y0 = *y;
+
+            *x = c * x0 + s * y0;
+            *y = c * y0 - s * x0;
+
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+    }
+}
+
+pub unsafe fn drotm(
+    n: usize,
+    mut x: *mut f64,
+    incx: isize,
+    mut y: *mut f64,
+    incy: isize,
+    h11: f64,
+    h21: f64,
+    h12: f64,
+    h22: f64,
+) {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts_mut(x, n);
+        let y = slice::from_raw_parts_mut(y, n);
+
+        for (x, y) in x.iter_mut().zip(y.iter_mut()) {
+            let x0 = *x;
+            let y0 = *y;
+
+            *x = h11 * x0 + h12 * y0;
+            *y = h21 * x0 + h22 * y0;
+        }
+    } else {
+        for _ in 0..n {
+            let x0 = *x;
+            let y0 = *y;
+
+            *x = h11 * x0 + h12 * y0;
+            *y = h21 * x0 + h22 * y0;
+
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+    }
+}
+
+pub unsafe fn dswap(n: usize, mut x: *mut f64, incx: isize, mut y: *mut f64, incy: isize) {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts_mut(x, n);
+        let y = slice::from_raw_parts_mut(y, n);
+
+        x.swap_with_slice(y);
+    } else {
+        for _ in 0..n {
+            std::ptr::swap(x, y);
+
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+    }
+}
+
+pub unsafe fn dscal(n: usize, a: f64, mut x: *mut f64, incx: isize) {
+    if incx == 1 {
+        let x = slice::from_raw_parts_mut(x, n);
+
+        for x in x.iter_mut() {
+            *x *= a;
+        }
+    } else {
+        for _ in 0..n {
+            *x *= a;
+            x = x.wrapping_offset(incx);
+        }
+    }
+}
+
+pub unsafe fn dcopy(n: usize, mut x: *const f64, incx: isize, mut y: *mut f64, incy: isize) {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts(x, n);
+        let y = slice::from_raw_parts_mut(y, n);
+
+        y.copy_from_slice(x);
+    } else {
+        for _ in 0..n {
+            *y = *x;
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+    }
+}
+
+pub unsafe fn daxpy(
+    n: usize,
+    a: f64,
+    mut x: *const f64,
+    incx: isize,
+    mut y: *mut f64,
+    incy: isize,
+) {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts(x, n);
+        let y = slice::from_raw_parts_mut(y, n);
+
+        for (y, x) in y.iter_mut().zip(x.iter()) {
+            *y += a * *x;
+        }
+    } else {
+        for _ in 0..n {
+            *y += a * *x;
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+    }
+}
+
+pub unsafe fn ddot(
+    n: usize,
+    mut x: *const f64,
+    incx: isize,
+    mut y: *const f64,
+    incy: isize,
+) -> f64 {
+    if incx == 1 && incy == 1 {
+        let x = slice::from_raw_parts(x, n);
+        let y = slice::from_raw_parts(y, n);
+
+        let mut acc = [0.0f64; 4];
+        let xs = x.chunks_exact(4);
+        let ys = y.chunks_exact(4);
+        let (xr, yr) = (xs.remainder(), ys.remainder());
+
+        for (x, y) in xs.zip(ys) {
+            for ((acc, x), y) in acc.iter_mut().zip(x).zip(y) {
+                *acc += x * y;
+            }
+        }
+
+        let mut acc = acc.iter().sum::<f64>();
+        for (x, y) in xr.iter().zip(yr) {
+            acc += x * y;
+        }
+        acc
+    } else {
+        let mut acc = 0.0;
+        for _ in 0..n {
+            acc += *x * *y;
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+        }
+        acc
+    }
+}
+
+// z must not overlap x or y.
+pub unsafe fn ddotaxpy(
+    n: usize,
+    alpha: f64,
+    mut x: *const f64,
+    incx: isize,
+    mut y: *const f64,
+    incy: isize,
+    mut z: *mut f64,
+    incz: isize,
+) -> f64 {
+    if incx == 1 && incy == 1 && incz == 1 {
+        let x = slice::from_raw_parts(x, n);
+        let y = slice::from_raw_parts(y, n);
+        let z = slice::from_raw_parts_mut(z, n);
+
+        let mut acc = [0.0f64; 4];
+        let xs = x.chunks_exact(4);
+        let ys = y.chunks_exact(4);
+        let (xr, yr) = (xs.remainder(), ys.remainder());
+        let (zs, zr) = z.split_at_mut(n - n % 4);
+
+        for ((x, y), z) in xs.zip(ys).zip(zs.chunks_exact_mut(4)) {
+            for (((acc, x), y), z) in acc.iter_mut().zip(x).zip(y).zip(z) {
+                *acc += x * y;
+                *z += alpha * x;
+            }
+        }
+
+        let mut acc = acc.iter().sum::<f64>();
+        for ((x, y), z) in xr.iter().zip(yr).zip(zr) {
+            acc += x * y;
+            *z += alpha * x;
+        }
+        acc
+    } else {
+        let mut acc = 0.0;
+        for _ in 0..n {
+            acc += *x * *y;
+            *z += alpha * *x;
+            x = x.wrapping_offset(incx);
+            y = y.wrapping_offset(incy);
+            z = z.wrapping_offset(incz);
+        }
+        acc
+    }
+}
+
+pub unsafe fn dnrm2(n: usize, mut x: *const f64, incx: isize) -> f64 {
+    let (mut asml, mut amed, mut abig) = (0., 0., 0.);
+
+    if incx == 1 {
+        let x = slice::from_raw_parts(x, n);
+
+        let mut acc = [0.0f64; 4];
+        let xs = x.chunks_exact(4);
+        let xr = xs.remainder();
+
+        for x in xs {
+            for (acc, x) in acc.iter_mut().zip(x) {
+                *acc += x * x;
+            }
+        }
+
+        let mut acc = acc.iter().sum::<f64>();
+        for x in xr {
+            acc += x * x;
+        }
+
+        if dnrm2_sumsq_ok(n, acc) {
+            return acc.sqrt();
+        }
+
+        for &x in x {
+            dnrm2_acc(x, &mut asml, &mut amed, &mut abig);
+        }
+    } else {
+        for _ in 0..n {
+            dnrm2_acc(*x, &mut asml, &mut amed, &mut abig);
+            x = x.wrapping_offset(incx);
+        }
+    }
+
+    dnrm2_combine(asml, amed, abig)
+}
+
+pub unsafe fn dasum(n: usize, mut x: *const f64, incx: isize) -> f64 {
+    if incx == 1 {
+        let x = slice::from_raw_parts(x, n);
+
+        let mut acc = [0.0f64; 4];
+        let xs = x.chunks_exact(4);
+        let xr = xs.remainder();
+
+        for x in xs {
+            for (acc, x) in acc.iter_mut().zip(x) {
+                *acc += x.abs();
+            }
+        }
+
+        let mut acc = acc.iter().sum::<f64>();
+        for x in xr {
+            acc += x.abs();
+        }
+        acc
+    } else {
+        let mut acc = 0.0;
+        for _ in 0..n {
+            acc += (*x).abs();
+            x = x.wrapping_offset(incx);
+        }
+        acc
+    }
+}
+
+pub unsafe fn idamax(n: usize, x: *const f64, incx: isize) -> usize {
+    if incx == 1 {
+        // The strided search below starts from |x[0]| and never moves past a
+        // NaN there, as in the reference BLAS.
+        if (*x).is_nan() {
+            return 0;
+        }
+
+        let x = slice::from_raw_parts(x, n);
+
+        let mut acc = [0.0f64; 4];
+        let xs = x.chunks_exact(4);
+        let xr = xs.remainder();
+
+        for x in xs {
+            for (acc, x) in acc.iter_mut().zip(x) {
+                *acc = acc.max(x.abs());
+            }
+        }
+
+        let mut max = acc.iter().fold(acc[0], |a, b| a.max(*b));
+        for x in xr {
+            max = max.max(x.abs());
+        }
+
+        x.iter().position(|x| x.abs() == max).unwrap_or(0)
+    } else {
+        let mut imax = 0;
+        let mut max = (*x).abs();
+        let mut px = x;
+        for i in 1..n {
+            px = px.wrapping_offset(incx);
+            let x0 = (*px).abs();
+            if x0 > max {
+                imax = i;
+                max = x0;
+            }
+        }
+        imax
+    }
+}
+
+pub unsafe fn idamin(n: usize, x: *const f64, incx: isize) -> usize {
+    if incx == 1 {
+        // The strided search below starts from |x[0]| and never moves past a
+        // NaN there, as in the reference BLAS.
+        if (*x).is_nan() {
+            return 0;
+        }
+
+        let x = slice::from_raw_parts(x, n);
+
+        let mut acc = [f64::INFINITY; 4];
+        let xs = x.chunks_exact(4);
+        let xr = xs.remainder();
+
+        for x in xs {
+            for (acc, x) in acc.iter_mut().zip(x) {
+                *acc = acc.min(x.abs());
+            }
+        }
+
+        let mut min = acc.iter().fold(acc[0], |a, b| a.min(*b));
+        for x in xr {
+            min = min.min(x.abs());
+        }
+
+        x.iter().position(|x| x.abs() == min).unwrap_or(0)
+    } else {
+        let mut imin = 0;
+        let mut min = (*x).abs();
+        let mut px = x;
+        for i in 1..n {
+            px = px.wrapping_offset(incx);
+            let x0 = (*px).abs();
+            if x0 < min {
+                imin = i;
+                min = x0;
+            }
+        }
+        imin
+    }
+}
+
+// Blue's algorithm as in LAPACK 3.10: squares of magnitudes between TSML and
+// TBIG are summed directly, smaller ones after scaling up by SSML and larger
+// ones after scaling down by SBIG, so that no square overflows or underflows.
+pub(crate) const TSML: f64 = f64::from_bits((1023 - 511) << 52); // 2^-511
+pub(crate) const TBIG: f64 = f64::from_bits((1023 + 486) << 52); // 2^486
+pub(crate) const SSML: f64 = f64::from_bits((1023 + 537) << 52); // 2^537
+pub(crate) const SBIG: f64 = f64::from_bits((1023 - 538) << 52); // 2^-538
+
+// A plain sum of squares is accurate when it has not overflowed and the
+// squares that underflowed, each below TSML^2 = MIN_POSITIVE, add up to less
+// than its rounding error.
+#[inline(always)]
+pub(crate) fn dnrm2_sumsq_ok(n: usize, sumsq: f64) -> bool {
+    sumsq.is_finite() && sumsq * f64::EPSILON >= n as f64 * f64::MIN_POSITIVE
+}
+
+#[inline(always)]
+pub(crate) fn dnrm2_acc(x: f64, asml: &mut f64, amed: &mut f64, abig: &mut f64) {
+    let ax = x.abs();
+    if ax > TBIG {
+        *abig += (ax * SBIG) * (ax * SBIG);
+    } else if ax < TSML {
+        *asml += (ax * SSML) * (ax * SSML);
+    } else {
+        *amed += ax * ax;
+    }
+}
+
+pub(crate) fn dnrm2_combine(asml: f64, amed: f64, abig: f64) -> f64 {
+    if abig > 0. {
+        let mut abig = abig;
+        if amed > 0. || amed.is_nan() {
+            abig += (amed * SBIG) * SBIG;
+        }
+        abig.sqrt() / SBIG
+    } else if asml > 0. {
+        if amed > 0. || amed.is_nan() {
+            let amed = amed.sqrt();
+            let asml = asml.sqrt() / SSML;
+            let (ymin, ymax) = if asml > amed {
+                (amed, asml)
+            } else {
+                (asml, amed)
+            };
+            ymax * (1. + (ymin / ymax) * (ymin / ymax)).sqrt()
+        } else {
+            asml.sqrt() / SSML
+        }
+    } else {
+        amed.sqrt()
+    }
+}
@@ -1,8 +1,88 @@
+pub(crate) unsafe fn dgemm_ukr_4x4(
+    k: usize,
+    alpha: f64,
+    pa: *const f64,
+    pb: *const f64,
+    beta: f64,
+    c: *mut f64,
+    ldc: usize,
+) {
+    let mut mt = [[0.0; 4]; 4];
+
+    let mut pa = pa;
+    let mut pb = pb;
+
+    for _ in 0..k {
+        let a = &*(pa as *const [f64; 4]);
+        let b = &*(pb as *const [f64; 4]);
+
+        for (mtcol, b0) in mt.iter_mut().zip(b) {
+            for (mt0, a0) in mtcol.iter_mut().zip(a) {
+                *mt0 += a0 * b0;
+            }
+        }
+
+        pa = pa.add(4);
+        pb = pb.add(4);
+    }
+
+    for (j, mtcol) in mt.iter().enumerate() {
+        let ccol = c.add(j * ldc);
+
+        for (i, mt0) in mtcol.iter().enumerate() {
+            let mut elem = alpha * mt0;
+
+            if beta != 0.0 {
+                elem += beta * *ccol.add(i);
+            }
+
+            *ccol.add(i) = elem;
+        }
+    }
+}
+
+pub(crate) unsafe fn dgemm_sup_4x1(
+    k: usize,
+    alpha: f64,
+    pa: *const f64,
+    b: *const f64,
+    rsb: usize,
+    beta: f64,
+    c: *mut f64,
+) {
+    let mut mt = [0.0; 4];
+
+    let mut pa = pa;
+    let mut b = b;
+
+    for _ in 0..k {
+        let a = &*(pa as *const [f64; 4]);
+        let b0 = *b;
+
+        for (mt0, a0) in mt.iter_mut().zip(a) {
+            *mt0 += a0 * b0;
+        }
+
+        pa = pa.add(4);
+        b = b.add(rsb);
+    }
+
+    for (i, mt0) in mt.iter().enumerate() {
+        let mut elem = alpha * mt0;
+
+        if beta != 0.0 {
+            elem += beta * *c.add(i);
+        }
+
+        *c.add(i) = elem;
+    }
+}
+
 pub(crate) unsafe fn dgemm_sup_1x4(
     k: usize,
     alpha: f64,
     a: *const f64,
-    lda: usize,
+    csa: usize,
     pb: *const f64,
     beta: f64,
     c: *mut f64,
@@ -24,7 +104,7 @@ pub(crate) unsafe fn dgemm_sup_1x4(
         c2 += *pb.add(2) * a0;
         c3 += *pb.add(3) * a0;
 
-        a = a.add(lda);
+        a = a.add(csa);
         pb = pb.add(4);
     }
 
@@ -39,10 +119,10 @@ pub(crate) unsafe fn dgemm_sup_1x4(
     let ccol3 = c.add(ldc * 3);
 
     if beta != 0.0 {
-        c0 *= beta * *ccol0;
-        c1 *= beta * *ccol1;
-        c2 *= beta * *ccol2;
-        c3 *= beta * *ccol3;
+        c0 += beta * *ccol0;
+        c1 += beta * *ccol1;
+        c2 += beta * *ccol2;
+        c3 += beta * *ccol3;
     }
 
     *ccol0 = c0;
@@ -51,11 +131,11 @@ pub(crate) unsafe fn dgemm_sup_1x4(
     *ccol3 = c3;
 }
 
-pub(crate) unsafe fn dgemm_pb_x4(k: usize, b: *const f64, ldb: usize, pb: *mut f64) {
+pub(crate) unsafe fn dgemm_pb_x4(k: usize, b: *const f64, rsb: usize, csb: usize, pb: *mut f64) {
     let mut bcol0 = b;
-    let mut bcol1 = b.add(ldb);
-    let mut bcol2 = b.add(ldb * 2);
-    let mut bcol3 = b.add(ldb * 3);
+    let mut bcol1 = b.add(csb);
+    let mut bcol2 = b.add(csb * 2);
+    let mut bcol3 = b.add(csb * 3);
 
     let mut pb = pb;
 
@@ -65,10 +145,36 @@ pub(crate) unsafe fn dgemm_pb_x4(k: usize, b: *const f64, ldb: usize, pb: *mut f
         *pb.add(2) = *bcol2;
         *pb.add(3) = *bcol3;
 
-        bcol0 = bcol0.add(1);
-        bcol1 = bcol1.add(1);
-        bcol2 = bcol2.add(1);
-        bcol3 = bcol3.add(1);
+        bcol0 = bcol0.add(rsb);
+        bcol1 = bcol1.add(rsb);
+        bcol2 = bcol2.add(rsb);
+        bcol3 = bcol3.add(rsb);
         pb = pb.add(4);
     }
 }
+
+pub(crate) unsafe fn dgemm_pa_4x(k: usize, a: *const f64, rsa: usize, csa: usize, pa: *mut f64) {
+    if rsa == 1 {
+        let mut a = a;
+        let mut pa = pa;
+
+        for _ in 0..k {
+            std::ptr::copy_nonoverlapping(a, pa, 4);
+
+            pa = pa.add(4);
+            a = a.add(csa);
+        }
+    } else {
+        for i in 0..4 {
+            let mut a = a.add(i * rsa);
+            let mut pa = pa.add(i);
+
+            for _ in 0..k {
+                *pa = *a;
+
+                pa = pa.add(4);
+                a = a.add(csa);
+            }
+        }
+    }
+}
@@ -1,65 +1,317 @@
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod avx;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx512;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx_nofma;
+mod generic;
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub use avx::{l1d::*, l1s::*};
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) use avx::l3s::{
-    sgemm_pa_16x as sgemm_pa, sgemm_sup_16x1 as sgemm_sup0, sgemm_ukr_16x4 as sgemm_ukr,
-};
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) use avx::l3d::{
-    dgemm_pa_8x as dgemm_pa, dgemm_sup_8x1 as dgemm_sup0, dgemm_ukr_8x4 as dgemm_ukr,
-};
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) use generic::l3s::{sgemm_pb_x4 as sgemm_pb, sgemm_sup_1x4 as sgemm_sup1};
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) use generic::l3d::{dgemm_pb_x4 as dgemm_pb, dgemm_sup_1x4 as dgemm_sup1};
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) const SMR: usize = 16;
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) const SNR: usize = 4;
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) const DMR: usize = 8;
-
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "avx"
-))]
-pub(crate) const DNR: usize = 4;
+use std::sync::OnceLock;
 
-mod generic;
+pub(crate) struct Kernels {
+    pub(crate) l1s: L1Kernels<f32>,
+    pub(crate) l1d: L1Kernels<f64>,
+    pub(crate) sgemm: GemmKernels<f32>,
+    pub(crate) dgemm: GemmKernels<f64>,
+}
+
+pub(crate) struct L1Kernels<T> {
+    pub(crate) rot: unsafe fn(usize, *mut T, isize, *mut T, isize, T, T),
+    pub(crate) rotm: unsafe fn(usize, *mut T, isize, *mut T, isize, T, T, T, T),
+    pub(crate) swap: unsafe fn(usize, *mut T, isize, *mut T, isize),
+    pub(crate) scal: unsafe fn(usize, T, *mut T, isize),
+    pub(crate) copy: unsafe fn(usize, *const T, isize, *mut T, isize),
+    pub(crate) axpy: unsafe fn(usize, T, *const T, isize, *mut T, isize),
+    pub(crate) dot: unsafe fn(usize, *const T, isize, *const T, isize) -> T,
+    // Returns x . y and adds alpha * x to z in the same pass over x.
+    pub(crate) dotaxpy: unsafe fn(usize, T, *const T, isize, *const T, isize, *mut T, isize) -> T,
+    pub(crate) nrm2: unsafe fn(usize, *const T, isize) -> T,
+    pub(crate) asum: unsafe fn(usize, *const T, isize) -> T,
+    pub(crate) iamax: unsafe fn(usize, *const T, isize) -> usize,
+    pub(crate) iamin: unsafe fn(usize, *const T, isize) -> usize,
+}
+
+pub(crate) struct GemmKernels<T> {
+    pub(crate) mr: usize,
+    pub(crate) nr: usize,
+    pub(crate) ukr: unsafe fn(usize, T, *const T, *const T, T, *mut T, usize),
+    pub(crate) sup0: unsafe fn(usize, T, *const T, *const T, usize, T, *mut T),
+    pub(crate) sup1: unsafe fn(usize, T, *const T, usize, *const T, T, *mut T, usize),
+    pub(crate) pa: unsafe fn(usize, *const T, usize, usize, *mut T),
+    pub(crate) pb: unsafe fn(usize, *const T, usize, usize, *mut T),
+}
+
+pub(crate) fn kernels() -> &'static Kernels {
+    static KERNELS: OnceLock<&'static Kernels> = OnceLock::new();
+
+    KERNELS.get_or_init(detect)
+}
+
+fn detect() -> &'static Kernels {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("fma")
+            && is_x86_feature_detected!("avx512f")
+        {
+            return &avx512::KERNELS;
+        }
+
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+            return &avx::KERNELS;
+        }
+
+        if is_x86_feature_detected!("avx") {
+            return &avx_nofma::KERNELS;
+        }
+    }
+
+    &generic::KERNELS
+}
+
+// Every kernel table the running CPU can execute, so that tests cover more than
+// the one `detect` picks.
+#[cfg(test)]
+pub(crate) fn available() -> Vec<&'static Kernels> {
+    #[allow(unused_mut)]
+    let mut tables: Vec<&'static Kernels> = vec![&generic::KERNELS];
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        if is_x86_feature_detected!("avx") {
+            tables.push(&avx_nofma::KERNELS);
+        }
+
+        if is_x86_feature_detected!("avx") && is_x86_feature_detected!("fma") {
+            tables.push(&avx::KERNELS);
+        }
+
+        if is_x86_feature_detected!("avx")
+            && is_x86_feature_detected!("fma")
+            && is_x86_feature_detected!("avx512f")
+        {
+            tables.push(&avx512::KERNELS);
+        }
+    }
+
+    tables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aligned_alloc::Alloc;
+    use std::fmt::Debug;
+    use std::mem;
+    use std::ops::{Add, Div, Mul};
+    use std::slice;
+
+    trait Element:
+        Copy
+        + Debug
+        + PartialEq
+        + From<i8>
+        + Add<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+    {
+    }
+
+    impl<T> Element for T where
+        T: Copy
+            + Debug
+            + PartialEq
+            + From<i8>
+            + Add<Output = T>
+            + Mul<Output = T>
+            + Div<Output = T>
+    {
+    }
+
+    // Small integers, so that every sum is exact whatever order the kernels
+    // add in.
+    fn value<T: Element>(i: usize, j: usize) -> T {
+        T::from(((i * 7 + j * 13) % 11) as i8 - 5)
+    }
+
+    fn nan<T: Element>() -> T {
+        T::from(0) / T::from(0)
+    }
+
+    // The kernels load packed panels with aligned loads, so packed data lives
+    // in the same aligned allocations the context hands out.
+    fn aligned<T: Element>(values: &[T]) -> Alloc {
+        let alloc = Alloc::new(mem::size_of_val(values));
+        unsafe { slice::from_raw_parts_mut(alloc.ptr() as *mut T, values.len()) }
+            .copy_from_slice(values);
+        alloc
+    }
+
+    fn contents<T: Element>(alloc: &Alloc, len: usize) -> Vec<T> {
+        unsafe { slice::from_raw_parts(alloc.ptr() as *const T, len) }.to_vec()
+    }
+
+    // Checks the packing routines, the microkernel and the edge kernels of a
+    // gemm table against scalar loops on an mr x k block A and a k x nr block
+    // B. C has an odd leading dimension and starts one element into its
+    // buffer.
+    fn check_gemm_kernels<T: Element>(kernels: &GemmKernels<T>) {
+        let (mr, nr) = (kernels.mr, kernels.nr);
+        let a = |i: usize, p: usize| value::<T>(i, p);
+        let b = |p: usize, j: usize| value::<T>(p + 3, j);
+        let dot = |i: usize, j: usize, k: usize| {
+            (0..k).fold(T::from(0), |sum, p| sum + a(i, p) * b(p, j))
+        };
+
+        for &k in &[1, 7, 33] {
+            // Column-major and row-major copies of A and B, so that both
+            // branches of the packing routines run.
+            let (lda_col, lda_row) = (mr + 1, k + 1);
+            let a_col: Vec<T> = (0..lda_col * k)
+                .map(|e| a(e % lda_col, e / lda_col))
+                .collect();
+            let a_row: Vec<T> = (0..mr * lda_row)
+                .map(|e| a(e / lda_row, e % lda_row))
+                .collect();
+            let (ldb_col, ldb_row) = (k + 1, nr + 1);
+            let b_col: Vec<T> = (0..ldb_col * nr)
+                .map(|e| b(e % ldb_col, e / ldb_col))
+                .collect();
+            let b_row: Vec<T> = (0..k * ldb_row)
+                .map(|e| b(e / ldb_row, e % ldb_row))
+                .collect();
+
+            let packed_a: Vec<T> = (0..mr * k).map(|e| a(e % mr, e / mr)).collect();
+            let packed_b: Vec<T> = (0..k * nr).map(|e| b(e / nr, e % nr)).collect();
+
+            for &(data, rsa, csa) in &[(&a_col, 1, lda_col), (&a_row, lda_row, 1)] {
+                let pa = aligned(&vec![nan::<T>(); mr * k]);
+                unsafe { (kernels.pa)(k, data.as_ptr(), rsa, csa, pa.ptr() as *mut T) };
+                assert_eq!(
+                    contents::<T>(&pa, mr * k),
+                    packed_a,
+                    "pa k {} rsa {}",
+                    k,
+                    rsa
+                );
+            }
+
+            for &(data, rsb, csb) in &[(&b_col, 1, ldb_col), (&b_row, ldb_row, 1)] {
+                let pb = aligned(&vec![nan::<T>(); k * nr]);
+                unsafe { (kernels.pb)(k, data.as_ptr(), rsb, csb, pb.ptr() as *mut T) };
+                assert_eq!(
+                    contents::<T>(&pb, k * nr),
+                    packed_b,
+                    "pb k {} rsb {}",
+                    k,
+                    rsb
+                );
+            }
+
+            let (pa, pb) = (aligned(&packed_a), aligned(&packed_b));
+
+            for &beta in &[T::from(0), T::from(1), T::from(-3)] {
+                let ldc = mr + 1;
+                let c0 = |i: usize, j: usize| {
+                    if beta == T::from(0) {
+                        nan()
+                    } else {
+                        value(i + 1, j)
+                    }
+                };
+                let expected = |i: usize, j: usize| {
+                    if beta == T::from(0) {
+                        T::from(2) * dot(i, j, k)
+                    } else {
+                        T::from(2) * dot(i, j, k) + beta * c0(i, j)
+                    }
+                };
+                let fill = |f: &dyn Fn(usize, usize) -> T| {
+                    let mut c = vec![T::from(100); 1 + ldc * nr];
+                    for j in 0..nr {
+                        for i in 0..mr {
+                            c[1 + i + j * ldc] = f(i, j);
+                        }
+                    }
+                    c
+                };
+
+                let mut c = fill(&c0);
+                unsafe {
+                    (kernels.ukr)(
+                        k,
+                        T::from(2),
+                        pa.ptr() as *const T,
+                        pb.ptr() as *const T,
+                        beta,
+                        c.as_mut_ptr().add(1),
+                        ldc,
+                    )
+                };
+                assert_eq!(c, fill(&expected), "ukr k {} beta {:?}", k, beta);
+
+                // sup0 computes the first column of C from packed A.
+                let mut c = fill(&c0);
+                unsafe {
+                    (kernels.sup0)(
+                        k,
+                        T::from(2),
+                        pa.ptr() as *const T,
+                        b_col.as_ptr(),
+                        1,
+                        beta,
+                        c.as_mut_ptr().add(1),
+                    )
+                };
+                let first_column = |i, j| if j == 0 { expected(i, j) } else { c0(i, j) };
+                assert_eq!(
+                    format!("{:?}", c),
+                    format!("{:?}", fill(&first_column)),
+                    "sup0 k {} beta {:?}",
+                    k,
+                    beta
+                );
+
+                // sup1 computes the first row of C from packed B.
+                let mut c = fill(&c0);
+                unsafe {
+                    (kernels.sup1)(
+                        k,
+                        T::from(2),
+                        a_col.as_ptr(),
+                        lda_col,
+                        pb.ptr() as *const T,
+                        beta,
+                        c.as_mut_ptr().add(1),
+                        ldc,
+                    )
+                };
+                let first_row = |i, j| if i == 0 { expected(i, j) } else { c0(i, j) };
+                assert_eq!(
+                    format!("{:?}", c),
+                    format!("{:?}", fill(&first_row)),
+                    "sup1 k {} beta {:?}",
+                    k,
+                    beta
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_kernels_match_scalar_loops() {
+        for kernels in available() {
+            check_gemm_kernels(&kernels.sgemm);
+            check_gemm_kernels(&kernels.dgemm);
+        }
+    }
+
+    #[test]
+    fn available_includes_the_detected_table() {
+        let detected = kernels();
+
+        assert!(available()
+            .iter()
+            .any(|&table| std::ptr::eq(table, detected)));
+    }
+}
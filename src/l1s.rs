@@ -0,0 +1,754 @@
+use crate::kernels::kernels;
+use crate::stride::start;
+
+pub unsafe fn srot(n: usize, x: *mut f32, incx: isize, y: *mut f32, incy: isize, c: f32, s: f32) {
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.rot)(n, x, incx, y, incy, c, s)
+}
+
+/// Generates a plane rotation that zeroes `b` in the vector (`a`, `b`).
+///
+/// On return `a` holds `r` and `b` holds `z`, from which the reference BLAS
+/// recovers `c` and `s`: `z` is `s` when `|a| > |b|`, `1 / c` when `c` is
+/// nonzero and 1 otherwise.
+pub fn srotg(a: &mut f32, b: &mut f32, c: &mut f32, s: &mut f32) {
+    let safmin = f32::MIN_POSITIVE;
+    let safmax = 1. / safmin;
+
+    let anorm = a.abs();
+    let bnorm = b.abs();
+
+    if bnorm == 0. {
+        *c = 1.;
+        *s = 0.;
+        *b = 0.;
+    } else if anorm == 0. {
+        *c = 0.;
+        *s = 1.;
+        *a = *b;
+        *b = 1.;
+    } else {
+        let scl = anorm.max(bnorm).max(safmin).min(safmax);
+        let sigma = if anorm > bnorm {
+            1f32.copysign(*a)
+        } else {
+            1f32.copysign(*b)
+        };
+        let r = sigma * scl * ((*a / scl).powi(2) + (*b / scl).powi(2)).sqrt();
+
+        *c = *a / r;
+        *s = *b / r;
+        *a = r;
+        *b = if anorm > bnorm {
+            *s
+        } else if *c != 0. {
+            1. / *c
+        } else {
+            1.
+        };
+    }
+}
+
+/// Generates the modified Givens rotation H that zeroes the second component
+/// of (`sqrt(d1) * x1`, `sqrt(d2) * y1`), updating `d1`, `d2` and `x1`.
+///
+/// `param[0]` is the flag selecting the form of H, followed by h11, h21, h12
+/// and h22 as described in [`srotm`].
+pub fn srotmg(d1: &mut f32, d2: &mut f32, x1: &mut f32, y1: f32, param: &mut [f32; 5]) {
+    const GAM: f32 = 4096.;
+    const GAMSQ: f32 = GAM * GAM;
+    const RGAMSQ: f32 = 1. / GAMSQ;
+
+    let mut flag;
+    let (mut h11, mut h21, mut h12, mut h22) = (0., 0., 0., 0.);
+
+    if *d1 < 0. {
+        flag = -1.;
+        *d1 = 0.;
+        *d2 = 0.;
+        *x1 = 0.;
+    } else {
+        let p2 = *d2 * y1;
+        if p2 == 0. {
+            param[0] = -2.;
+            return;
+        }
+
+        let p1 = *d1 * *x1;
+        let q2 = p2 * y1;
+        let q1 = p1 * *x1;
+
+        if q1.abs() > q2.abs() {
+            h21 = -y1 / *x1;
+            h12 = p2 / p1;
+
+            let u = 1. - h12 * h21;
+            if u > 0. {
+                flag = 0.;
+                *d1 /= u;
+                *d2 /= u;
+                *x1 *= u;
+            } else {
+                // Only reachable through rounding errors.
+                flag = -1.;
+                h21 = 0.;
+                h12 = 0.;
+                *d1 = 0.;
+                *d2 = 0.;
+                *x1 = 0.;
+            }
+        } else if q2 < 0. {
+            flag = -1.;
+            *d1 = 0.;
+            *d2 = 0.;
+            *x1 = 0.;
+        } else {
+            flag = 1.;
+            h11 = p1 / p2;
+            h22 = *x1 / y1;
+
+            let u = 1. + h11 * h22;
+            let temp = *d2 / u;
+            *d2 = *d1 / u;
+            *d1 = temp;
+            *x1 = y1 * u;
+        }
+
+        if *d1 != 0. {
+            while *d1 <= RGAMSQ || *d1 >= GAMSQ {
+                // Rescaling needs every entry of H explicitly.
+                if flag == 0. {
+                    h11 = 1.;
+                    h22 = 1.;
+                } else if flag == 1. {
+                    h21 = -1.;
+                    h12 = 1.;
+                }
+                flag = -1.;
+
+                if *d1 <= RGAMSQ {
+                    *d1 *= GAMSQ;
+                    *x1 /= GAM;
+                    h11 /= GAM;
+                    h12 /= GAM;
+                } else {
+                    *d1 /= GAMSQ;
+                    *x1 *= GAM;
+                    h11 *= GAM;
+                    h12 *= GAM;
+                }
+            }
+        }
+
+        if *d2 != 0. {
+            while d2.abs() <= RGAMSQ || d2.abs() >= GAMSQ {
+                if flag == 0. {
+                    h11 = 1.;
+                    h22 = 1.;
+                } else if flag == 1. {
+                    h21 = -1.;
+                    h12 = 1.;
+                }
+                flag = -1.;
+
+                if d2.abs() <= RGAMSQ {
+                    *d2 *= GAMSQ;
+                    h21 /= GAM;
+                    h22 /= GAM;
+                } else {
+                    *d2 /= GAMSQ;
+                    h21 *= GAM;
+                    h22 *= GAM;
+                }
+            }
+        }
+    }
+
+    if flag < 0. {
+        param[1] = h11;
+        param[2] = h21;
+        param[3] = h12;
+        param[4] = h22;
+    } else if flag == 0. {
+        param[2] = h21;
+        param[3] = h12;
+    } else {
+        param[1] = h11;
+        param[4] = h22;
+    }
+    param[0] = flag;
+}
+
+/// Applies the modified Givens rotation H described by `param` to the pairs
+/// (`x[i]`, `y[i]`).
+///
+/// The flag `param[0]` selects the form of H, with entries not stored in
+/// `param` implied:
+///
+/// * `-1`: H = [h11 h12; h21 h22], all read from `param`.
+/// * `0`: H = [1 h12; h21 1].
+/// * `1`: H = [h11 1; -1 h22].
+/// * `-2`: H is the identity and nothing is done.
+pub unsafe fn srotm(
+    n: usize,
+    x: *mut f32,
+    incx: isize,
+    y: *mut f32,
+    incy: isize,
+    param: &[f32; 5],
+) {
+    let flag = param[0];
+    let (h11, h21, h12, h22) = if flag == -1. {
+        (param[1], param[2], param[3], param[4])
+    } else if flag == 0. {
+        (1., param[2], param[3], 1.)
+    } else if flag == 1. {
+        (param[1], -1., 1., param[4])
+    } else {
+        return;
+    };
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.rotm)(n, x, incx, y, incy, h11, h21, h12, h22)
+}
+
+pub unsafe fn sswap(n: usize, x: *mut f32, incx: isize, y: *mut f32, incy: isize) {
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.swap)(n, x, incx, y, incy)
+}
+
+/// Does nothing unless `incx` is positive.
+pub unsafe fn sscal(n: usize, a: f32, x: *mut f32, incx: isize) {
+    if incx <= 0 {
+        return;
+    }
+
+    (kernels().l1s.scal)(n, a, x, incx)
+}
+
+pub unsafe fn scopy(n: usize, x: *const f32, incx: isize, y: *mut f32, incy: isize) {
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.copy)(n, x, incx, y, incy)
+}
+
+pub unsafe fn saxpy(n: usize, a: f32, x: *const f32, incx: isize, y: *mut f32, incy: isize) {
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.axpy)(n, a, x, incx, y, incy)
+}
+
+pub unsafe fn sdot(n: usize, x: *const f32, incx: isize, y: *const f32, incy: isize) -> f32 {
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    (kernels().l1s.dot)(n, x, incx, y, incy)
+}
+
+/// Returns zero unless `incx` is positive.
+pub unsafe fn snrm2(n: usize, x: *const f32, incx: isize) -> f32 {
+    if incx <= 0 {
+        return 0.;
+    }
+
+    (kernels().l1s.nrm2)(n, x, incx)
+}
+
+/// Returns zero unless `incx` is positive.
+pub unsafe fn sasum(n: usize, x: *const f32, incx: isize) -> f32 {
+    if incx <= 0 {
+        return 0.;
+    }
+
+    (kernels().l1s.asum)(n, x, incx)
+}
+
+/// Zero-based index of the first element with the largest absolute value.
+/// Returns zero when `n` is zero or `incx` is not positive.
+pub unsafe fn isamax(n: usize, x: *const f32, incx: isize) -> usize {
+    if n == 0 || incx <= 0 {
+        return 0;
+    }
+
+    (kernels().l1s.iamax)(n, x, incx)
+}
+
+/// Zero-based index of the first element with the smallest absolute value.
+/// Returns zero when `n` is zero or `incx` is not positive.
+pub unsafe fn isamin(n: usize, x: *const f32, incx: isize) -> usize {
+    if n == 0 || incx <= 0 {
+        return 0;
+    }
+
+    (kernels().l1s.iamin)(n, x, incx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::kernels::available;
+    use std::ptr::null;
+
+    // Filler of magnitude between 1 and 2, so that a planted 9 is the largest
+    // and a planted 0.5 the smallest element.
+    fn filler(len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 } * (1.0 + (i % 7) as f32 / 7.0))
+            .collect()
+    }
+
+    // Plants a tie between elements p < q of a vector of n elements with
+    // stride incx, for every kernel table, and expects the first of the two.
+    fn check_ties(value: f32, iamax: bool) {
+        for kernels in available() {
+            for &incx in &[1, 3] {
+                for n in 1..70 {
+                    for p in 0..n {
+                        for &q in &[p + 1, p + 4, p + 8, p + 16, p + 33, n - 1] {
+                            if q <= p || q >= n {
+                                continue;
+                            }
+
+                            let mut x = filler(n * incx);
+                            x[p * incx] = -value;
+                            x[q * incx] = value;
+
+                            let found = unsafe {
+                                if iamax {
+                                    (kernels.l1s.iamax)(n, x.as_ptr(), incx as isize)
+                                } else {
+                                    (kernels.l1s.iamin)(n, x.as_ptr(), incx as isize)
+                                }
+                            };
+                            assert_eq!(found, p, "n = {}, incx = {}, q = {}", n, incx, q);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // A NaN first element is never passed, whatever the stride, while NaNs
+    // after it are skipped in favour of the planted value at q.
+    fn check_nans(value: f32, iamax: bool) {
+        for kernels in available() {
+            for &incx in &[1, 2] {
+                for n in 3..70 {
+                    for &(nan, q, expected) in &[(0, n - 1, 0), (1, n - 1, n - 1), (n - 1, 2, 2)] {
+                        let mut x = filler(n * incx);
+                        x[nan * incx] = f32::NAN;
+                        x[q * incx] = value;
+
+                        let found = unsafe {
+                            if iamax {
+                                (kernels.l1s.iamax)(n, x.as_ptr(), incx as isize)
+                            } else {
+                                (kernels.l1s.iamin)(n, x.as_ptr(), incx as isize)
+                            }
+                        };
+                        assert_eq!(found, expected, "n = {}, incx = {}, nan = {}", n, incx, nan);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn iamax_ties_return_the_first_index() {
+        check_ties(9.0, true);
+        check_nans(9.0, true);
+    }
+
+    #[test]
+    fn iamin_ties_return_the_first_index() {
+        check_ties(0.5, false);
+        check_nans(0.5, false);
+    }
+
+    #[test]
+    fn equal_elements_return_zero() {
+        let x = [-2.0f32; 40];
+
+        for kernels in available() {
+            unsafe {
+                assert_eq!((kernels.l1s.iamax)(40, x.as_ptr(), 1), 0);
+                assert_eq!((kernels.l1s.iamin)(40, x.as_ptr(), 1), 0);
+            }
+        }
+    }
+
+    #[test]
+    fn index_routines_return_early_without_a_positive_increment() {
+        // The pointer is never read.
+        unsafe {
+            for &incx in &[0, -1, -3] {
+                assert_eq!(isamax(5, null(), incx), 0);
+                assert_eq!(isamin(5, null(), incx), 0);
+            }
+
+            assert_eq!(isamax(0, null(), 1), 0);
+            assert_eq!(isamin(0, null(), 1), 0);
+        }
+    }
+
+    const TOL: f32 = 1e-6;
+
+    fn assert_close(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() <= TOL * expected.abs(),
+            "{} is not {}",
+            actual,
+            expected
+        );
+    }
+
+    // H as (h11, h21, h12, h22), with the entries implied by the flag filled
+    // in.
+    fn rotm_matrix(param: &[f32; 5]) -> [f32; 4] {
+        match param[0] as i32 {
+            -1 => [param[1], param[2], param[3], param[4]],
+            0 => [1., param[2], param[3], 1.],
+            1 => [param[1], -1., 1., param[4]],
+            _ => [1., 0., 0., 1.],
+        }
+    }
+
+    #[test]
+    fn rotg_matches_the_reference() {
+        // a, b, r, z, c and s from the reference BLAS test suite.
+        let table: [[f32; 6]; 8] = [
+            [0.3, 0.4, 0.5, 1. / 0.6, 0.6, 0.8],
+            [0.4, 0.3, 0.5, 0.6, 0.8, 0.6],
+            [-0.3, 0.4, 0.5, -1. / 0.6, -0.6, 0.8],
+            [-0.4, 0.3, -0.5, -0.6, 0.8, -0.6],
+            [-0.3, -0.4, -0.5, 1. / 0.6, 0.6, 0.8],
+            [0., 0., 0., 0., 1., 0.],
+            [0., 1., 1., 1., 0., 1.],
+            [1., 0., 1., 0., 1., 0.],
+        ];
+
+        // Squaring either end of the range overflows or underflows unless
+        // srotg scales a and b first.
+        for &scale in &[1., f32::MAX, f32::MIN_POSITIVE] {
+            for row in &table {
+                let (mut a, mut b, mut c, mut s) = (row[0] * scale, row[1] * scale, 0., 0.);
+                srotg(&mut a, &mut b, &mut c, &mut s);
+
+                assert_close(a, row[2] * scale);
+                assert_close(b, row[3]);
+                assert_close(c, row[4]);
+                assert_close(s, row[5]);
+            }
+        }
+    }
+
+    // d1, d2, x1 and y1, then the flag, d1, d2 and x1 on return and H.
+    type RotmgCase = ([f32; 4], f32, [f32; 3], [f32; 4]);
+
+    #[test]
+    fn rotmg_matches_the_reference() {
+        // The first nine rows are the inputs of the reference BLAS test suite.
+        // Rows five to eight and the last one leave d1 or d2 outside
+        // [1 / 4096^2, 4096^2] and are rescaled, the last one three times.
+        let table: [RotmgCase; 11] = [
+            (
+                [0.1, 0.3, 1.2, 0.2],
+                0.,
+                [0.092307694, 0.2769231, 1.3],
+                [1., -0.16666667, 0.5, 1.],
+            ),
+            (
+                [0.7, 0.2, 0.6, 4.2],
+                1.,
+                [0.18666667, 0.6533333, 4.5],
+                [0.5, -1., 1., 0.14285715],
+            ),
+            ([0., 0., 0., 0.], -2., [0., 0., 0.], [1., 0., 0., 1.]),
+            ([4., -1., 2., 4.], -1., [0., 0., 0.], [0., 0., 0., 0.]),
+            (
+                [6e-10, 2e-2, 1e5, 10.],
+                -1.,
+                [0.0075497472, 0.015, 32.552082],
+                [0.00024414063, -0.0001, 0.81380206, 1.],
+            ),
+            (
+                [4e10, 2e-2, 1e-5, 10.],
+                -1.,
+                [1589.4572, 0.013333334, 0.06144],
+                [4096., -1000000., 0.002048, 1.],
+            ),
+            (
+                [2e-10, 4e-2, 1e5, 10.],
+                -1.,
+                [0.026666667, 0.002236962, 15.],
+                [5e-5, -0.00024414063, 1., 2.4414063],
+            ),
+            (
+                [2e10, 4e-2, 1e-5, 10.],
+                -1.,
+                [0.026666667, 794.7286, 15.],
+                [500000., -4096., 1., 0.004096],
+            ),
+            (
+                [4., -2., 8., 4.],
+                0.,
+                [4.571429, -2.2857144, 7.],
+                [1., -0.5, -0.25, 1.],
+            ),
+            ([-1., 2., 3., 4.], -1., [0., 0., 0.], [0., 0., 0., 0.]),
+            (
+                [1e-20, 1., 1., 1e-5],
+                -1.,
+                [0.9999999999, 2.8147497e-6, 1e-5],
+                [1e-15, -5.9604645e-8, 1., 0.0059604645],
+            ),
+        ];
+
+        for (input, flag, output, h) in &table {
+            let [mut d1, mut d2, mut x1, y1] = *input;
+            let mut param = [0.; 5];
+            srotmg(&mut d1, &mut d2, &mut x1, y1, &mut param);
+
+            assert_eq!(param[0], *flag, "{:?}", input);
+            assert_close(d1, output[0]);
+            assert_close(d2, output[1]);
+            assert_close(x1, output[2]);
+
+            for (actual, expected) in rotm_matrix(&param).iter().zip(h) {
+                assert_close(*actual, *expected);
+            }
+        }
+    }
+
+    #[test]
+    fn rotm_applies_every_flag() {
+        let params: [[f32; 5]; 4] = [
+            [-1., 2., 3., 4., 5.],
+            [0., 7., 3., 4., 7.],
+            [1., 2., 7., 7., 5.],
+            [-2., 2., 3., 4., 5.],
+        ];
+
+        for param in &params {
+            let [h11, h21, h12, h22] = rotm_matrix(param);
+
+            for &(incx, incy) in &[(1, 1), (2, -1), (-1, 3), (-2, -2)] {
+                let n = 5;
+                let x0: Vec<f32> = (0..n * 3).map(|i| i as f32).collect();
+                let y0: Vec<f32> = (0..n * 3).map(|i| 1. - i as f32).collect();
+                let (mut x, mut y) = (x0.clone(), y0.clone());
+
+                unsafe { srotm(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, param) };
+
+                // Element i of a vector with a negative increment is stored
+                // at (n - 1 - i) * |inc|.
+                let at = |i: usize, inc: isize| {
+                    if inc > 0 {
+                        i * inc as usize
+                    } else {
+                        (n - 1 - i) * (-inc) as usize
+                    }
+                };
+                let (mut ex, mut ey) = (x0.clone(), y0.clone());
+                for i in 0..n {
+                    let (xi, yi) = (x0[at(i, incx)], y0[at(i, incy)]);
+                    ex[at(i, incx)] = h11 * xi + h12 * yi;
+                    ey[at(i, incy)] = h21 * xi + h22 * yi;
+                }
+
+                assert_eq!(x, ex, "param {:?}, incx {}, incy {}", param, incx, incy);
+                assert_eq!(y, ey, "param {:?}, incx {}, incy {}", param, incx, incy);
+            }
+        }
+    }
+
+    #[test]
+    fn rotm_kernels_match_the_scalar_rotation() {
+        let [h11, h21, h12, h22] = [2., 3., 4., 5.];
+
+        for kernels in available() {
+            for n in 0..70 {
+                let x0: Vec<f32> = (0..n).map(|i| (i % 5) as f32).collect();
+                let y0: Vec<f32> = (0..n).map(|i| 1. - (i % 3) as f32).collect();
+                let (mut x, mut y) = (x0.clone(), y0.clone());
+
+                unsafe {
+                    (kernels.l1s.rotm)(n, x.as_mut_ptr(), 1, y.as_mut_ptr(), 1, h11, h21, h12, h22)
+                };
+
+                for i in 0..n {
+                    assert_eq!(x[i], h11 * x0[i] + h12 * y0[i], "n = {}", n);
+                    assert_eq!(y[i], h21 * x0[i] + h22 * y0[i], "n = {}", n);
+                }
+            }
+        }
+    }
+
+    // The square root of the sum of squares, scaled by the largest magnitude
+    // so that it neither overflows nor underflows.
+    fn reference_nrm2(x: &[f32]) -> f32 {
+        let scale = x.iter().fold(0f32, |max, v| max.max(v.abs()));
+        if scale == 0. {
+            return 0.;
+        }
+
+        scale * x.iter().map(|v| (v / scale).powi(2)).sum::<f32>().sqrt()
+    }
+
+    // Checks every kernel table on x with unit stride and with a stride of
+    // three, whose gaps hold values that would spoil the norm if read.
+    fn check_nrm2(x: &[f32]) {
+        let expected = reference_nrm2(x);
+        let mut strided = vec![f32::MAX; x.len() * 3];
+        for (i, v) in x.iter().enumerate() {
+            strided[i * 3] = *v;
+        }
+
+        for kernels in available() {
+            for &(data, incx) in &[(x, 1), (&strided[..], 3)] {
+                let norm = unsafe { (kernels.l1s.nrm2)(x.len(), data.as_ptr(), incx) };
+                assert!(
+                    (norm - expected).abs() <= 1e-5 * expected,
+                    "{} is not {} for {:?}",
+                    norm,
+                    expected,
+                    x
+                );
+            }
+        }
+    }
+
+    fn alternating(n: usize, value: f32) -> Vec<f32> {
+        (0..n)
+            .map(|i| if i % 2 == 0 { value } else { -value })
+            .collect()
+    }
+
+    #[test]
+    fn nrm2_does_not_overflow() {
+        for n in 1..70 {
+            check_nrm2(&alternating(n, f32::MAX / 16.));
+        }
+
+        check_nrm2(&[f32::MAX * 0.3, -f32::MAX * 0.4]);
+    }
+
+    #[test]
+    fn nrm2_does_not_underflow() {
+        let subnormal = f32::MIN_POSITIVE / 1024.;
+        for n in 1..70 {
+            check_nrm2(&alternating(n, subnormal));
+        }
+
+        let smallest = f32::MIN_POSITIVE * f32::EPSILON;
+        check_nrm2(&[3. * smallest, -4. * smallest]);
+    }
+
+    #[test]
+    fn nrm2_mixes_magnitudes() {
+        // The thresholds below and above which Blue's algorithm sums squares
+        // in separate accumulators.
+        let (tsml, tbig) = (2f32.powi(-63), 2f32.powi(52));
+        let patterns: [&[f32]; 4] = [
+            &[tbig * 2., tbig / 2., 1.],
+            &[tsml * 2., tsml / 2.],
+            &[tbig * 4., 3., tsml, f32::MIN_POSITIVE / 1024., 0.],
+            &[f32::MAX / 64., 1., -tsml, f32::MIN_POSITIVE],
+        ];
+
+        for pattern in &patterns {
+            for n in 1..70 {
+                let x: Vec<f32> = (0..n)
+                    .map(|i| pattern[i % pattern.len()] * if i % 3 == 0 { -1. } else { 1. })
+                    .collect();
+                check_nrm2(&x);
+            }
+        }
+    }
+
+    #[test]
+    fn norms_return_early_without_a_positive_increment() {
+        // The pointer is never read.
+        unsafe {
+            for &incx in &[0, -1, -3] {
+                assert_eq!(snrm2(5, null(), incx), 0.);
+                assert_eq!(sasum(5, null(), incx), 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn scal_does_nothing_without_a_positive_increment() {
+        let mut x = [1., 2., 3., 4., 5.];
+
+        for &incx in &[0, -1, -3] {
+            unsafe { sscal(2, 7., x.as_mut_ptr().add(4), incx) };
+        }
+
+        assert_eq!(x, [1., 2., 3., 4., 5.]);
+    }
+
+    // Buffer of a vector of n elements with increment inc whose element i is
+    // f(i). Element i of a vector with a negative increment is stored at
+    // (n - 1 - i) * |inc|. The elements in between and one past the end hold
+    // a filler that must not change.
+    fn strided(n: usize, inc: isize, f: impl Fn(usize) -> f32) -> Vec<f32> {
+        let step = inc.unsigned_abs();
+        let mut x = vec![1000.5; n * step + 1];
+        for i in 0..n {
+            let at = if inc > 0 {
+                i * step
+            } else {
+                (n - 1 - i) * step
+            };
+            x[at] = f(i);
+        }
+        x
+    }
+
+    #[test]
+    fn level_1_routines_follow_signed_increments() {
+        let xv = |i: usize| (i % 5) as f32 - 2.;
+        let yv = |i: usize| (i % 3) as f32 + 1.;
+
+        for &(incx, incy) in &[(-1, 1), (2, -3), (-1, -1), (1, 1), (-2, 2)] {
+            for &n in &[0, 1, 7, 37] {
+                let (x, y) = (strided(n, incx, xv), strided(n, incy, yv));
+                let case = format!("n {} incx {} incy {}", n, incx, incy);
+
+                let mut out = y.clone();
+                unsafe { saxpy(n, 2., x.as_ptr(), incx, out.as_mut_ptr(), incy) };
+                let expected = strided(n, incy, |i| 2. * xv(i) + yv(i));
+                assert_eq!(out, expected, "saxpy {}", case);
+
+                let mut out = y.clone();
+                unsafe { scopy(n, x.as_ptr(), incx, out.as_mut_ptr(), incy) };
+                assert_eq!(out, strided(n, incy, xv), "scopy {}", case);
+
+                let (mut sx, mut sy) = (x.clone(), y.clone());
+                unsafe { sswap(n, sx.as_mut_ptr(), incx, sy.as_mut_ptr(), incy) };
+                assert_eq!(sx, strided(n, incx, yv), "sswap x {}", case);
+                assert_eq!(sy, strided(n, incy, xv), "sswap y {}", case);
+
+                let (mut rx, mut ry) = (x.clone(), y.clone());
+                unsafe { srot(n, rx.as_mut_ptr(), incx, ry.as_mut_ptr(), incy, 3., 2.) };
+                let expected = strided(n, incx, |i| 3. * xv(i) + 2. * yv(i));
+                assert_eq!(rx, expected, "srot x {}", case);
+                let expected = strided(n, incy, |i| 3. * yv(i) - 2. * xv(i));
+                assert_eq!(ry, expected, "srot y {}", case);
+
+                let dot: f32 = (0..n).map(|i| xv(i) * yv(i)).sum();
+                let actual = unsafe { sdot(n, x.as_ptr(), incx, y.as_ptr(), incy) };
+                assert_eq!(actual, dot, "sdot {}", case);
+            }
+        }
+    }
+}
@@ -0,0 +1,2390 @@
+use crate::context::Context;
+use crate::kernels::L1Kernels;
+use crate::send::{SSend, SSendMut};
+use crate::stride::start;
+use crate::types::{Diag, Layout, Uplo};
+
+// Rows of y updated by one job. A column sweep keeps its slice of y in L1
+// while it adds every column of A into it. Dot products stream x once per row
+// of y, so the rows of a dot product job only set how finely the work is split
+// between threads.
+const SWEEP_ROWS: usize = 256;
+const DOT_ROWS: usize = 16;
+
+// Order of the diagonal blocks of a triangular matrix that are handled by
+// level 1 sweeps. Everything off those blocks goes through sgemv.
+const TRIANGLE_BLOCK: usize = 128;
+
+/// Computes `y = alpha * op(A) * x + beta * y`, where `A` is `m x n` and
+/// `op(A)` is `A` or, when `trans` is set, its transpose.
+///
+/// `x` has `n` and `y` has `m` elements, or the other way round when `trans`
+/// is set. `incy` must not be zero. When `beta` is zero `y` is not read.
+pub unsafe fn sgemv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let (rsa, csa) = layout.strides(trans, lda);
+    let (m, n) = if trans { (n, m) } else { (m, n) };
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(m, incy));
+
+    sgemv_strided(context, m, n, alpha, a, rsa, csa, x, incx, beta, y, incy);
+}
+
+// Computes y = alpha * A * x + beta * y where element (i, j) of A lives at
+// a + i * rsa + j * csa, and x and y point at the elements visited first.
+pub(crate) unsafe fn sgemv_strided(
+    context: &Context,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    sgbmv_strided(
+        context,
+        m,
+        n,
+        m.saturating_sub(1),
+        n.saturating_sub(1),
+        alpha,
+        a,
+        rsa,
+        csa,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    );
+}
+
+/// Computes `y = alpha * op(A) * x + beta * y`, where `A` is the `m x n` band
+/// matrix with `kl` subdiagonals and `ku` superdiagonals held in band storage
+/// and `op(A)` is `A` or, when `trans` is set, its transpose.
+///
+/// Column-major storage keeps column `j` of the band in column `j` of the
+/// array with the diagonal in row `ku`, row-major storage keeps row `i` in row
+/// `i` with the diagonal in column `kl`. `x` has `n` and `y` has `m` elements,
+/// or the other way round when `trans` is set. `incy` must not be zero. When
+/// `beta` is zero `y` is not read.
+pub unsafe fn sgbmv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let (offset, rs, cs) = layout.band(kl, ku, lda);
+    let (m, n, kl, ku, rsa, csa) = if trans {
+        (n, m, ku, kl, cs, rs)
+    } else {
+        (m, n, kl, ku, rs, cs)
+    };
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(m, incy));
+
+    sgbmv_strided(
+        context,
+        m,
+        n,
+        kl,
+        ku,
+        alpha,
+        a.wrapping_add(offset),
+        rsa,
+        csa,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    );
+}
+
+// Computes y = alpha * A * x + beta * y for the band matrix A with kl
+// subdiagonals and ku superdiagonals, where element (i, j) of the band lives
+// at a + i * rsa + j * csa, and x and y point at the elements visited first.
+pub(crate) unsafe fn sgbmv_strided(
+    context: &Context,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: f32,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    if m == 0 || (n == 0 || alpha == 0.0) && beta == 1.0 {
+        return;
+    }
+
+    if n == 0 || alpha == 0.0 {
+        sscale(kernels, m, beta, y, incy);
+        return;
+    }
+
+    // Diagonals past the corners of A hold no elements.
+    let kl = std::cmp::min(kl, m - 1);
+    let ku = std::cmp::min(ku, n - 1);
+
+    // Columns of A are added into y when they are contiguous, otherwise every
+    // element of y is the dot product of a row of A with x.
+    let sweep = if m == 1 {
+        false
+    } else if n == 1 {
+        true
+    } else {
+        rsa <= csa
+    };
+
+    let rows = if sweep { SWEEP_ROWS } else { DOT_ROWS };
+
+    let a = SSend(a);
+    let x = SSend(x);
+    let y = SSendMut(y);
+
+    let job = move |i: usize| {
+        let is = std::cmp::min(m - i, rows);
+        let a_at = |i: usize, j: usize| a.0.wrapping_add(i * rsa + j * csa);
+        let x_at = |j: usize| x.0.wrapping_offset(j as isize * incx);
+        let y_at = |r: usize| y.0.wrapping_offset(r as isize * incy);
+
+        if sweep {
+            sscale(kernels, is, beta, y_at(i), incy);
+
+            for j in i.saturating_sub(kl)..std::cmp::min(n, i + is + ku) {
+                let lo = std::cmp::max(i, j.saturating_sub(ku));
+                let hi = std::cmp::min(i + is, j + kl + 1);
+                let temp = alpha * *x_at(j);
+                (kernels.axpy)(hi - lo, temp, a_at(lo, j), rsa as isize, y_at(lo), incy);
+            }
+        } else {
+            for r in i..i + is {
+                let lo = r.saturating_sub(kl);
+                let hi = std::cmp::min(n, r + ku + 1);
+                let dot = (kernels.dot)(
+                    hi.saturating_sub(lo),
+                    a_at(r, lo),
+                    csa as isize,
+                    x_at(lo),
+                    incx,
+                );
+                let y = y_at(r);
+                *y = if beta != 0.0 {
+                    alpha * dot + beta * *y
+                } else {
+                    alpha * dot
+                };
+            }
+        }
+    };
+
+    let elements = m.saturating_mul(std::cmp::min(n, kl + ku + 1));
+    context.execute_sized(elements, 0, m.div_ceil(rows) * rows, rows, job);
+}
+
+// Computes y = beta * y without reading y when beta is zero.
+unsafe fn sscale(kernels: &L1Kernels<f32>, n: usize, beta: f32, y: *mut f32, incy: isize) {
+    if beta == 0.0 {
+        for i in 0..n {
+            *y.offset(i as isize * incy) = 0.0;
+        }
+    } else if beta != 1.0 {
+        (kernels.scal)(n, beta, y, incy);
+    }
+}
+
+/// Computes `A = alpha * x * y^T + A`, where `A` is `m x n`, `x` has `m` and
+/// `y` has `n` elements.
+pub unsafe fn sger(
+    context: &Context,
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    lda: usize,
+) {
+    let (rsa, csa) = layout.strides(false, lda);
+
+    let x = x.offset(start(m, incx));
+    let y = y.offset(start(n, incy));
+
+    sger_strided(context, m, n, alpha, x, incx, y, incy, a, rsa, csa);
+}
+
+// Computes A = alpha * x * y^T + A where element (i, j) of A lives at
+// a + i * rsa + j * csa, and x and y point at the elements visited first.
+pub(crate) unsafe fn sger_strided(
+    context: &Context,
+    m: usize,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    rsa: usize,
+    csa: usize,
+) {
+    if m == 0 || n == 0 || alpha == 0.0 {
+        return;
+    }
+
+    // Rows of A are updated as the columns of A^T = alpha * y * x^T + A^T.
+    if rsa > csa {
+        sger_strided(context, n, m, alpha, y, incy, x, incx, a, csa, rsa);
+        return;
+    }
+
+    let kernels = &context.kernels().l1s;
+
+    let x = SSend(x);
+    let y = SSend(y);
+    let a = SSendMut(a);
+
+    context.execute_sized(m.saturating_mul(n), 0, n, 1, move |j| {
+        let temp = *y.0.offset(j as isize * incy);
+
+        if temp != 0.0 {
+            (kernels.axpy)(m, alpha * temp, x.0, incx, a.0.add(j * csa), rsa as isize);
+        }
+    });
+}
+
+/// Computes `A = alpha * x * x^T + A` on the `uplo` triangle of the
+/// symmetric `n x n` matrix `A`.
+pub unsafe fn ssyr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    a: *mut f32,
+    lda: usize,
+) {
+    let (rsa, csa) = layout.strides(false, lda);
+
+    let x = x.offset(start(n, incx));
+
+    ssyr_strided(context, uplo, n, alpha, x, incx, a, rsa, csa);
+}
+
+// Computes A = alpha * x * x^T + A on the uplo triangle, where element (i, j)
+// of A lives at a + i * rsa + j * csa and x points at the element visited
+// first.
+pub(crate) unsafe fn ssyr_strided(
+    context: &Context,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    a: *mut f32,
+    rsa: usize,
+    csa: usize,
+) {
+    if n == 0 || alpha == 0.0 {
+        return;
+    }
+
+    // A is its own transpose, so its rows are updated as the columns of the
+    // other triangle of A^T.
+    if rsa > csa {
+        ssyr_strided(context, uplo.transpose(), n, alpha, x, incx, a, csa, rsa);
+        return;
+    }
+
+    let kernels = &context.kernels().l1s;
+
+    let x = SSend(x);
+    let a = SSendMut(a);
+
+    let column = move |j: usize| {
+        let temp = *x.0.offset(j as isize * incx);
+
+        if temp != 0.0 {
+            let (i, len) = uplo.column(n, j);
+
+            (kernels.axpy)(
+                len,
+                alpha * temp,
+                x.0.offset(i as isize * incx),
+                incx,
+                a.0.add(i * rsa + j * csa),
+                rsa as isize,
+            );
+        }
+    };
+
+    context.execute_columns(n.saturating_mul(n) / 2, n, column);
+}
+
+/// Computes `A = alpha * x * y^T + alpha * y * x^T + A` on the `uplo` triangle
+/// of the symmetric `n x n` matrix `A`.
+pub unsafe fn ssyr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    lda: usize,
+) {
+    let (rsa, csa) = layout.strides(false, lda);
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    ssyr2_strided(context, uplo, n, alpha, x, incx, y, incy, a, rsa, csa);
+}
+
+// Computes A = alpha * x * y^T + alpha * y * x^T + A on the uplo triangle,
+// where element (i, j) of A lives at a + i * rsa + j * csa, and x and y point
+// at the elements visited first.
+pub(crate) unsafe fn ssyr2_strided(
+    context: &Context,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    a: *mut f32,
+    rsa: usize,
+    csa: usize,
+) {
+    if n == 0 || alpha == 0.0 {
+        return;
+    }
+
+    if rsa > csa {
+        ssyr2_strided(
+            context,
+            uplo.transpose(),
+            n,
+            alpha,
+            x,
+            incx,
+            y,
+            incy,
+            a,
+            csa,
+            rsa,
+        );
+        return;
+    }
+
+    let kernels = &context.kernels().l1s;
+
+    let x = SSend(x);
+    let y = SSend(y);
+    let a = SSendMut(a);
+
+    let column = move |j: usize| {
+        let xj = *x.0.offset(j as isize * incx);
+        let yj = *y.0.offset(j as isize * incy);
+
+        if xj != 0.0 || yj != 0.0 {
+            let (i, len) = uplo.column(n, j);
+            let a = a.0.add(i * rsa + j * csa);
+
+            (kernels.axpy)(
+                len,
+                alpha * yj,
+                x.0.offset(i as isize * incx),
+                incx,
+                a,
+                rsa as isize,
+            );
+            (kernels.axpy)(
+                len,
+                alpha * xj,
+                y.0.offset(i as isize * incy),
+                incy,
+                a,
+                rsa as isize,
+            );
+        }
+    };
+
+    context.execute_columns(n.saturating_mul(n), n, column);
+}
+
+/// Computes `A = alpha * x * x^T + A` on the `uplo` triangle of the
+/// symmetric `n x n` matrix `A` packed in `ap`.
+///
+/// Column-major storage packs the triangle column by column, row-major
+/// storage row by row.
+pub unsafe fn sspr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    ap: *mut f32,
+) {
+    if n == 0 || alpha == 0.0 {
+        return;
+    }
+
+    let (uplo, _) = layout.packed(uplo, false);
+
+    let kernels = &context.kernels().l1s;
+
+    let x = SSend(x.offset(start(n, incx)));
+    let ap = SSendMut(ap);
+
+    let column = move |j: usize| {
+        let temp = *x.0.offset(j as isize * incx);
+
+        if temp != 0.0 {
+            let (i, len) = uplo.column(n, j);
+
+            (kernels.axpy)(
+                len,
+                alpha * temp,
+                x.0.offset(i as isize * incx),
+                incx,
+                ap.0.add(uplo.packed(n, i, j)),
+                1,
+            );
+        }
+    };
+
+    context.execute_columns(n.saturating_mul(n) / 2, n, column);
+}
+
+/// Computes `A = alpha * x * y^T + alpha * y * x^T + A` on the `uplo` triangle
+/// of the symmetric `n x n` matrix `A` packed in `ap`.
+pub unsafe fn sspr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    x: *const f32,
+    incx: isize,
+    y: *const f32,
+    incy: isize,
+    ap: *mut f32,
+) {
+    if n == 0 || alpha == 0.0 {
+        return;
+    }
+
+    let (uplo, _) = layout.packed(uplo, false);
+
+    let kernels = &context.kernels().l1s;
+
+    let x = SSend(x.offset(start(n, incx)));
+    let y = SSend(y.offset(start(n, incy)));
+    let ap = SSendMut(ap);
+
+    let column = move |j: usize| {
+        let xj = *x.0.offset(j as isize * incx);
+        let yj = *y.0.offset(j as isize * incy);
+
+        if xj != 0.0 || yj != 0.0 {
+            let (i, len) = uplo.column(n, j);
+            let ap = ap.0.add(uplo.packed(n, i, j));
+
+            (kernels.axpy)(len, alpha * yj, x.0.offset(i as isize * incx), incx, ap, 1);
+            (kernels.axpy)(len, alpha * xj, y.0.offset(i as isize * incy), incy, ap, 1);
+        }
+    };
+
+    context.execute_columns(n.saturating_mul(n), n, column);
+}
+
+/// Computes `x = op(A) * x`, where `A` is the `n x n` triangular matrix held in
+/// the `uplo` triangle and `op(A)` is `A` or, when `trans` is set, its
+/// transpose. `incx` must not be zero.
+pub unsafe fn strmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let (rsa, csa) = layout.strides(trans, lda);
+    let uplo = if trans { uplo.transpose() } else { uplo };
+
+    let x = x.offset(start(n, incx));
+
+    strmv_strided(context, uplo, diag, n, a, rsa, csa, x, incx);
+}
+
+// Computes x = A * x for the triangular matrix A held in the uplo triangle,
+// where element (i, j) of A lives at a + i * rsa + j * csa and x points at
+// the element visited first.
+pub(crate) unsafe fn strmv_strided(
+    context: &Context,
+    uplo: Uplo,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+    let a_at = |i: usize, j: usize| a.wrapping_add(i * rsa + j * csa);
+    let x_at = |i: usize| x.wrapping_offset(i as isize * incx);
+
+    let blocks = (0..n).step_by(TRIANGLE_BLOCK);
+    let block = |i: usize| std::cmp::min(n - i, TRIANGLE_BLOCK);
+    let diagonal = |i: usize, ib: usize| {
+        strmv_unblocked(
+            kernels,
+            uplo,
+            diag,
+            ib,
+            ib,
+            |r, c| a_at(i + r, i + c),
+            rsa <= csa,
+            std::cmp::min(rsa, csa) as isize,
+            x_at(i),
+            incx,
+        )
+    };
+
+    // With contiguous columns the blocks off the diagonal are multiplied by
+    // a block of x into the rest of x, otherwise by the rest of x into a
+    // block of x, so that sgemv always runs its fast sweep.
+    match (uplo, rsa <= csa) {
+        (Uplo::Upper, true) => {
+            for i in blocks {
+                let ib = block(i);
+                sgemv_strided(
+                    context,
+                    i,
+                    ib,
+                    1.0,
+                    a_at(0, i),
+                    rsa,
+                    csa,
+                    x_at(i),
+                    incx,
+                    1.0,
+                    x,
+                    incx,
+                );
+                diagonal(i, ib);
+            }
+        }
+        (Uplo::Lower, true) => {
+            for i in blocks.rev() {
+                let ib = block(i);
+                sgemv_strided(
+                    context,
+                    n - i - ib,
+                    ib,
+                    1.0,
+                    a_at(i + ib, i),
+                    rsa,
+                    csa,
+                    x_at(i),
+                    incx,
+                    1.0,
+                    x_at(i + ib),
+                    incx,
+                );
+                diagonal(i, ib);
+            }
+        }
+        (Uplo::Upper, false) => {
+            for i in blocks {
+                let ib = block(i);
+                diagonal(i, ib);
+                sgemv_strided(
+                    context,
+                    ib,
+                    n - i - ib,
+                    1.0,
+                    a_at(i, i + ib),
+                    rsa,
+                    csa,
+                    x_at(i + ib),
+                    incx,
+                    1.0,
+                    x_at(i),
+                    incx,
+                );
+            }
+        }
+        (Uplo::Lower, false) => {
+            for i in blocks.rev() {
+                let ib = block(i);
+                diagonal(i, ib);
+                sgemv_strided(
+                    context,
+                    ib,
+                    i,
+                    1.0,
+                    a_at(i, 0),
+                    rsa,
+                    csa,
+                    x,
+                    incx,
+                    1.0,
+                    x_at(i),
+                    incx,
+                );
+            }
+        }
+    }
+}
+
+// Computes x = A * x for the triangular matrix A held in the uplo triangle
+// with k diagonals next to the main one, where element (i, j) lives at
+// at(i, j). Contiguous columns are walked by axpy updates and contiguous rows
+// by dot products, inc elements apart.
+unsafe fn strmv_unblocked<F: Fn(usize, usize) -> *const f32>(
+    kernels: &L1Kernels<f32>,
+    uplo: Uplo,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    at: F,
+    columns: bool,
+    inc: isize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let x_at = |i: usize| x.wrapping_offset(i as isize * incx);
+
+    let nounit = diag == Diag::NonUnit;
+
+    match (uplo, columns) {
+        (Uplo::Upper, true) => {
+            for j in 0..n {
+                let temp = *x_at(j);
+                if temp != 0.0 {
+                    let i = j.saturating_sub(k);
+                    (kernels.axpy)(j - i, temp, at(i, j), inc, x_at(i), incx);
+                    if nounit {
+                        *x_at(j) *= *at(j, j);
+                    }
+                }
+            }
+        }
+        (Uplo::Lower, true) => {
+            for j in (0..n).rev() {
+                let temp = *x_at(j);
+                if temp != 0.0 {
+                    let len = std::cmp::min(n - j - 1, k);
+                    (kernels.axpy)(len, temp, at(j + 1, j), inc, x_at(j + 1), incx);
+                    if nounit {
+                        *x_at(j) *= *at(j, j);
+                    }
+                }
+            }
+        }
+        (Uplo::Upper, false) => {
+            for i in 0..n {
+                let mut temp = *x_at(i);
+                if nounit {
+                    temp *= *at(i, i);
+                }
+                let len = std::cmp::min(n - i - 1, k);
+                temp += (kernels.dot)(len, at(i, i + 1), inc, x_at(i + 1), incx);
+                *x_at(i) = temp;
+            }
+        }
+        (Uplo::Lower, false) => {
+            for i in (0..n).rev() {
+                let mut temp = *x_at(i);
+                if nounit {
+                    temp *= *at(i, i);
+                }
+                let j = i.saturating_sub(k);
+                temp += (kernels.dot)(i - j, at(i, j), inc, x_at(j), incx);
+                *x_at(i) = temp;
+            }
+        }
+    }
+}
+
+/// Computes `x = op(A) * x`, where `A` is the `n x n` triangular band matrix
+/// with `k` diagonals next to the main one held in the `uplo` triangle of band
+/// storage and `op(A)` is `A` or, when `trans` is set, its transpose.
+///
+/// Column-major storage keeps column `j` of the band in column `j` of the
+/// array, row-major storage keeps row `i` in row `i`. The main diagonal comes
+/// first in the lower and last in the upper triangle. `incx` must not be zero.
+pub unsafe fn stbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    let (kl, ku) = uplo.band(k);
+    let (offset, rs, cs) = layout.band(kl, ku, lda);
+    let (rsa, csa) = if trans { (cs, rs) } else { (rs, cs) };
+    let at = |i: usize, j: usize| a.wrapping_add(offset + i * rsa + j * csa);
+    let uplo = if trans { uplo.transpose() } else { uplo };
+
+    let x = x.offset(start(n, incx));
+
+    let inc = std::cmp::min(rsa, csa) as isize;
+    strmv_unblocked(kernels, uplo, diag, n, k, at, rsa <= csa, inc, x, incx);
+}
+
+/// Computes `x = op(A) * x`, where `A` is the `n x n` triangular matrix
+/// packed in `ap` and `op(A)` is `A` or, when `trans` is set, its transpose.
+///
+/// Column-major storage packs the `uplo` triangle column by column, row-major
+/// storage row by row. `incx` must not be zero.
+pub unsafe fn stpmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f32,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    let (uplo, trans) = layout.packed(uplo, trans);
+
+    let x = x.offset(start(n, incx));
+
+    // Rows of op(A) are the packed columns of A when it is transposed.
+    if trans {
+        let at = |i: usize, j: usize| ap.wrapping_add(uplo.packed(n, j, i));
+        strmv_unblocked(kernels, uplo.transpose(), diag, n, n, at, false, 1, x, incx);
+    } else {
+        let at = |i: usize, j: usize| ap.wrapping_add(uplo.packed(n, i, j));
+        strmv_unblocked(kernels, uplo, diag, n, n, at, true, 1, x, incx);
+    }
+}
+
+/// Solves `op(A) * x = b` for `x`, where `A` is the `n x n` triangular matrix
+/// held in the `uplo` triangle, `op(A)` is `A` or, when `trans` is set, its
+/// transpose, and `x` holds `b` on entry. `incx` must not be zero.
+///
+/// No test for singularity is made.
+pub unsafe fn strsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let (rsa, csa) = layout.strides(trans, lda);
+    let uplo = if trans { uplo.transpose() } else { uplo };
+
+    let x = x.offset(start(n, incx));
+
+    strsv_strided(context, uplo, diag, n, a, rsa, csa, x, incx);
+}
+
+// Solves A * x = b for the triangular matrix A held in the uplo triangle,
+// where element (i, j) of A lives at a + i * rsa + j * csa and x points at
+// the element visited first.
+pub(crate) unsafe fn strsv_strided(
+    context: &Context,
+    uplo: Uplo,
+    diag: Diag,
+    n: usize,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+    let a_at = |i: usize, j: usize| a.wrapping_add(i * rsa + j * csa);
+    let x_at = |i: usize| x.wrapping_offset(i as isize * incx);
+
+    let blocks = (0..n).step_by(TRIANGLE_BLOCK);
+    let block = |i: usize| std::cmp::min(n - i, TRIANGLE_BLOCK);
+    let diagonal = |i: usize, ib: usize| {
+        strsv_unblocked(
+            kernels,
+            uplo,
+            diag,
+            ib,
+            ib,
+            |r, c| a_at(i + r, i + c),
+            rsa <= csa,
+            std::cmp::min(rsa, csa) as isize,
+            x_at(i),
+            incx,
+        )
+    };
+
+    // Solved blocks of x are subtracted from the rest of x with contiguous
+    // columns, otherwise every block first subtracts the solved rest of x.
+    match (uplo, rsa <= csa) {
+        (Uplo::Upper, true) => {
+            for i in blocks.rev() {
+                let ib = block(i);
+                diagonal(i, ib);
+                sgemv_strided(
+                    context,
+                    i,
+                    ib,
+                    -1.0,
+                    a_at(0, i),
+                    rsa,
+                    csa,
+                    x_at(i),
+                    incx,
+                    1.0,
+                    x,
+                    incx,
+                );
+            }
+        }
+        (Uplo::Lower, true) => {
+            for i in blocks {
+                let ib = block(i);
+                diagonal(i, ib);
+                sgemv_strided(
+                    context,
+                    n - i - ib,
+                    ib,
+                    -1.0,
+                    a_at(i + ib, i),
+                    rsa,
+                    csa,
+                    x_at(i),
+                    incx,
+                    1.0,
+                    x_at(i + ib),
+                    incx,
+                );
+            }
+        }
+        (Uplo::Upper, false) => {
+            for i in blocks.rev() {
+                let ib = block(i);
+                sgemv_strided(
+                    context,
+                    ib,
+                    n - i - ib,
+                    -1.0,
+                    a_at(i, i + ib),
+                    rsa,
+                    csa,
+                    x_at(i + ib),
+                    incx,
+                    1.0,
+                    x_at(i),
+                    incx,
+                );
+                diagonal(i, ib);
+            }
+        }
+        (Uplo::Lower, false) => {
+            for i in blocks {
+                let ib = block(i);
+                sgemv_strided(
+                    context,
+                    ib,
+                    i,
+                    -1.0,
+                    a_at(i, 0),
+                    rsa,
+                    csa,
+                    x,
+                    incx,
+                    1.0,
+                    x_at(i),
+                    incx,
+                );
+                diagonal(i, ib);
+            }
+        }
+    }
+}
+
+// Solves A * x = b for the triangular matrix A held in the uplo triangle
+// with k diagonals next to the main one, where element (i, j) lives at
+// at(i, j). Contiguous columns are walked by axpy updates and contiguous rows
+// by dot products, inc elements apart.
+unsafe fn strsv_unblocked<F: Fn(usize, usize) -> *const f32>(
+    kernels: &L1Kernels<f32>,
+    uplo: Uplo,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    at: F,
+    columns: bool,
+    inc: isize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let x_at = |i: usize| x.wrapping_offset(i as isize * incx);
+
+    let nounit = diag == Diag::NonUnit;
+
+    match (uplo, columns) {
+        (Uplo::Upper, true) => {
+            for j in (0..n).rev() {
+                if *x_at(j) != 0.0 {
+                    if nounit {
+                        *x_at(j) /= *at(j, j);
+                    }
+                    let i = j.saturating_sub(k);
+                    (kernels.axpy)(j - i, -*x_at(j), at(i, j), inc, x_at(i), incx);
+                }
+            }
+        }
+        (Uplo::Lower, true) => {
+            for j in 0..n {
+                if *x_at(j) != 0.0 {
+                    if nounit {
+                        *x_at(j) /= *at(j, j);
+                    }
+                    let len = std::cmp::min(n - j - 1, k);
+                    (kernels.axpy)(len, -*x_at(j), at(j + 1, j), inc, x_at(j + 1), incx);
+                }
+            }
+        }
+        (Uplo::Upper, false) => {
+            for i in (0..n).rev() {
+                let len = std::cmp::min(n - i - 1, k);
+                let mut temp = *x_at(i) - (kernels.dot)(len, at(i, i + 1), inc, x_at(i + 1), incx);
+                if nounit {
+                    temp /= *at(i, i);
+                }
+                *x_at(i) = temp;
+            }
+        }
+        (Uplo::Lower, false) => {
+            for i in 0..n {
+                let j = i.saturating_sub(k);
+                let mut temp = *x_at(i) - (kernels.dot)(i - j, at(i, j), inc, x_at(j), incx);
+                if nounit {
+                    temp /= *at(i, i);
+                }
+                *x_at(i) = temp;
+            }
+        }
+    }
+}
+
+/// Solves `op(A) * x = b` for `x`, where `A` is the `n x n` triangular band
+/// matrix with `k` diagonals next to the main one held in the `uplo` triangle
+/// of band storage, `op(A)` is `A` or, when `trans` is set, its transpose, and
+/// `x` holds `b` on entry. The storage is that of [`stbmv`]. `incx` must not
+/// be zero.
+///
+/// No test for singularity is made.
+pub unsafe fn stbsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: *const f32,
+    lda: usize,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    let (kl, ku) = uplo.band(k);
+    let (offset, rs, cs) = layout.band(kl, ku, lda);
+    let (rsa, csa) = if trans { (cs, rs) } else { (rs, cs) };
+    let at = |i: usize, j: usize| a.wrapping_add(offset + i * rsa + j * csa);
+    let uplo = if trans { uplo.transpose() } else { uplo };
+
+    let x = x.offset(start(n, incx));
+
+    let inc = std::cmp::min(rsa, csa) as isize;
+    strsv_unblocked(kernels, uplo, diag, n, k, at, rsa <= csa, inc, x, incx);
+}
+
+/// Solves `op(A) * x = b` for `x`, where `A` is the `n x n` triangular matrix
+/// packed in `ap` as for [`stpmv`], `op(A)` is `A` or, when `trans` is set,
+/// its transpose, and `x` holds `b` on entry. `incx` must not be zero.
+///
+/// No test for singularity is made.
+pub unsafe fn stpsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: *const f32,
+    x: *mut f32,
+    incx: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    let (uplo, trans) = layout.packed(uplo, trans);
+
+    let x = x.offset(start(n, incx));
+
+    if trans {
+        let at = |i: usize, j: usize| ap.wrapping_add(uplo.packed(n, j, i));
+        strsv_unblocked(kernels, uplo.transpose(), diag, n, n, at, false, 1, x, incx);
+    } else {
+        let at = |i: usize, j: usize| ap.wrapping_add(uplo.packed(n, i, j));
+        strsv_unblocked(kernels, uplo, diag, n, n, at, true, 1, x, incx);
+    }
+}
+
+/// Computes `y = alpha * A * x + beta * y` for the symmetric `n x n` matrix
+/// `A` held in the `uplo` triangle. When `beta` is zero `y` is not read.
+pub unsafe fn ssymv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let (rsa, csa) = layout.strides(false, lda);
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    ssymv_strided(context, uplo, n, alpha, a, rsa, csa, x, incx, beta, y, incy);
+}
+
+// Computes y = alpha * A * x + beta * y for the symmetric matrix A held in
+// the uplo triangle, where element (i, j) of A lives at a + i * rsa + j * csa,
+// and x and y point at the elements visited first.
+pub(crate) unsafe fn ssymv_strided(
+    context: &Context,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    ssbmv_strided(
+        context,
+        uplo,
+        n,
+        n.saturating_sub(1),
+        alpha,
+        a,
+        rsa,
+        csa,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    );
+}
+
+/// Computes `y = alpha * A * x + beta * y` for the symmetric `n x n` band
+/// matrix `A` with `k` diagonals next to the main one held in the `uplo`
+/// triangle of band storage. The storage is that of [`stbmv`]. When `beta` is
+/// zero `y` is not read.
+pub unsafe fn ssbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: *const f32,
+    lda: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let (kl, ku) = uplo.band(k);
+    let (offset, rsa, csa) = layout.band(kl, ku, lda);
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    ssbmv_strided(
+        context,
+        uplo,
+        n,
+        k,
+        alpha,
+        a.wrapping_add(offset),
+        rsa,
+        csa,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    );
+}
+
+// Computes y = alpha * A * x + beta * y for the symmetric band matrix A with
+// k diagonals next to the main one held in the uplo triangle, where element
+// (i, j) of the band lives at a + i * rsa + j * csa, and x and y point at the
+// elements visited first.
+pub(crate) unsafe fn ssbmv_strided(
+    context: &Context,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    // A is its own transpose, so its rows are read as the columns of the
+    // other triangle of A^T.
+    let (uplo, rsa, csa) = if rsa > csa {
+        (uplo.transpose(), csa, rsa)
+    } else {
+        (uplo, rsa, csa)
+    };
+
+    let at = |i: usize, j: usize| a.wrapping_add(i * rsa + j * csa);
+
+    ssymv_unblocked(
+        kernels,
+        uplo,
+        n,
+        k,
+        alpha,
+        at,
+        rsa as isize,
+        x,
+        incx,
+        beta,
+        y,
+        incy,
+    );
+}
+
+/// Computes `y = alpha * A * x + beta * y` for the symmetric `n x n` matrix
+/// `A` whose `uplo` triangle is packed in `ap` as for [`sspr`]. When `beta` is
+/// zero `y` is not read.
+pub unsafe fn sspmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f32,
+    ap: *const f32,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    let kernels = &context.kernels().l1s;
+
+    let (uplo, _) = layout.packed(uplo, false);
+    let at = |i: usize, j: usize| ap.wrapping_add(uplo.packed(n, i, j));
+
+    let x = x.offset(start(n, incx));
+    let y = y.offset(start(n, incy));
+
+    ssymv_unblocked(kernels, uplo, n, n, alpha, at, 1, x, incx, beta, y, incy);
+}
+
+// Computes y = alpha * A * x + beta * y for the symmetric matrix A held in
+// the uplo triangle with k diagonals next to the main one, where element
+// (i, j) lives at at(i, j) and columns are contiguous with inc elements
+// between their elements.
+unsafe fn ssymv_unblocked<F: Fn(usize, usize) -> *const f32>(
+    kernels: &L1Kernels<f32>,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    at: F,
+    inc: isize,
+    x: *const f32,
+    incx: isize,
+    beta: f32,
+    y: *mut f32,
+    incy: isize,
+) {
+    if n == 0 || alpha == 0.0 && beta == 1.0 {
+        return;
+    }
+
+    sscale(kernels, n, beta, y, incy);
+
+    if alpha == 0.0 {
+        return;
+    }
+
+    let x_at = |i: usize| x.wrapping_offset(i as isize * incx);
+    let y_at = |i: usize| y.wrapping_offset(i as isize * incy);
+
+    // The part of column j off the diagonal is added into y for element j of
+    // x and dotted with x for element j of y in one pass, so every element of
+    // the triangle is read once.
+    for j in 0..n {
+        let temp = alpha * *x_at(j);
+        let (i, len) = match uplo {
+            Uplo::Upper => (j.saturating_sub(k), std::cmp::min(j, k)),
+            Uplo::Lower => (j + 1, std::cmp::min(n - j - 1, k)),
+        };
+
+        let dot = (kernels.dotaxpy)(len, temp, at(i, j), inc, x_at(i), incx, y_at(i), incy);
+
+        *y_at(j) += temp * *at(j, j) + alpha * dot;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::ContextBuilder;
+
+    const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+
+    // Fills the gaps of strided vectors and the unused parts of arrays, so that
+    // reading or writing them shows up in the results.
+    const PAD: f32 = 1000.5;
+
+    // Small integers, so that every sum in these tests is exact whatever order
+    // the kernels add in.
+    fn value(i: usize, j: usize) -> f32 {
+        ((i * 7 + j * 13) % 11) as f32 - 5.
+    }
+
+    // Splits level 2 routines past PARALLEL_THRESHOLD between four threads.
+    fn context() -> Context {
+        ContextBuilder::new().num_threads(4).build().unwrap()
+    }
+
+    // Position of element (i, j) of a matrix with leading dimension ld.
+    fn index(layout: Layout, ld: usize, i: usize, j: usize) -> usize {
+        match layout {
+            Layout::ColMajor => i + j * ld,
+            Layout::RowMajor => i * ld + j,
+        }
+    }
+
+    // Position of element i of a vector of n elements with increment inc.
+    fn at(n: usize, inc: isize, i: usize) -> usize {
+        if inc > 0 {
+            i * inc as usize
+        } else {
+            (n - 1 - i) * inc.unsigned_abs()
+        }
+    }
+
+    // Vector of n elements with increment inc holding f(i) at element i.
+    fn vector(n: usize, inc: isize, f: impl Fn(usize) -> f32) -> Vec<f32> {
+        let mut v = vec![PAD; n.saturating_sub(1) * inc.unsigned_abs() + 1];
+        for i in 0..n {
+            v[at(n, inc, i)] = f(i);
+        }
+        v
+    }
+
+    // m x n matrix with leading dimension ld holding f(i, j) at (i, j).
+    fn matrix(
+        layout: Layout,
+        m: usize,
+        n: usize,
+        ld: usize,
+        f: impl Fn(usize, usize) -> f32,
+    ) -> Vec<f32> {
+        let len = match layout {
+            Layout::ColMajor => ld * n,
+            Layout::RowMajor => m * ld,
+        };
+        let mut a = vec![PAD; len.max(1)];
+        for i in 0..m {
+            for j in 0..n {
+                a[index(layout, ld, i, j)] = f(i, j);
+            }
+        }
+        a
+    }
+
+    // y = alpha * A * x + beta * y for the m x n matrix A with element (i, j)
+    // a(i, j), without reading y when beta is zero.
+    fn reference_gemv(
+        m: usize,
+        n: usize,
+        alpha: f32,
+        a: impl Fn(usize, usize) -> f32,
+        x: &[f32],
+        incx: isize,
+        beta: f32,
+        y: &mut [f32],
+        incy: isize,
+    ) {
+        for i in 0..m {
+            let dot: f32 = (0..n).map(|j| a(i, j) * x[at(n, incx, j)]).sum();
+            let y = &mut y[at(m, incy, i)];
+            *y = if beta != 0. {
+                alpha * dot + beta * *y
+            } else {
+                alpha * dot
+            };
+        }
+    }
+
+    const INCREMENTS: [(isize, isize); 4] = [(1, 1), (2, -1), (-3, 2), (-1, -1)];
+
+    #[test]
+    fn gemv_matches_the_reference() {
+        let context = context();
+
+        // 300 x 250 is past PARALLEL_THRESHOLD.
+        let sizes = [(1, 1), (1, 9), (9, 1), (5, 3), (3, 7), (33, 17), (300, 250)];
+
+        for &layout in &LAYOUTS {
+            for &trans in &[false, true] {
+                for &(m, n) in &sizes {
+                    for &(incx, incy) in &INCREMENTS {
+                        for &beta in &[0., 1., -2.] {
+                            let ld = match layout {
+                                Layout::ColMajor => m,
+                                Layout::RowMajor => n,
+                            } + 3;
+                            let a = matrix(layout, m, n, ld, value);
+                            let (ym, xn) = if trans { (n, m) } else { (m, n) };
+                            let x = vector(xn, incx, |j| value(j, 3));
+                            let mut y =
+                                vector(
+                                    ym,
+                                    incy,
+                                    |i| if beta == 0. { f32::NAN } else { value(5, i) },
+                                );
+                            let mut expected = y.clone();
+
+                            let op = |i, j| {
+                                if trans {
+                                    a[index(layout, ld, j, i)]
+                                } else {
+                                    a[index(layout, ld, i, j)]
+                                }
+                            };
+                            reference_gemv(ym, xn, 2., op, &x, incx, beta, &mut expected, incy);
+
+                            unsafe {
+                                sgemv(
+                                    &context,
+                                    layout,
+                                    trans,
+                                    m,
+                                    n,
+                                    2.,
+                                    a.as_ptr(),
+                                    ld,
+                                    x.as_ptr(),
+                                    incx,
+                                    beta,
+                                    y.as_mut_ptr(),
+                                    incy,
+                                )
+                            };
+
+                            assert_eq!(
+                                y, expected,
+                                "{:?} trans {} {} x {} incx {} incy {} beta {}",
+                                layout, trans, m, n, incx, incy, beta
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Band storage with leading dimension ld of the m x n matrix with kl
+    // subdiagonals and ku superdiagonals holding f(i, j) at (i, j).
+    fn band(
+        layout: Layout,
+        m: usize,
+        n: usize,
+        kl: usize,
+        ku: usize,
+        ld: usize,
+        f: impl Fn(usize, usize) -> f32,
+    ) -> Vec<f32> {
+        let mut a = vec![PAD; ld * std::cmp::max(m, n)];
+        for i in 0..m {
+            for j in 0..n {
+                if in_band(kl, ku, i, j) {
+                    let k = match layout {
+                        Layout::ColMajor => ku + i - j + j * ld,
+                        Layout::RowMajor => i * ld + kl + j - i,
+                    };
+                    a[k] = f(i, j);
+                }
+            }
+        }
+        a
+    }
+
+    // Whether (i, j) lies in the band with kl subdiagonals and ku
+    // superdiagonals.
+    fn in_band(kl: usize, ku: usize, i: usize, j: usize) -> bool {
+        i <= j + kl && j <= i + ku
+    }
+
+    #[test]
+    fn gbmv_matches_the_reference() {
+        let context = context();
+
+        // The last band is past PARALLEL_THRESHOLD either way round.
+        let sizes = [
+            (1, 1, 0, 0),
+            (5, 5, 1, 2),
+            (7, 4, 2, 0),
+            (4, 7, 0, 3),
+            (6, 6, 0, 0),
+            (5, 3, 6, 6),
+            (3, 5, 9, 1),
+            (40, 33, 3, 5),
+            (700, 660, 50, 50),
+        ];
+
+        for &layout in &LAYOUTS {
+            for &trans in &[false, true] {
+                for &(m, n, kl, ku) in &sizes {
+                    for &(incx, incy) in &INCREMENTS {
+                        for &beta in &[0., -2.] {
+                            let ld = kl + ku + 2;
+                            let a = band(layout, m, n, kl, ku, ld, value);
+                            let (ym, xn) = if trans { (n, m) } else { (m, n) };
+                            let x = vector(xn, incx, |j| value(j, 3));
+                            let mut y =
+                                vector(
+                                    ym,
+                                    incy,
+                                    |i| if beta == 0. { f32::NAN } else { value(5, i) },
+                                );
+                            let mut expected = y.clone();
+
+                            let dense = |i, j| {
+                                if in_band(kl, ku, i, j) {
+                                    value(i, j)
+                                } else {
+                                    0.
+                                }
+                            };
+                            let op = |i, j| if trans { dense(j, i) } else { dense(i, j) };
+                            reference_gemv(ym, xn, 2., op, &x, incx, beta, &mut expected, incy);
+
+                            unsafe {
+                                sgbmv(
+                                    &context,
+                                    layout,
+                                    trans,
+                                    m,
+                                    n,
+                                    kl,
+                                    ku,
+                                    2.,
+                                    a.as_ptr(),
+                                    ld,
+                                    x.as_ptr(),
+                                    incx,
+                                    beta,
+                                    y.as_mut_ptr(),
+                                    incy,
+                                )
+                            };
+
+                            assert_eq!(
+                                y, expected,
+                                "{:?} trans {} {} x {} kl {} ku {} incx {} incy {} beta {}",
+                                layout, trans, m, n, kl, ku, incx, incy, beta
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Whether (i, j) lies in the uplo triangle.
+    fn in_triangle(uplo: Uplo, i: usize, j: usize) -> bool {
+        match uplo {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        }
+    }
+
+    #[test]
+    fn ger_updates_the_whole_matrix() {
+        let context = context();
+
+        // 300 x 250 is past PARALLEL_THRESHOLD.
+        for &layout in &LAYOUTS {
+            for &(m, n) in &[(1, 1), (5, 3), (3, 7), (33, 17), (300, 250)] {
+                for &(incx, incy) in &INCREMENTS {
+                    let ld = match layout {
+                        Layout::ColMajor => m,
+                        Layout::RowMajor => n,
+                    } + 3;
+                    let x = vector(m, incx, |i| value(i, 1));
+                    let y = vector(n, incy, |j| value(2, j));
+                    let mut a = matrix(layout, m, n, ld, value);
+                    let expected = matrix(layout, m, n, ld, |i, j| {
+                        value(i, j) + 2. * value(i, 1) * value(2, j)
+                    });
+
+                    unsafe {
+                        sger(
+                            &context,
+                            layout,
+                            m,
+                            n,
+                            2.,
+                            x.as_ptr(),
+                            incx,
+                            y.as_ptr(),
+                            incy,
+                            a.as_mut_ptr(),
+                            ld,
+                        )
+                    };
+
+                    assert_eq!(
+                        a, expected,
+                        "{:?} {} x {} incx {} incy {}",
+                        layout, m, n, incx, incy
+                    );
+                }
+            }
+        }
+    }
+
+    // n = 400 is past PARALLEL_THRESHOLD for both updates.
+    const SYR_SIZES: [usize; 5] = [1, 2, 7, 33, 400];
+
+    #[test]
+    fn syr_writes_only_its_triangle() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &SYR_SIZES {
+                    for &incx in &[1, -2] {
+                        let ld = n + 2;
+                        let x = vector(n, incx, |i| value(i, 1));
+                        let mut a = matrix(layout, n, n, ld, value);
+                        let expected = matrix(layout, n, n, ld, |i, j| {
+                            if in_triangle(uplo, i, j) {
+                                value(i, j) + 2. * value(i, 1) * value(j, 1)
+                            } else {
+                                value(i, j)
+                            }
+                        });
+
+                        unsafe {
+                            ssyr(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                a.as_mut_ptr(),
+                                ld,
+                            )
+                        };
+
+                        assert_eq!(a, expected, "{:?} {:?} n {} incx {}", layout, uplo, n, incx);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn syr2_writes_only_its_triangle() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &SYR_SIZES {
+                    for &(incx, incy) in &INCREMENTS {
+                        let ld = n + 2;
+                        let x = vector(n, incx, |i| value(i, 1));
+                        let y = vector(n, incy, |j| value(2, j));
+                        let mut a = matrix(layout, n, n, ld, value);
+                        let expected = matrix(layout, n, n, ld, |i, j| {
+                            if in_triangle(uplo, i, j) {
+                                value(i, j)
+                                    + 2. * value(i, 1) * value(2, j)
+                                    + 2. * value(2, i) * value(j, 1)
+                            } else {
+                                value(i, j)
+                            }
+                        });
+
+                        unsafe {
+                            ssyr2(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                y.as_ptr(),
+                                incy,
+                                a.as_mut_ptr(),
+                                ld,
+                            )
+                        };
+
+                        assert_eq!(
+                            a, expected,
+                            "{:?} {:?} n {} incx {} incy {}",
+                            layout, uplo, n, incx, incy
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    // Triangular matrix whose off-diagonal elements are small next to its
+    // diagonal, so that it is well conditioned whatever its order. Elements
+    // that must not be read are NaN.
+    fn triangle(layout: Layout, uplo: Uplo, diag: Diag, n: usize, ld: usize) -> Vec<f32> {
+        matrix(layout, n, n, ld, |i, j| {
+            if i == j {
+                match diag {
+                    Diag::NonUnit => 2. + (i % 3) as f32,
+                    Diag::Unit => f32::NAN,
+                }
+            } else if in_triangle(uplo, i, j) {
+                value(i, j) / (8 * n) as f32
+            } else {
+                f32::NAN
+            }
+        })
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32], tol: f32, context: &str) {
+        for (k, (a, e)) in actual.iter().zip(expected).enumerate() {
+            assert!(
+                (a - e).abs() <= tol * e.abs().max(1.),
+                "{} is not {} at {} for {}",
+                a,
+                e,
+                k,
+                context
+            );
+        }
+    }
+
+    #[test]
+    fn trmv_and_trsv_round_trip() {
+        let context = context();
+
+        // Orders on both sides of TRIANGLE_BLOCK and of two blocks.
+        for &n in &[1, 5, 127, 128, 129, 300] {
+            let tol = 16. * n as f32 * f32::EPSILON;
+
+            for &layout in &LAYOUTS {
+                for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                    for &trans in &[false, true] {
+                        for &diag in &[Diag::NonUnit, Diag::Unit] {
+                            for &incx in &[1, -2] {
+                                let ld = n + 1;
+                                let a = triangle(layout, uplo, diag, n, ld);
+                                let x0 = vector(n, incx, |i| value(i, 4));
+
+                                let dense = |i: usize, j: usize| {
+                                    if i == j && diag == Diag::Unit {
+                                        1.
+                                    } else if in_triangle(uplo, i, j) {
+                                        a[index(layout, ld, i, j)]
+                                    } else {
+                                        0.
+                                    }
+                                };
+                                let op = |i, j| if trans { dense(j, i) } else { dense(i, j) };
+                                let mut expected = x0.clone();
+                                reference_gemv(n, n, 1., op, &x0, incx, 0., &mut expected, incx);
+
+                                let what = format!(
+                                    "{:?} {:?} trans {} {:?} n {} incx {}",
+                                    layout, uplo, trans, diag, n, incx
+                                );
+
+                                let mut x = x0.clone();
+                                unsafe {
+                                    strmv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        a.as_ptr(),
+                                        ld,
+                                        x.as_mut_ptr(),
+                                        incx,
+                                    )
+                                };
+                                assert_close(&x, &expected, tol, &what);
+
+                                unsafe {
+                                    strsv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        a.as_ptr(),
+                                        ld,
+                                        x.as_mut_ptr(),
+                                        incx,
+                                    )
+                                };
+                                assert_close(&x, &x0, tol, &what);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dotaxpy_kernels_match_dot_and_axpy() {
+        for kernels in crate::kernels::available() {
+            for n in 0..70 {
+                for &(incx, incy, incz) in &[(1, 1, 1), (2, 3, 1), (1, 2, 3)] {
+                    let x = vector(n, incx, |i| value(i, 1));
+                    let y = vector(n, incy, |i| value(2, i));
+                    let mut z = vector(n, incz, |i| value(i, i));
+                    let mut expected = z.clone();
+                    for i in 0..n {
+                        expected[i * incz as usize] += 2. * x[i * incx as usize];
+                    }
+
+                    let dot = unsafe {
+                        (kernels.l1s.dotaxpy)(
+                            n,
+                            2.,
+                            x.as_ptr(),
+                            incx,
+                            y.as_ptr(),
+                            incy,
+                            z.as_mut_ptr(),
+                            incz,
+                        )
+                    };
+
+                    let expected_dot: f32 = (0..n)
+                        .map(|i| x[i * incx as usize] * y[i * incy as usize])
+                        .sum();
+                    assert_eq!(dot, expected_dot, "n {} incs {:?}", n, (incx, incy, incz));
+                    assert_eq!(z, expected, "n {} incs {:?}", n, (incx, incy, incz));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn symv_matches_the_reference() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &[1, 2, 7, 33, 200] {
+                    for &(incx, incy) in &INCREMENTS {
+                        for &beta in &[0., -2.] {
+                            // The other triangle must not be read.
+                            let ld = n + 2;
+                            let a = matrix(layout, n, n, ld, |i, j| {
+                                if in_triangle(uplo, i, j) {
+                                    value(i, j)
+                                } else {
+                                    f32::NAN
+                                }
+                            });
+                            let x = vector(n, incx, |j| value(j, 3));
+                            let mut y =
+                                vector(
+                                    n,
+                                    incy,
+                                    |i| if beta == 0. { f32::NAN } else { value(5, i) },
+                                );
+                            let mut expected = y.clone();
+
+                            let symmetric = |i, j| {
+                                if in_triangle(uplo, i, j) {
+                                    value(i, j)
+                                } else {
+                                    value(j, i)
+                                }
+                            };
+                            reference_gemv(
+                                n,
+                                n,
+                                2.,
+                                symmetric,
+                                &x,
+                                incx,
+                                beta,
+                                &mut expected,
+                                incy,
+                            );
+
+                            unsafe {
+                                ssymv(
+                                    &context,
+                                    layout,
+                                    uplo,
+                                    n,
+                                    2.,
+                                    a.as_ptr(),
+                                    ld,
+                                    x.as_ptr(),
+                                    incx,
+                                    beta,
+                                    y.as_mut_ptr(),
+                                    incy,
+                                )
+                            };
+
+                            assert_eq!(
+                                y, expected,
+                                "{:?} {:?} n {} incx {} incy {} beta {}",
+                                layout, uplo, n, incx, incy, beta
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Diagonal counts of a symmetric or triangular band of order n: none,
+    // one, several, and as many or more than the matrix has.
+    fn band_widths(n: usize) -> [usize; 5] {
+        [0, 1, 3, n, n + 2]
+    }
+
+    // Position of element (i, j) of the uplo triangle of an n x n matrix in
+    // packed storage with this layout.
+    fn packed_index(layout: Layout, uplo: Uplo, n: usize, i: usize, j: usize) -> usize {
+        match (layout, uplo) {
+            (Layout::ColMajor, Uplo::Upper) => i + j * (j + 1) / 2,
+            (Layout::ColMajor, Uplo::Lower) => i + j * (2 * n - j - 1) / 2,
+            (Layout::RowMajor, Uplo::Upper) => i * (2 * n - i + 1) / 2 + j - i,
+            (Layout::RowMajor, Uplo::Lower) => i * (i + 1) / 2 + j,
+        }
+    }
+
+    // The uplo triangle of the n x n matrix holding f(i, j) at (i, j), packed.
+    fn packed(layout: Layout, uplo: Uplo, n: usize, f: impl Fn(usize, usize) -> f32) -> Vec<f32> {
+        let mut ap = vec![PAD; n * (n + 1) / 2];
+        for i in 0..n {
+            for j in 0..n {
+                if in_triangle(uplo, i, j) {
+                    ap[packed_index(layout, uplo, n, i, j)] = f(i, j);
+                }
+            }
+        }
+        ap
+    }
+
+    #[test]
+    fn sbmv_matches_symv_on_the_unpacked_band() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &[1, 5, 9] {
+                    for &k in &band_widths(n) {
+                        for &(incx, incy) in &INCREMENTS {
+                            let (kl, ku) = uplo.band(k);
+                            let ld = k + 2;
+                            let dense = |i: usize, j: usize| {
+                                if i.max(j) - i.min(j) <= k {
+                                    value(i.min(j), i.max(j))
+                                } else {
+                                    0.
+                                }
+                            };
+                            let a = band(layout, n, n, kl, ku, ld, dense);
+                            let full = matrix(layout, n, n, n, dense);
+                            let x = vector(n, incx, |j| value(j, 3));
+                            let mut y = vector(n, incy, |i| value(5, i));
+                            let mut expected = y.clone();
+
+                            unsafe {
+                                ssbmv(
+                                    &context,
+                                    layout,
+                                    uplo,
+                                    n,
+                                    k,
+                                    2.,
+                                    a.as_ptr(),
+                                    ld,
+                                    x.as_ptr(),
+                                    incx,
+                                    -2.,
+                                    y.as_mut_ptr(),
+                                    incy,
+                                );
+                                ssymv(
+                                    &context,
+                                    layout,
+                                    uplo,
+                                    n,
+                                    2.,
+                                    full.as_ptr(),
+                                    n,
+                                    x.as_ptr(),
+                                    incx,
+                                    -2.,
+                                    expected.as_mut_ptr(),
+                                    incy,
+                                );
+                            }
+
+                            assert_eq!(
+                                y, expected,
+                                "{:?} {:?} n {} k {} incx {} incy {}",
+                                layout, uplo, n, k, incx, incy
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tbmv_and_tbsv_match_trmv_and_trsv_on_the_unpacked_band() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &trans in &[false, true] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        for &n in &[1, 5, 9] {
+                            let tol = 16. * n as f32 * f32::EPSILON;
+                            let full = triangle(layout, uplo, diag, n, n);
+
+                            for &k in &band_widths(n) {
+                                for &incx in &[1, -2] {
+                                    let (kl, ku) = uplo.band(k);
+                                    let ld = k + 2;
+                                    let dense = |i: usize, j: usize| {
+                                        if i.max(j) - i.min(j) <= k {
+                                            full[index(layout, n, i, j)]
+                                        } else {
+                                            0.
+                                        }
+                                    };
+                                    let a = band(layout, n, n, kl, ku, ld, dense);
+                                    let full = matrix(layout, n, n, n, dense);
+                                    let x0 = vector(n, incx, |i| value(i, 4));
+                                    let what = format!(
+                                        "{:?} {:?} trans {} {:?} n {} k {} incx {}",
+                                        layout, uplo, trans, diag, n, k, incx
+                                    );
+
+                                    let mut x = x0.clone();
+                                    let mut expected = x0.clone();
+                                    unsafe {
+                                        stbmv(
+                                            &context,
+                                            layout,
+                                            uplo,
+                                            trans,
+                                            diag,
+                                            n,
+                                            k,
+                                            a.as_ptr(),
+                                            ld,
+                                            x.as_mut_ptr(),
+                                            incx,
+                                        );
+                                        strmv(
+                                            &context,
+                                            layout,
+                                            uplo,
+                                            trans,
+                                            diag,
+                                            n,
+                                            full.as_ptr(),
+                                            n,
+                                            expected.as_mut_ptr(),
+                                            incx,
+                                        );
+                                    }
+                                    assert_close(&x, &expected, tol, &what);
+
+                                    let mut x = x0.clone();
+                                    let mut expected = x0.clone();
+                                    unsafe {
+                                        stbsv(
+                                            &context,
+                                            layout,
+                                            uplo,
+                                            trans,
+                                            diag,
+                                            n,
+                                            k,
+                                            a.as_ptr(),
+                                            ld,
+                                            x.as_mut_ptr(),
+                                            incx,
+                                        );
+                                        strsv(
+                                            &context,
+                                            layout,
+                                            uplo,
+                                            trans,
+                                            diag,
+                                            n,
+                                            full.as_ptr(),
+                                            n,
+                                            expected.as_mut_ptr(),
+                                            incx,
+                                        );
+                                    }
+                                    assert_close(&x, &expected, tol, &what);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // n = 400 is past PARALLEL_THRESHOLD for the packed updates.
+    const PACKED_SIZES: [usize; 5] = [1, 2, 5, 33, 400];
+
+    #[test]
+    fn spmv_matches_symv_on_the_unpacked_triangle() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &PACKED_SIZES {
+                    for &(incx, incy) in &INCREMENTS {
+                        let ap = packed(layout, uplo, n, value);
+                        let full = matrix(layout, n, n, n, |i, j| {
+                            if in_triangle(uplo, i, j) {
+                                value(i, j)
+                            } else {
+                                f32::NAN
+                            }
+                        });
+                        let x = vector(n, incx, |j| value(j, 3));
+                        let mut y = vector(n, incy, |i| value(5, i));
+                        let mut expected = y.clone();
+
+                        unsafe {
+                            sspmv(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                ap.as_ptr(),
+                                x.as_ptr(),
+                                incx,
+                                -2.,
+                                y.as_mut_ptr(),
+                                incy,
+                            );
+                            ssymv(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                full.as_ptr(),
+                                n,
+                                x.as_ptr(),
+                                incx,
+                                -2.,
+                                expected.as_mut_ptr(),
+                                incy,
+                            );
+                        }
+
+                        assert_eq!(
+                            y, expected,
+                            "{:?} {:?} n {} incx {} incy {}",
+                            layout, uplo, n, incx, incy
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tpmv_and_tpsv_match_trmv_and_trsv_on_the_unpacked_triangle() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &trans in &[false, true] {
+                    for &diag in &[Diag::NonUnit, Diag::Unit] {
+                        for &n in &[1, 2, 5, 33, 200] {
+                            let tol = 16. * n as f32 * f32::EPSILON;
+                            let full = triangle(layout, uplo, diag, n, n);
+                            let ap = packed(layout, uplo, n, |i, j| full[index(layout, n, i, j)]);
+
+                            for &incx in &[1, -2] {
+                                let x0 = vector(n, incx, |i| value(i, 4));
+                                let what = format!(
+                                    "{:?} {:?} trans {} {:?} n {} incx {}",
+                                    layout, uplo, trans, diag, n, incx
+                                );
+
+                                let mut x = x0.clone();
+                                let mut expected = x0.clone();
+                                unsafe {
+                                    stpmv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        ap.as_ptr(),
+                                        x.as_mut_ptr(),
+                                        incx,
+                                    );
+                                    strmv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        full.as_ptr(),
+                                        n,
+                                        expected.as_mut_ptr(),
+                                        incx,
+                                    );
+                                }
+                                assert_close(&x, &expected, tol, &what);
+
+                                let mut x = x0.clone();
+                                let mut expected = x0.clone();
+                                unsafe {
+                                    stpsv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        ap.as_ptr(),
+                                        x.as_mut_ptr(),
+                                        incx,
+                                    );
+                                    strsv(
+                                        &context,
+                                        layout,
+                                        uplo,
+                                        trans,
+                                        diag,
+                                        n,
+                                        full.as_ptr(),
+                                        n,
+                                        expected.as_mut_ptr(),
+                                        incx,
+                                    );
+                                }
+                                assert_close(&x, &expected, tol, &what);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spr_and_spr2_match_syr_and_syr2_on_the_unpacked_triangle() {
+        let context = context();
+
+        for &layout in &LAYOUTS {
+            for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                for &n in &PACKED_SIZES {
+                    for &(incx, incy) in &INCREMENTS {
+                        let x = vector(n, incx, |i| value(i, 1));
+                        let y = vector(n, incy, |j| value(2, j));
+                        let what = format!(
+                            "{:?} {:?} n {} incx {} incy {}",
+                            layout, uplo, n, incx, incy
+                        );
+
+                        let mut ap = packed(layout, uplo, n, value);
+                        let mut full = matrix(layout, n, n, n, value);
+                        unsafe {
+                            sspr(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                ap.as_mut_ptr(),
+                            );
+                            ssyr(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                full.as_mut_ptr(),
+                                n,
+                            );
+                        }
+                        let expected = packed(layout, uplo, n, |i, j| full[index(layout, n, i, j)]);
+                        assert_eq!(ap, expected, "spr {}", what);
+
+                        let mut ap = packed(layout, uplo, n, value);
+                        let mut full = matrix(layout, n, n, n, value);
+                        unsafe {
+                            sspr2(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                y.as_ptr(),
+                                incy,
+                                ap.as_mut_ptr(),
+                            );
+                            ssyr2(
+                                &context,
+                                layout,
+                                uplo,
+                                n,
+                                2.,
+                                x.as_ptr(),
+                                incx,
+                                y.as_ptr(),
+                                incy,
+                                full.as_mut_ptr(),
+                                n,
+                            );
+                        }
+                        let expected = packed(layout, uplo, n, |i, j| full[index(layout, n, i, j)]);
+                        assert_eq!(ap, expected, "spr2 {}", what);
+                    }
+                }
+            }
+        }
+    }
+}
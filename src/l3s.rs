@@ -1,12 +1,13 @@
 use crate::context::Context;
-use crate::kernels::{sgemm_pa, sgemm_pb, sgemm_sup0, sgemm_sup1, sgemm_ukr};
-use crate::kernels::{SMR as MR, SNR as NR};
+use crate::kernels::GemmKernels;
 use crate::send::{SSend, SSendMut};
+use crate::types::Layout;
 
 pub unsafe fn sgemm(
     context: &Context,
-    _transa: bool,
-    _transb: bool,
+    layout: Layout,
+    transa: bool,
+    transb: bool,
     m: usize,
     n: usize,
     k: usize,
@@ -19,12 +20,99 @@ pub unsafe fn sgemm(
     c: *mut f32,
     ldc: usize,
 ) {
+    let (rsa, csa) = layout.strides(transa, lda);
+    let (rsb, csb) = layout.strides(transb, ldb);
+    let (rsc, csc) = layout.strides(false, ldc);
+
+    sgemm_strided(
+        context, m, n, k, alpha, a, rsa, csa, b, rsb, csb, beta, c, rsc, csc,
+    );
+}
+
+// Computes C = alpha * A * B + beta * C where element (i, j) of a matrix X
+// lives at x + i * rsx + j * csx.
+pub(crate) unsafe fn sgemm_strided(
+    context: &Context,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: *const f32,
+    rsa: usize,
+    csa: usize,
+    b: *const f32,
+    rsb: usize,
+    csb: usize,
+    beta: f32,
+    c: *mut f32,
+    rsc: usize,
+    csc: usize,
+) {
+    if m == 0 || n == 0 {
+        return;
+    }
+
+    let rsc = if m == 1 { 1 } else { rsc };
+    let csc = if n == 1 { 1 } else { csc };
+
+    if k == 0 || alpha == 0.0 {
+        for j in 0..n {
+            for i in 0..m {
+                let c = c.add(i * rsc + j * csc);
+                *c = if beta != 0.0 { beta * *c } else { 0.0 };
+            }
+        }
+        return;
+    }
+
+    // The microkernels store columns of C, so a row-major C is computed as
+    // C^T = B^T * A^T and any other layout goes through a temporary.
+    if rsc != 1 {
+        if csc == 1 {
+            sgemm_strided(
+                context, n, m, k, alpha, b, csb, rsb, a, csa, rsa, beta, c, 1, rsc,
+            );
+        } else {
+            let mut tmp = vec![0.0; m * n];
+
+            sgemm_strided(
+                context,
+                m,
+                n,
+                k,
+                alpha,
+                a,
+                rsa,
+                csa,
+                b,
+                rsb,
+                csb,
+                0.0,
+                tmp.as_mut_ptr(),
+                1,
+                m,
+            );
+
+            for j in 0..n {
+                for i in 0..m {
+                    let c = c.add(i * rsc + j * csc);
+                    let elem = tmp[i + j * m];
+                    *c = if beta != 0.0 { elem + beta * *c } else { elem };
+                }
+            }
+        }
+        return;
+    }
+
+    let ldc = csc;
+
     let mc = context.smc();
     let kc = context.skc();
     let nc = context.snc();
 
-    let pa = context.spa();
-    let pb = context.spb();
+    let buffers = context.buffers();
+    let pa = buffers.spa();
+    let pb = buffers.spb();
 
     for j in (0..n).step_by(nc) {
         let js = std::cmp::min(n - j, nc);
@@ -35,14 +123,17 @@ pub unsafe fn sgemm(
                 let is = std::cmp::min(m - i, mc);
                 sgemm_macrokernel(
                     context,
+                    &context.kernels().sgemm,
                     is,
                     js,
                     ps,
                     alpha,
-                    a.add(i + p * lda),
-                    lda,
-                    b.add(p + j * ldb),
-                    ldb,
+                    a.add(i * rsa + p * csa),
+                    rsa,
+                    csa,
+                    b.add(p * rsb + j * csb),
+                    rsb,
+                    csb,
                     beta_scale,
                     c.add(i + j * ldc),
                     ldc,
@@ -58,14 +149,17 @@ pub unsafe fn sgemm(
 
 unsafe fn sgemm_macrokernel(
     context: &Context,
+    kernels: &'static GemmKernels<f32>,
     m: usize,
     n: usize,
     k: usize,
     alpha: f32,
     a: *const f32,
-    lda: usize,
+    rsa: usize,
+    csa: usize,
     b: *const f32,
-    ldb: usize,
+    rsb: usize,
+    csb: usize,
     beta: f32,
     c: *mut f32,
     ldc: usize,
@@ -73,9 +167,12 @@ unsafe fn sgemm_macrokernel(
     pb: *mut f32,
     first_time: bool,
 ) {
-    let n_left = n % NR;
+    let mr = kernels.mr;
+    let nr = kernels.nr;
+
+    let n_left = n % nr;
     let n_main = n - n_left;
-    let m_left = m % MR;
+    let m_left = m % mr;
     let m_main = m - m_left;
 
     let a = SSend(a);
@@ -85,18 +182,18 @@ unsafe fn sgemm_macrokernel(
     let pb = SSendMut(pb);
 
     if first_time {
-        context.execute(0, n_main, NR, move |j| {
-            sgemm_pb(k, b.0.add(j * ldb), ldb, pb.0.add(j * k));
+        context.execute(0, n_main, nr, move |j| {
+            (kernels.pb)(k, b.0.add(j * csb), rsb, csb, pb.0.add(j * k));
         });
     }
 
-    context.execute(0, m_main, MR, move |i| {
-        sgemm_pa(k, a.0.add(i), lda, pa.0.add(i * k));
+    context.execute(0, m_main, mr, move |i| {
+        (kernels.pa)(k, a.0.add(i * rsa), rsa, csa, pa.0.add(i * k));
     });
 
-    context.execute(0, n_main, NR, move |j| {
-        for i in (0..m_main).step_by(MR) {
-            sgemm_ukr(
+    context.execute(0, n_main, nr, move |j| {
+        for i in (0..m_main).step_by(mr) {
+            (kernels.ukr)(
                 k,
                 alpha,
                 pa.0.add(i * k),
@@ -108,11 +205,11 @@ unsafe fn sgemm_macrokernel(
         }
 
         for i in m_main..m {
-            sgemm_sup1(
+            (kernels.sup1)(
                 k,
                 alpha,
-                a.0.add(i),
-                lda,
+                a.0.add(i * rsa),
+                csa,
                 pb.0.add(j * k),
                 beta,
                 c.0.add(i + j * ldc),
@@ -122,12 +219,13 @@ unsafe fn sgemm_macrokernel(
     });
 
     context.execute(n_main, n, 1, move |j| {
-        for i in (0..m_main).step_by(MR) {
-            sgemm_sup0(
+        for i in (0..m_main).step_by(mr) {
+            (kernels.sup0)(
                 k,
                 alpha,
                 pa.0.add(i * k),
-                b.0.add(j * ldb),
+                b.0.add(j * csb),
+                rsb,
                 beta,
                 c.0.add(i + j * ldc),
             );
@@ -137,7 +235,7 @@ unsafe fn sgemm_macrokernel(
             let mut elem = 0.0;
 
             for p in 0..k {
-                elem += *a.0.add(i + p * lda) * *b.0.add(p + j * ldb);
+                elem += *a.0.add(i * rsa + p * csa) * *b.0.add(p * rsb + j * csb);
             }
 
             elem *= alpha;
@@ -150,3 +248,237 @@ unsafe fn sgemm_macrokernel(
         }
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::ContextBuilder;
+    use crate::kernels::available;
+    use std::sync::Arc;
+    use std::thread;
+
+    // Small integers, so that every sum is exact whatever order the kernels
+    // add in.
+    fn value(i: usize, j: usize) -> f32 {
+        ((i * 7 + j * 13) % 11) as f32 - 5.
+    }
+
+    // Sizes below, at and past the tile sizes of every kernel table, so that
+    // the microkernel, sup0, sup1 and the scalar tail all run.
+    const SIZES: [(usize, usize, usize); 6] = [
+        (1, 1, 1),
+        (17, 13, 9),
+        (65, 29, 3),
+        (32, 12, 1),
+        (40, 40, 40),
+        (100, 50, 20),
+    ];
+
+    // Fills the unused parts of C, so that writing them shows up in the
+    // results.
+    const PAD: f32 = 1000.5;
+
+    fn index(layout: Layout, ld: usize, i: usize, j: usize) -> usize {
+        match layout {
+            Layout::ColMajor => i + j * ld,
+            Layout::RowMajor => i * ld + j,
+        }
+    }
+
+    // rows x cols matrix stored in `layout` holding f(i, j) at (i, j), placed
+    // after `offset` unused elements and with leading dimension `ld`. Unused
+    // elements hold `fill`.
+    fn matrix(
+        layout: Layout,
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        offset: usize,
+        fill: f32,
+        f: impl Fn(usize, usize) -> f32,
+    ) -> Vec<f32> {
+        let outer = match layout {
+            Layout::ColMajor => cols,
+            Layout::RowMajor => rows,
+        };
+        let mut x = vec![fill; offset + ld * outer];
+        for j in 0..cols {
+            for i in 0..rows {
+                x[offset + index(layout, ld, i, j)] = f(i, j);
+            }
+        }
+        x
+    }
+
+    // Checks C = 2 * op(A) * op(B) + beta * C on matrices stored in `layout`
+    // against a triple loop. Every matrix starts `offset` elements into its
+    // buffer and has an odd leading dimension past its rows, or columns when
+    // row-major, when `padded` is set. C holds
+    // NaN when beta is zero and A and B hold NaN outside the matrices, none of
+    // which may be read.
+    fn check_gemm(
+        context: &Context,
+        layout: Layout,
+        transa: bool,
+        transb: bool,
+        m: usize,
+        n: usize,
+        k: usize,
+        beta: f32,
+        offset: usize,
+        padded: bool,
+    ) {
+        let ld = |rows: usize, cols: usize| {
+            let inner = match layout {
+                Layout::ColMajor => rows,
+                Layout::RowMajor => cols,
+            };
+            if padded {
+                (inner + 1) | 1
+            } else {
+                inner
+            }
+        };
+        let (ar, ac) = if transa { (k, m) } else { (m, k) };
+        let (br, bc) = if transb { (n, k) } else { (k, n) };
+        let (lda, ldb, ldc) = (ld(ar, ac), ld(br, bc), ld(m, n));
+
+        let a = matrix(layout, ar, ac, lda, offset, f32::NAN, value);
+        let b = matrix(layout, br, bc, ldb, offset, f32::NAN, |i, j| {
+            value(j + 3, i)
+        });
+        let op_a = |i: usize, p: usize| {
+            let (r, c) = if transa { (p, i) } else { (i, p) };
+            a[offset + index(layout, lda, r, c)]
+        };
+        let op_b = |p: usize, j: usize| {
+            let (r, c) = if transb { (j, p) } else { (p, j) };
+            b[offset + index(layout, ldb, r, c)]
+        };
+
+        let c0 = |i: usize, j: usize| {
+            if beta == 0. {
+                f32::NAN
+            } else {
+                value(i + 1, j)
+            }
+        };
+        let mut c = matrix(layout, m, n, ldc, offset, PAD, c0);
+        let expected = matrix(layout, m, n, ldc, offset, PAD, |i, j| {
+            let dot: f32 = (0..k).map(|p| op_a(i, p) * op_b(p, j)).sum();
+            if beta == 0. {
+                2. * dot
+            } else {
+                2. * dot + beta * c0(i, j)
+            }
+        });
+
+        unsafe {
+            sgemm(
+                context,
+                layout,
+                transa,
+                transb,
+                m,
+                n,
+                k,
+                2.,
+                a.as_ptr().add(offset),
+                lda,
+                b.as_ptr().add(offset),
+                ldb,
+                beta,
+                c.as_mut_ptr().add(offset),
+                ldc,
+            )
+        };
+
+        assert_eq!(
+            c, expected,
+            "{:?} transa {} transb {} {} x {} x {} beta {} offset {} padded {}",
+            layout, transa, transb, m, n, k, beta, offset, padded
+        );
+    }
+
+    #[test]
+    fn gemm_matches_the_reference_for_every_transpose() {
+        for kernels in available() {
+            // Small odd block sizes make every blocking loop run several
+            // times and leave partial blocks at the edges.
+            let contexts = [
+                ContextBuilder::new().kernels(kernels).build().unwrap(),
+                ContextBuilder::new()
+                    .kernels(kernels)
+                    .f32_block_sizes(37, 19, 23)
+                    .f64_block_sizes(37, 19, 23)
+                    .build()
+                    .unwrap(),
+            ];
+
+            for context in &contexts {
+                for &(m, n, k) in &SIZES {
+                    for &transa in &[false, true] {
+                        for &transb in &[false, true] {
+                            for &beta in &[0., 1., -3.] {
+                                // An odd offset leaves every matrix unaligned.
+                                // Row-major C is computed as its transpose.
+                                for &(layout, offset, padded) in &[
+                                    (Layout::ColMajor, 0, false),
+                                    (Layout::ColMajor, 1, true),
+                                    (Layout::RowMajor, 1, true),
+                                ] {
+                                    check_gemm(
+                                        context, layout, transa, transb, m, n, k, beta, offset,
+                                        padded,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_on_a_shared_context_matches_the_reference() {
+        // Small blocks give every call many parallel steps, each of which must
+        // wait for its own jobs and not those of the other threads.
+        let context = Arc::new(
+            ContextBuilder::new()
+                .num_threads(3)
+                .f32_block_sizes(37, 19, 23)
+                .f64_block_sizes(37, 19, 23)
+                .build()
+                .unwrap(),
+        );
+
+        let threads: Vec<_> = (0..4)
+            .map(|t| {
+                let context = Arc::clone(&context);
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        for &(m, n, k) in &SIZES {
+                            check_gemm(
+                                &context,
+                                Layout::ColMajor,
+                                t % 2 == 1,
+                                t >= 2,
+                                m,
+                                n,
+                                k,
+                                0.,
+                                1,
+                                true,
+                            );
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+    }
+}
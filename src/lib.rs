@@ -1,9 +1,34 @@
+#![allow(clippy::missing_safety_doc, clippy::too_many_arguments)]
+
 pub mod aligned_alloc;
+mod cache;
+pub mod checked;
 mod context;
+mod error;
+#[cfg(any(feature = "cblas", feature = "fortran"))]
+mod ffi;
 mod kernels;
+mod l1d;
+mod l1s;
+mod l2d;
+mod l2s;
+mod l3d;
 mod l3s;
+pub mod mat;
+pub mod safe;
 mod send;
+mod stride;
+mod types;
 
-pub use context::Context;
-pub use kernels::*;
+pub use context::{Context, ContextBuilder, ContextError};
+pub use error::BlasError;
+#[cfg(any(feature = "cblas", feature = "fortran"))]
+pub use ffi::{set_error_handler, ErrorHandler};
+pub use l1d::*;
+pub use l1s::*;
+pub use l2d::*;
+pub use l2s::*;
+pub use l3d::*;
 pub use l3s::*;
+pub use threadpool::ThreadPool;
+pub use types::{Diag, Layout, Uplo};
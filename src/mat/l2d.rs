@@ -0,0 +1,231 @@
+use super::{MatMut, MatRef};
+use crate::context::Context;
+use crate::l2d::{
+    dgemv_strided, dger_strided, dsymv_strided, dsyr2_strided, dsyr_strided, dtrmv_strided,
+    dtrsv_strided,
+};
+use crate::types::{Diag, Uplo};
+
+/// Computes `y = alpha * a * x + beta * y`.
+///
+/// Panics unless `x` has `a.cols()` and `y` has `a.rows()` elements. When
+/// `beta` is zero `y` is not read.
+#[track_caller]
+pub fn dgemv(context: &Context, alpha: f64, a: MatRef<f64>, x: &[f64], beta: f64, y: &mut [f64]) {
+    assert!(
+        x.len() == a.cols() && y.len() == a.rows(),
+        "cannot multiply a {}x{} matrix by a vector of length {} into one of length {}",
+        a.rows(),
+        a.cols(),
+        x.len(),
+        y.len(),
+    );
+
+    unsafe {
+        dgemv_strided(
+            context,
+            a.rows(),
+            a.cols(),
+            alpha,
+            a.as_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+            x.as_ptr(),
+            1,
+            beta,
+            y.as_mut_ptr(),
+            1,
+        )
+    }
+}
+
+/// Computes `a = alpha * x * y^T + a`.
+///
+/// Panics unless `x` has `a.rows()` and `y` has `a.cols()` elements.
+#[track_caller]
+pub fn dger(context: &Context, alpha: f64, x: &[f64], y: &[f64], mut a: MatMut<f64>) {
+    assert!(
+        x.len() == a.rows() && y.len() == a.cols(),
+        "cannot add the product of vectors of length {} and {} to a {}x{} matrix",
+        x.len(),
+        y.len(),
+        a.rows(),
+        a.cols(),
+    );
+
+    unsafe {
+        dger_strided(
+            context,
+            a.rows(),
+            a.cols(),
+            alpha,
+            x.as_ptr(),
+            1,
+            y.as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+        )
+    }
+}
+
+/// Computes `a = alpha * x * x^T + a` on the `uplo` triangle of `a`.
+///
+/// Panics unless `a` is square and `x` has `a.rows()` elements.
+#[track_caller]
+pub fn dsyr(context: &Context, uplo: Uplo, alpha: f64, x: &[f64], mut a: MatMut<f64>) {
+    assert!(
+        x.len() == a.rows() && x.len() == a.cols(),
+        "cannot add the square of a vector of length {} to a {}x{} matrix",
+        x.len(),
+        a.rows(),
+        a.cols(),
+    );
+
+    unsafe {
+        dsyr_strided(
+            context,
+            uplo,
+            a.rows(),
+            alpha,
+            x.as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+        )
+    }
+}
+
+/// Computes `a = alpha * x * y^T + alpha * y * x^T + a` on the `uplo` triangle
+/// of `a`.
+///
+/// Panics unless `a` is square and `x` and `y` have `a.rows()` elements.
+#[track_caller]
+pub fn dsyr2(context: &Context, uplo: Uplo, alpha: f64, x: &[f64], y: &[f64], mut a: MatMut<f64>) {
+    assert!(
+        x.len() == a.rows() && y.len() == a.rows() && a.rows() == a.cols(),
+        "cannot add the products of vectors of length {} and {} to a {}x{} matrix",
+        x.len(),
+        y.len(),
+        a.rows(),
+        a.cols(),
+    );
+
+    unsafe {
+        dsyr2_strided(
+            context,
+            uplo,
+            a.rows(),
+            alpha,
+            x.as_ptr(),
+            1,
+            y.as_ptr(),
+            1,
+            a.as_mut_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+        )
+    }
+}
+
+/// Computes `x = a * x` for the triangular matrix held in the `uplo` triangle
+/// of `a`.
+///
+/// Panics unless `a` is square and `x` has `a.rows()` elements.
+#[track_caller]
+pub fn dtrmv(context: &Context, uplo: Uplo, diag: Diag, a: MatRef<f64>, x: &mut [f64]) {
+    assert!(
+        x.len() == a.rows() && x.len() == a.cols(),
+        "cannot multiply a {}x{} triangular matrix by a vector of length {}",
+        a.rows(),
+        a.cols(),
+        x.len(),
+    );
+
+    unsafe {
+        dtrmv_strided(
+            context,
+            uplo,
+            diag,
+            a.rows(),
+            a.as_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+            x.as_mut_ptr(),
+            1,
+        )
+    }
+}
+
+/// Solves `a * x = b` for the triangular matrix held in the `uplo` triangle
+/// of `a`, where `x` holds `b` on entry.
+///
+/// Panics unless `a` is square and `x` has `a.rows()` elements.
+#[track_caller]
+pub fn dtrsv(context: &Context, uplo: Uplo, diag: Diag, a: MatRef<f64>, x: &mut [f64]) {
+    assert!(
+        x.len() == a.rows() && x.len() == a.cols(),
+        "cannot solve a {}x{} triangular system with a vector of length {}",
+        a.rows(),
+        a.cols(),
+        x.len(),
+    );
+
+    unsafe {
+        dtrsv_strided(
+            context,
+            uplo,
+            diag,
+            a.rows(),
+            a.as_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+            x.as_mut_ptr(),
+            1,
+        )
+    }
+}
+
+/// Computes `y = alpha * a * x + beta * y` for the symmetric matrix held in the
+/// `uplo` triangle of `a`.
+///
+/// Panics unless `a` is square and `x` and `y` have `a.rows()` elements. When
+/// `beta` is zero `y` is not read.
+#[track_caller]
+pub fn dsymv(
+    context: &Context,
+    uplo: Uplo,
+    alpha: f64,
+    a: MatRef<f64>,
+    x: &[f64],
+    beta: f64,
+    y: &mut [f64],
+) {
+    assert!(
+        x.len() == a.rows() && y.len() == a.rows() && a.rows() == a.cols(),
+        "cannot multiply a {}x{} symmetric matrix by a vector of length {} into one of length {}",
+        a.rows(),
+        a.cols(),
+        x.len(),
+        y.len(),
+    );
+
+    unsafe {
+        dsymv_strided(
+            context,
+            uplo,
+            a.rows(),
+            alpha,
+            a.as_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+            x.as_ptr(),
+            1,
+            beta,
+            y.as_mut_ptr(),
+            1,
+        )
+    }
+}
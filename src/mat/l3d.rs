@@ -0,0 +1,169 @@
+use super::{MatMut, MatRef};
+use crate::context::Context;
+use crate::l3d::dgemm_strided;
+
+/// Computes `c = alpha * a * b + beta * c`.
+///
+/// Panics unless `a` is `m x k`, `b` is `k x n` and `c` is `m x n`. When
+/// `beta` is zero `c` is not read.
+#[track_caller]
+pub fn dgemm(
+    context: &Context,
+    alpha: f64,
+    a: MatRef<f64>,
+    b: MatRef<f64>,
+    beta: f64,
+    mut c: MatMut<f64>,
+) {
+    assert!(
+        a.rows() == c.rows() && b.cols() == c.cols() && a.cols() == b.rows(),
+        "cannot multiply a {}x{} by a {}x{} matrix into a {}x{} matrix",
+        a.rows(),
+        a.cols(),
+        b.rows(),
+        b.cols(),
+        c.rows(),
+        c.cols(),
+    );
+
+    unsafe {
+        dgemm_strided(
+            context,
+            c.rows(),
+            c.cols(),
+            a.cols(),
+            alpha,
+            a.as_ptr(),
+            a.row_stride(),
+            a.col_stride(),
+            b.as_ptr(),
+            b.row_stride(),
+            b.col_stride(),
+            beta,
+            c.as_mut_ptr(),
+            c.row_stride(),
+            c.col_stride(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Layout;
+
+    const PAD: f64 = 1000.5;
+
+    // Small integers, so that every sum is exact whatever order the kernels
+    // add in.
+    fn storage(len: usize, shift: usize) -> Vec<f64> {
+        (0..len)
+            .map(|e| ((e * 7 + shift) % 11) as f64 - 5.)
+            .collect()
+    }
+
+    // Checks c = 2 * a * b + beta * c against a triple loop over the views. C
+    // lives in `c_data`, which holds PAD outside the view and NaN inside it
+    // when beta is zero; neither may be read or written.
+    fn check_dgemm(a: MatRef<f64>, b: MatRef<f64>, beta: f64, mut c: MatMut<f64>) {
+        let (m, n, k) = (a.rows(), b.cols(), a.cols());
+
+        let expected: Vec<f64> = (0..m * n)
+            .map(|e| {
+                let (i, j) = (e % m, e / m);
+                let dot: f64 = (0..k).map(|p| a.get(i, p) * b.get(p, j)).sum();
+                if beta == 0. {
+                    2. * dot
+                } else {
+                    2. * dot + beta * c.get(i, j)
+                }
+            })
+            .collect();
+
+        dgemm(&Context::new(), 2., a, b, beta, c.reborrow());
+
+        for (e, &expected) in expected.iter().enumerate() {
+            let (i, j) = (e % m, e / m);
+            assert_eq!(*c.get(i, j), expected, "({}, {})", i, j);
+        }
+    }
+
+    // Fills the elements of a view of C with `value`.
+    fn fill(mut c: MatMut<f64>, value: impl Fn(usize, usize) -> f64) {
+        for i in 0..c.rows() {
+            for j in 0..c.cols() {
+                *c.get_mut(i, j) = value(i, j);
+            }
+        }
+    }
+
+    fn c0(beta: f64) -> impl Fn(usize, usize) -> f64 {
+        move |i, j| {
+            if beta == 0. {
+                f64::NAN
+            } else {
+                ((i * 3 + j) % 7) as f64
+            }
+        }
+    }
+
+    #[test]
+    fn dgemm_matches_the_reference_on_transposed_and_sub_views() {
+        let (m, n, k) = (17, 13, 9);
+        let a_data = storage(30 * 40, 1);
+        let b_data = storage(30 * 40, 2);
+
+        for &beta in &[0., -3.] {
+            // A is a transposed block of a column-major matrix, B a block of
+            // a row-major one and C a block of a column-major one.
+            let a = MatRef::from_slice(&a_data, 25, 30, 27)
+                .submatrix(3, 2, k, m)
+                .transpose();
+            let b = MatRef::from_slice_with_layout(&b_data, Layout::RowMajor, 20, 30, 31)
+                .submatrix(5, 4, k, n);
+            let mut c_data = vec![PAD; 25 * 20];
+            let mut c = MatMut::from_slice(&mut c_data, 25, 20, 25).submatrix(2, 3, m, n);
+            fill(c.reborrow(), c0(beta));
+            check_dgemm(a, b, beta, c);
+            assert_eq!(
+                c_data.iter().filter(|&&x| x == PAD).count(),
+                25 * 20 - m * n
+            );
+
+            // A row-major, B transposed and C a transposed column-major block,
+            // which makes C row-major.
+            let a = MatRef::from_slice_with_layout(&a_data, Layout::RowMajor, m, k, k + 2);
+            let b = MatRef::from_slice(&b_data, n, k, n + 1).transpose();
+            let mut c_data = vec![PAD; 20 * 20];
+            let mut c = MatMut::from_slice(&mut c_data, 20, 20, 20)
+                .submatrix(1, 2, n, m)
+                .transpose();
+            fill(c.reborrow(), c0(beta));
+            check_dgemm(a, b, beta, c);
+            assert_eq!(
+                c_data.iter().filter(|&&x| x == PAD).count(),
+                20 * 20 - m * n
+            );
+        }
+    }
+
+    #[test]
+    fn dgemm_matches_the_reference_when_no_stride_of_c_is_one() {
+        let (m, n, k) = (17, 13, 9);
+        let a_data = storage(m * k, 1);
+        let b_data = storage(k * n, 2);
+
+        for &beta in &[0., 1., -3.] {
+            let a = MatRef::from_slice(&a_data, m, k, m);
+            let b = MatRef::from_slice_with_layout(&b_data, Layout::RowMajor, k, n, n);
+            // A block of a view that skips every other element of its columns,
+            // so the elements between its rows must be left alone.
+            let mut c_data = vec![PAD; 800];
+            let mut c =
+                MatMut::from_slice_with_strides(&mut c_data, 18, 20, 2, 37).submatrix(1, 3, m, n);
+            fill(c.reborrow(), c0(beta));
+            check_dgemm(a, b, beta, c);
+            assert_eq!(c_data.iter().filter(|&&x| x == PAD).count(), 800 - m * n);
+        }
+    }
+}
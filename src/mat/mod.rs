@@ -0,0 +1,642 @@
+//! Borrowed strided matrix views and the level-2 and level-3 routines that
+//! take them.
+//!
+//! Element `(i, j)` of a view lives `i * row_stride + j * col_stride`
+//! elements past its first element, so column-major, row-major, transposed and
+//! sub-matrix views are all expressed without copying. Every constructor and
+//! slicing method checks its bounds and panics when they are violated.
+
+mod l2d;
+mod l2s;
+mod l3d;
+mod l3s;
+
+pub use l2d::*;
+pub use l2s::*;
+pub use l3d::*;
+pub use l3s::*;
+
+use crate::types::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Immutable view of a `rows x cols` matrix.
+pub struct MatRef<'a, T> {
+    ptr: *const T,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// Mutable view of a `rows x cols` matrix.
+///
+/// Distinct indices of a mutable view never refer to the same element.
+pub struct MatMut<'a, T> {
+    ptr: *mut T,
+    rows: usize,
+    cols: usize,
+    row_stride: usize,
+    col_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+unsafe impl<'a, T: Sync> Send for MatRef<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for MatRef<'a, T> {}
+
+unsafe impl<'a, T: Send> Send for MatMut<'a, T> {}
+
+unsafe impl<'a, T: Sync> Sync for MatMut<'a, T> {}
+
+impl<'a, T> Clone for MatRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for MatRef<'a, T> {}
+
+impl<'a, T> fmt::Debug for MatRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MatRef")
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .field("row_stride", &self.row_stride)
+            .field("col_stride", &self.col_stride)
+            .finish()
+    }
+}
+
+impl<'a, T> fmt::Debug for MatMut<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MatMut")
+            .field("rows", &self.rows)
+            .field("cols", &self.cols)
+            .field("row_stride", &self.row_stride)
+            .field("col_stride", &self.col_stride)
+            .finish()
+    }
+}
+
+// Number of elements a strided view spans, or `None` on overflow.
+fn span(rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> Option<usize> {
+    if rows == 0 || cols == 0 {
+        return Some(0);
+    }
+
+    (rows - 1)
+        .checked_mul(row_stride)?
+        .checked_add((cols - 1).checked_mul(col_stride)?)?
+        .checked_add(1)
+}
+
+// Whether every index of the view maps to its own element. Checks that one of
+// the two dimensions is laid out entirely inside a single step of the other.
+fn is_disjoint(rows: usize, cols: usize, row_stride: usize, col_stride: usize) -> bool {
+    if rows <= 1 || cols <= 1 {
+        return (rows <= 1 || row_stride > 0) && (cols <= 1 || col_stride > 0);
+    }
+
+    let columns_apart = row_stride > 0
+        && rows
+            .checked_mul(row_stride)
+            .is_some_and(|extent| col_stride >= extent);
+
+    let rows_apart = col_stride > 0
+        && cols
+            .checked_mul(col_stride)
+            .is_some_and(|extent| row_stride >= extent);
+
+    columns_apart || rows_apart
+}
+
+#[track_caller]
+fn check_span(len: usize, rows: usize, cols: usize, row_stride: usize, col_stride: usize) {
+    assert!(
+        span(rows, cols, row_stride, col_stride).is_some_and(|span| span <= len),
+        "a {}x{} view with strides ({}, {}) does not fit in a slice of length {}",
+        rows,
+        cols,
+        row_stride,
+        col_stride,
+        len,
+    );
+}
+
+#[track_caller]
+fn check_ld(inner: usize, ld: usize) {
+    assert!(
+        ld >= inner.max(1),
+        "leading dimension is {} but must be at least {}",
+        ld,
+        inner.max(1),
+    );
+}
+
+// Row and column stride of a matrix stored with `layout`, checking that `ld`
+// is large enough.
+#[track_caller]
+fn layout_strides(layout: Layout, rows: usize, cols: usize, ld: usize) -> (usize, usize) {
+    match layout {
+        Layout::ColMajor => check_ld(rows, ld),
+        Layout::RowMajor => check_ld(cols, ld),
+    }
+
+    layout.strides(false, ld)
+}
+
+#[track_caller]
+fn check_index(i: usize, j: usize, rows: usize, cols: usize) {
+    assert!(
+        i < rows && j < cols,
+        "index ({}, {}) is out of bounds for a {}x{} matrix",
+        i,
+        j,
+        rows,
+        cols,
+    );
+}
+
+#[track_caller]
+fn check_block(i: usize, j: usize, nrows: usize, ncols: usize, rows: usize, cols: usize) {
+    assert!(
+        i <= rows && nrows <= rows - i && j <= cols && ncols <= cols - j,
+        "block of {}x{} at ({}, {}) is out of bounds for a {}x{} matrix",
+        nrows,
+        ncols,
+        i,
+        j,
+        rows,
+        cols,
+    );
+}
+
+impl<'a, T> MatRef<'a, T> {
+    /// Column-major view with leading dimension `ld`.
+    #[track_caller]
+    pub fn from_slice(data: &'a [T], rows: usize, cols: usize, ld: usize) -> Self {
+        MatRef::from_slice_with_layout(data, Layout::ColMajor, rows, cols, ld)
+    }
+
+    /// View of a matrix stored in `layout` with leading dimension `ld`.
+    #[track_caller]
+    pub fn from_slice_with_layout(
+        data: &'a [T],
+        layout: Layout,
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Self {
+        let (row_stride, col_stride) = layout_strides(layout, rows, cols, ld);
+        MatRef::from_slice_with_strides(data, rows, cols, row_stride, col_stride)
+    }
+
+    #[track_caller]
+    pub fn from_slice_with_strides(
+        data: &'a [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        check_span(data.len(), rows, cols, row_stride, col_stride);
+
+        MatRef {
+            ptr: data.as_ptr(),
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    #[track_caller]
+    pub fn get(&self, i: usize, j: usize) -> &'a T {
+        check_index(i, j, self.rows, self.cols);
+        unsafe { &*self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    /// View of the `nrows x ncols` block whose top left element is `(i, j)`.
+    #[track_caller]
+    pub fn submatrix(self, i: usize, j: usize, nrows: usize, ncols: usize) -> Self {
+        check_block(i, j, nrows, ncols, self.rows, self.cols);
+
+        MatRef {
+            ptr: self
+                .ptr
+                .wrapping_add(i * self.row_stride + j * self.col_stride),
+            rows: nrows,
+            cols: ncols,
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        MatRef {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    /// Splits into the rows before `i` and the rows from `i` on.
+    #[track_caller]
+    pub fn split_at_row(self, i: usize) -> (Self, Self) {
+        let (rows, cols) = (self.rows, self.cols);
+        check_block(i, 0, 0, cols, rows, cols);
+
+        (
+            self.submatrix(0, 0, i, cols),
+            self.submatrix(i, 0, rows - i, cols),
+        )
+    }
+
+    /// Splits into the columns before `j` and the columns from `j` on.
+    #[track_caller]
+    pub fn split_at_col(self, j: usize) -> (Self, Self) {
+        let (rows, cols) = (self.rows, self.cols);
+        check_block(0, j, rows, 0, rows, cols);
+
+        (
+            self.submatrix(0, 0, rows, j),
+            self.submatrix(0, j, rows, cols - j),
+        )
+    }
+}
+
+impl<'a, T> MatMut<'a, T> {
+    /// Column-major view with leading dimension `ld`.
+    #[track_caller]
+    pub fn from_slice(data: &'a mut [T], rows: usize, cols: usize, ld: usize) -> Self {
+        MatMut::from_slice_with_layout(data, Layout::ColMajor, rows, cols, ld)
+    }
+
+    /// View of a matrix stored in `layout` with leading dimension `ld`.
+    #[track_caller]
+    pub fn from_slice_with_layout(
+        data: &'a mut [T],
+        layout: Layout,
+        rows: usize,
+        cols: usize,
+        ld: usize,
+    ) -> Self {
+        let (row_stride, col_stride) = layout_strides(layout, rows, cols, ld);
+        MatMut::from_slice_with_strides(data, rows, cols, row_stride, col_stride)
+    }
+
+    /// Panics unless the strides keep every element of the view distinct.
+    #[track_caller]
+    pub fn from_slice_with_strides(
+        data: &'a mut [T],
+        rows: usize,
+        cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        check_span(data.len(), rows, cols, row_stride, col_stride);
+        assert!(
+            is_disjoint(rows, cols, row_stride, col_stride),
+            "strides ({}, {}) make elements of a {}x{} mutable view overlap",
+            row_stride,
+            col_stride,
+            rows,
+            cols,
+        );
+
+        MatMut {
+            ptr: data.as_mut_ptr(),
+            rows,
+            cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+
+    pub fn col_stride(&self) -> usize {
+        self.col_stride
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.ptr
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.ptr
+    }
+
+    #[track_caller]
+    pub fn get(&self, i: usize, j: usize) -> &T {
+        check_index(i, j, self.rows, self.cols);
+        unsafe { &*self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    #[track_caller]
+    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T {
+        check_index(i, j, self.rows, self.cols);
+        unsafe { &mut *self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    /// Immutable view borrowing from this one.
+    pub fn as_const(&self) -> MatRef<'_, T> {
+        MatRef {
+            ptr: self.ptr,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Mutable view borrowing from this one, for passing on without giving
+    /// this view up.
+    pub fn reborrow(&mut self) -> MatMut<'_, T> {
+        MatMut {
+            ptr: self.ptr,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn into_const(self) -> MatRef<'a, T> {
+        MatRef {
+            ptr: self.ptr,
+            rows: self.rows,
+            cols: self.cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// View of the `nrows x ncols` block whose top left element is `(i, j)`.
+    #[track_caller]
+    pub fn submatrix(self, i: usize, j: usize, nrows: usize, ncols: usize) -> Self {
+        check_block(i, j, nrows, ncols, self.rows, self.cols);
+
+        MatMut {
+            ptr: self
+                .ptr
+                .wrapping_add(i * self.row_stride + j * self.col_stride),
+            rows: nrows,
+            cols: ncols,
+            ..self
+        }
+    }
+
+    pub fn transpose(self) -> Self {
+        MatMut {
+            rows: self.cols,
+            cols: self.rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    /// Splits into the rows before `i` and the rows from `i` on.
+    #[track_caller]
+    pub fn split_at_row(self, i: usize) -> (Self, Self) {
+        let (rows, cols) = (self.rows, self.cols);
+        check_block(i, 0, 0, cols, rows, cols);
+
+        let bottom = MatMut {
+            ptr: self.ptr.wrapping_add(i * self.row_stride),
+            rows: rows - i,
+            ..self
+        };
+
+        (MatMut { rows: i, ..self }, bottom)
+    }
+
+    /// Splits into the columns before `j` and the columns from `j` on.
+    #[track_caller]
+    pub fn split_at_col(self, j: usize) -> (Self, Self) {
+        let (rows, cols) = (self.rows, self.cols);
+        check_block(0, j, rows, 0, rows, cols);
+
+        let right = MatMut {
+            ptr: self.ptr.wrapping_add(j * self.col_stride),
+            cols: cols - j,
+            ..self
+        };
+
+        (MatMut { cols: j, ..self }, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_disjoint_requires_distinct_elements() {
+        let cases = [
+            ((3, 4, 1, 3), true),
+            ((3, 4, 1, 2), false),
+            ((3, 4, 4, 1), true),
+            ((3, 4, 3, 1), false),
+            ((3, 4, 2, 7), true),
+            ((3, 4, 2, 5), false),
+            ((3, 4, 0, 3), false),
+            ((3, 4, 1, 0), false),
+            ((1, 4, 0, 1), true),
+            ((4, 1, 1, 0), true),
+            ((1, 1, 0, 0), true),
+            ((3, 4, usize::MAX, 1), true),
+            ((3, 4, 1, usize::MAX), true),
+            ((3, 4, usize::MAX / 2, usize::MAX / 2), false),
+        ];
+
+        for &((rows, cols, row_stride, col_stride), disjoint) in &cases {
+            assert_eq!(
+                is_disjoint(rows, cols, row_stride, col_stride),
+                disjoint,
+                "{}x{} with strides ({}, {})",
+                rows,
+                cols,
+                row_stride,
+                col_stride
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "strides (1, 2) make elements of a 3x4 mutable view overlap")]
+    fn mutable_views_reject_overlapping_strides() {
+        MatMut::from_slice_with_strides(&mut [0; 12], 3, 4, 1, 2);
+    }
+
+    #[test]
+    fn immutable_views_accept_overlapping_strides() {
+        let data = [1, 2, 3];
+        let view = MatRef::from_slice_with_strides(&data, 2, 3, 0, 1);
+
+        assert_eq!((*view.get(0, 2), *view.get(1, 2)), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "a 3x4 view with strides (1, 4) does not fit in a slice of length 11"
+    )]
+    fn views_must_fit_in_their_slice() {
+        MatRef::from_slice(&[0; 11], 3, 4, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension is 3 but must be at least 4")]
+    fn row_major_leading_dimension_below_the_columns_panics() {
+        MatMut::from_slice_with_layout(&mut [0; 12], Layout::RowMajor, 3, 4, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index (3, 0) is out of bounds for a 3x4 matrix")]
+    fn get_out_of_bounds_panics() {
+        MatRef::from_slice(&[0; 12], 3, 4, 3).get(3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "block of 2x2 at (2, 3) is out of bounds for a 3x4 matrix")]
+    fn submatrix_out_of_bounds_panics() {
+        MatRef::from_slice(&[0; 12], 3, 4, 3).submatrix(2, 3, 2, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "block of 3x2 at (0, 3) is out of bounds for a 3x4 matrix")]
+    fn mutable_submatrix_out_of_bounds_panics() {
+        MatMut::from_slice(&mut [0; 12], 3, 4, 3).submatrix(0, 3, 3, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "block of 0x4 at (4, 0) is out of bounds for a 3x4 matrix")]
+    fn split_at_row_out_of_bounds_panics() {
+        MatMut::from_slice(&mut [0; 12], 3, 4, 3).split_at_row(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "block of 3x0 at (0, 5) is out of bounds for a 3x4 matrix")]
+    fn split_at_col_out_of_bounds_panics() {
+        MatRef::from_slice(&[0; 12], 3, 4, 3).split_at_col(5);
+    }
+
+    #[test]
+    fn submatrix_and_transpose_index_the_parent() {
+        let data: Vec<usize> = (0..40).collect();
+        let view = MatRef::from_slice_with_layout(&data, Layout::RowMajor, 4, 7, 9);
+
+        let sub = view.submatrix(1, 2, 3, 4);
+        let transposed = sub.transpose();
+
+        assert_eq!((transposed.rows(), transposed.cols()), (4, 3));
+        for i in 0..3 {
+            for j in 0..4 {
+                assert_eq!(sub.get(i, j), view.get(i + 1, j + 2));
+                assert_eq!(transposed.get(j, i), view.get(i + 1, j + 2));
+            }
+        }
+    }
+
+    // The two halves of a split and the parent index of the first element of
+    // the second half.
+    type Split<'a> = (MatMut<'a, i64>, MatMut<'a, i64>, usize, usize);
+
+    // Writes the parent index of every element through both halves of a
+    // split and checks that each element of the parent is written exactly
+    // once, with the padding left alone.
+    fn check_split(layout: Layout, split: fn(MatMut<i64>) -> Split) {
+        let (rows, cols, ld) = (5, 6, 8);
+        let mut data = vec![-1; 48];
+
+        let (mut first, mut second, i0, j0) = split(MatMut::from_slice_with_layout(
+            &mut data, layout, rows, cols, ld,
+        ));
+        for (half, (di, dj)) in [(&mut first, (0, 0)), (&mut second, (i0, j0))] {
+            for i in 0..half.rows() {
+                for j in 0..half.cols() {
+                    let element = half.get_mut(i, j);
+                    assert_eq!(
+                        *element,
+                        -1,
+                        "{:?} ({}, {}) written twice",
+                        layout,
+                        i + di,
+                        j + dj
+                    );
+                    *element = ((i + di) * 10 + j + dj) as i64;
+                }
+            }
+        }
+
+        let view = MatRef::from_slice_with_layout(&data, layout, rows, cols, ld);
+        for i in 0..rows {
+            for j in 0..cols {
+                assert_eq!(*view.get(i, j), (i * 10 + j) as i64, "{:?}", layout);
+            }
+        }
+        assert_eq!(data.iter().filter(|&&x| x == -1).count(), 48 - rows * cols);
+    }
+
+    #[test]
+    fn split_halves_do_not_overlap() {
+        for &layout in &[Layout::ColMajor, Layout::RowMajor] {
+            check_split(layout, |m| {
+                let (top, bottom) = m.split_at_row(2);
+                (top, bottom, 2, 0)
+            });
+            check_split(layout, |m| {
+                let (left, right) = m.split_at_col(4);
+                (left, right, 0, 4)
+            });
+            check_split(layout, |m| {
+                let (left, right) = m.split_at_col(0);
+                (left, right, 0, 0)
+            });
+            // Splitting a transposed view splits the parent the other way.
+            check_split(layout, |m| {
+                let (top, bottom) = m.transpose().split_at_row(3);
+                (top.transpose(), bottom.transpose(), 0, 3)
+            });
+        }
+    }
+}
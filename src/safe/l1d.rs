@@ -0,0 +1,107 @@
+use super::check_vector;
+
+pub use crate::{drotg, drotmg};
+
+pub fn drot(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, c: f64, s: f64) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::drot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s) }
+}
+
+pub fn drotm(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize, param: &[f64; 5]) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::drotm(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, param) }
+}
+
+pub fn dswap(n: usize, x: &mut [f64], incx: isize, y: &mut [f64], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::dswap(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn dscal(n: usize, a: f64, x: &mut [f64], incx: isize) {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::dscal(n, a, x.as_mut_ptr(), incx) }
+}
+
+pub fn dcopy(n: usize, x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::dcopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn daxpy(n: usize, a: f64, x: &[f64], incx: isize, y: &mut [f64], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::daxpy(n, a, x.as_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn ddot(n: usize, x: &[f64], incx: isize, y: &[f64], incy: isize) -> f64 {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::ddot(n, x.as_ptr(), incx, y.as_ptr(), incy) }
+}
+
+pub fn dnrm2(n: usize, x: &[f64], incx: isize) -> f64 {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::dnrm2(n, x.as_ptr(), incx) }
+}
+
+pub fn dasum(n: usize, x: &[f64], incx: isize) -> f64 {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::dasum(n, x.as_ptr(), incx) }
+}
+
+pub fn idamax(n: usize, x: &[f64], incx: isize) -> usize {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::idamax(n, x.as_ptr(), incx) }
+}
+
+pub fn idamin(n: usize, x: &[f64], incx: isize) -> usize {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::idamin(n, x.as_ptr(), incx) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daxpy_reaches_every_element_of_strided_vectors() {
+        let x = [1., 2., 3.];
+        let mut y = [10., 0., 20., 0., 30.];
+
+        daxpy(3, 2., &x, -1, &mut y, 2);
+
+        assert_eq!(y, [16., 0., 24., 0., 32.]);
+    }
+
+    #[test]
+    fn empty_vectors_need_no_elements() {
+        assert_eq!(ddot(0, &[], 1, &[], -3), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "x has length 4 but n = 3 with increment 2 needs 5")]
+    fn short_x_panics() {
+        ddot(3, &[1.; 4], 2, &[1.; 3], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "y has length 4 but n = 3 with increment -2 needs 5")]
+    fn short_y_with_a_negative_increment_panics() {
+        dcopy(3, &[1.; 3], 1, &mut [0.; 4], -2);
+    }
+}
@@ -0,0 +1,107 @@
+use super::check_vector;
+
+pub use crate::{srotg, srotmg};
+
+pub fn srot(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, c: f32, s: f32) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::srot(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, c, s) }
+}
+
+pub fn srotm(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize, param: &[f32; 5]) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::srotm(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy, param) }
+}
+
+pub fn sswap(n: usize, x: &mut [f32], incx: isize, y: &mut [f32], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::sswap(n, x.as_mut_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn sscal(n: usize, a: f32, x: &mut [f32], incx: isize) {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::sscal(n, a, x.as_mut_ptr(), incx) }
+}
+
+pub fn scopy(n: usize, x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::scopy(n, x.as_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn saxpy(n: usize, a: f32, x: &[f32], incx: isize, y: &mut [f32], incy: isize) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::saxpy(n, a, x.as_ptr(), incx, y.as_mut_ptr(), incy) }
+}
+
+pub fn sdot(n: usize, x: &[f32], incx: isize, y: &[f32], incy: isize) -> f32 {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    unsafe { crate::sdot(n, x.as_ptr(), incx, y.as_ptr(), incy) }
+}
+
+pub fn snrm2(n: usize, x: &[f32], incx: isize) -> f32 {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::snrm2(n, x.as_ptr(), incx) }
+}
+
+pub fn sasum(n: usize, x: &[f32], incx: isize) -> f32 {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::sasum(n, x.as_ptr(), incx) }
+}
+
+pub fn isamax(n: usize, x: &[f32], incx: isize) -> usize {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::isamax(n, x.as_ptr(), incx) }
+}
+
+pub fn isamin(n: usize, x: &[f32], incx: isize) -> usize {
+    check_vector("x", x.len(), n, incx);
+
+    unsafe { crate::isamin(n, x.as_ptr(), incx) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saxpy_reaches_every_element_of_strided_vectors() {
+        let x = [1., 2., 3.];
+        let mut y = [10., 0., 20., 0., 30.];
+
+        saxpy(3, 2., &x, -1, &mut y, 2);
+
+        assert_eq!(y, [16., 0., 24., 0., 32.]);
+    }
+
+    #[test]
+    fn empty_vectors_need_no_elements() {
+        assert_eq!(sdot(0, &[], 1, &[], -3), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "x has length 4 but n = 3 with increment 2 needs 5")]
+    fn short_x_panics() {
+        sdot(3, &[1.; 4], 2, &[1.; 3], 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "y has length 4 but n = 3 with increment -2 needs 5")]
+    fn short_y_with_a_negative_increment_panics() {
+        scopy(3, &[1.; 3], 1, &mut [0.; 4], -2);
+    }
+}
@@ -0,0 +1,728 @@
+use super::{check_band, check_matrix, check_packed, check_vector};
+use crate::context::Context;
+use crate::types::{Diag, Layout, Uplo};
+
+/// Panics when `incy` is zero.
+pub fn dgemv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+    incx: isize,
+    beta: f64,
+    y: &mut [f64],
+    incy: isize,
+) {
+    check_matrix("a", a.len(), layout, m, n, lda);
+
+    if trans {
+        check_vector("x", x.len(), m, incx);
+        check_vector("y", y.len(), n, incy);
+    } else {
+        check_vector("x", x.len(), n, incx);
+        check_vector("y", y.len(), m, incy);
+    }
+
+    assert!(incy != 0, "increment of y must not be zero");
+
+    unsafe {
+        crate::dgemv(
+            context,
+            layout,
+            trans,
+            m,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+}
+
+pub fn dger(
+    context: &Context,
+    layout: Layout,
+    m: usize,
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    incx: isize,
+    y: &[f64],
+    incy: isize,
+    a: &mut [f64],
+    lda: usize,
+) {
+    check_vector("x", x.len(), m, incx);
+    check_vector("y", y.len(), n, incy);
+    check_matrix("a", a.len(), layout, m, n, lda);
+
+    unsafe {
+        crate::dger(
+            context,
+            layout,
+            m,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+}
+
+pub fn dsyr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    incx: isize,
+    a: &mut [f64],
+    lda: usize,
+) {
+    check_vector("x", x.len(), n, incx);
+    check_matrix("a", a.len(), layout, n, n, lda);
+
+    unsafe {
+        crate::dsyr(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+}
+
+pub fn dsyr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    incx: isize,
+    y: &[f64],
+    incy: isize,
+    a: &mut [f64],
+    lda: usize,
+) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+    check_matrix("a", a.len(), layout, n, n, lda);
+
+    unsafe {
+        crate::dsyr2(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            a.as_mut_ptr(),
+            lda,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtrmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: &[f64],
+    lda: usize,
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_matrix("a", a.len(), layout, n, n, lda);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtrmv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtrsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    a: &[f64],
+    lda: usize,
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_matrix("a", a.len(), layout, n, n, lda);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtrsv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+/// Panics when `incy` is zero.
+pub fn dsymv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+    incx: isize,
+    beta: f64,
+    y: &mut [f64],
+    incy: isize,
+) {
+    check_matrix("a", a.len(), layout, n, n, lda);
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    assert!(incy != 0, "increment of y must not be zero");
+
+    unsafe {
+        crate::dsymv(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+}
+
+/// Panics when `incy` is zero.
+pub fn dgbmv(
+    context: &Context,
+    layout: Layout,
+    trans: bool,
+    m: usize,
+    n: usize,
+    kl: usize,
+    ku: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+    incx: isize,
+    beta: f64,
+    y: &mut [f64],
+    incy: isize,
+) {
+    check_band("a", a.len(), layout, m, n, kl, ku, lda);
+
+    if trans {
+        check_vector("x", x.len(), m, incx);
+        check_vector("y", y.len(), n, incy);
+    } else {
+        check_vector("x", x.len(), n, incx);
+        check_vector("y", y.len(), m, incy);
+    }
+
+    assert!(incy != 0, "increment of y must not be zero");
+
+    unsafe {
+        crate::dgbmv(
+            context,
+            layout,
+            trans,
+            m,
+            n,
+            kl,
+            ku,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+}
+
+/// Panics when `incy` is zero.
+pub fn dsbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    k: usize,
+    alpha: f64,
+    a: &[f64],
+    lda: usize,
+    x: &[f64],
+    incx: isize,
+    beta: f64,
+    y: &mut [f64],
+    incy: isize,
+) {
+    check_band("a", a.len(), layout, n, n, 0, k, lda);
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    assert!(incy != 0, "increment of y must not be zero");
+
+    unsafe {
+        crate::dsbmv(
+            context,
+            layout,
+            uplo,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtbmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[f64],
+    lda: usize,
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_band("a", a.len(), layout, n, n, 0, k, lda);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtbmv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            k,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtbsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    k: usize,
+    a: &[f64],
+    lda: usize,
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_band("a", a.len(), layout, n, n, 0, k, lda);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtbsv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            k,
+            a.as_ptr(),
+            lda,
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+/// Panics when `incy` is zero.
+pub fn dspmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    ap: &[f64],
+    x: &[f64],
+    incx: isize,
+    beta: f64,
+    y: &mut [f64],
+    incy: isize,
+) {
+    check_packed("ap", ap.len(), n);
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+
+    assert!(incy != 0, "increment of y must not be zero");
+
+    unsafe {
+        crate::dspmv(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            ap.as_ptr(),
+            x.as_ptr(),
+            incx,
+            beta,
+            y.as_mut_ptr(),
+            incy,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtpmv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_packed("ap", ap.len(), n);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtpmv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+/// Panics when `incx` is zero.
+pub fn dtpsv(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    trans: bool,
+    diag: Diag,
+    n: usize,
+    ap: &[f64],
+    x: &mut [f64],
+    incx: isize,
+) {
+    check_packed("ap", ap.len(), n);
+    check_vector("x", x.len(), n, incx);
+
+    assert!(incx != 0, "increment of x must not be zero");
+
+    unsafe {
+        crate::dtpsv(
+            context,
+            layout,
+            uplo,
+            trans,
+            diag,
+            n,
+            ap.as_ptr(),
+            x.as_mut_ptr(),
+            incx,
+        )
+    }
+}
+
+pub fn dspr(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    incx: isize,
+    ap: &mut [f64],
+) {
+    check_vector("x", x.len(), n, incx);
+    check_packed("ap", ap.len(), n);
+
+    unsafe {
+        crate::dspr(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            ap.as_mut_ptr(),
+        )
+    }
+}
+
+pub fn dspr2(
+    context: &Context,
+    layout: Layout,
+    uplo: Uplo,
+    n: usize,
+    alpha: f64,
+    x: &[f64],
+    incx: isize,
+    y: &[f64],
+    incy: isize,
+    ap: &mut [f64],
+) {
+    check_vector("x", x.len(), n, incx);
+    check_vector("y", y.len(), n, incy);
+    check_packed("ap", ap.len(), n);
+
+    unsafe {
+        crate::dspr2(
+            context,
+            layout,
+            uplo,
+            n,
+            alpha,
+            x.as_ptr(),
+            incx,
+            y.as_ptr(),
+            incy,
+            ap.as_mut_ptr(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The 2 x 3 matrix [1 2 3; 4 5 6] with a padded leading dimension.
+    fn matrix(layout: Layout) -> (Vec<f64>, usize) {
+        match layout {
+            Layout::ColMajor => (vec![1., 4., 0., 2., 5., 0., 3., 6., 0.], 3),
+            Layout::RowMajor => (vec![1., 2., 3., 0., 4., 5., 6., 0.], 4),
+        }
+    }
+
+    #[test]
+    fn dgemv_accepts_both_layouts() {
+        let context = Context::new();
+
+        for &layout in &[Layout::ColMajor, Layout::RowMajor] {
+            let (a, lda) = matrix(layout);
+            let mut y = [1., 0., 1.];
+
+            dgemv(
+                &context,
+                layout,
+                false,
+                2,
+                3,
+                1.,
+                &a,
+                lda,
+                &[1., 0., 2.],
+                1,
+                2.,
+                &mut y,
+                -2,
+            );
+
+            assert_eq!(y, [18., 0., 9.], "{:?}", layout);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension of a is 1 but must be at least 2")]
+    fn column_major_leading_dimension_below_the_rows_panics() {
+        let context = Context::new();
+
+        dgemv(
+            &context,
+            Layout::ColMajor,
+            false,
+            2,
+            3,
+            1.,
+            &[0.; 9],
+            1,
+            &[0.; 3],
+            1,
+            0.,
+            &mut [0.; 2],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension of a is 2 but must be at least 3")]
+    fn row_major_leading_dimension_below_the_columns_panics() {
+        let context = Context::new();
+
+        dgemv(
+            &context,
+            Layout::RowMajor,
+            false,
+            2,
+            3,
+            1.,
+            &[0.; 9],
+            2,
+            &[0.; 3],
+            1,
+            0.,
+            &mut [0.; 2],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a has length 5 but a 2x3 matrix with leading dimension 2 needs 6")]
+    fn short_matrix_panics() {
+        let context = Context::new();
+
+        dgemv(
+            &context,
+            Layout::ColMajor,
+            false,
+            2,
+            3,
+            1.,
+            &[0.; 5],
+            2,
+            &[0.; 3],
+            1,
+            0.,
+            &mut [0.; 2],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "increment of y must not be zero")]
+    fn dgemv_with_a_zero_y_increment_panics() {
+        let context = Context::new();
+
+        dgemv(
+            &context,
+            Layout::ColMajor,
+            false,
+            2,
+            3,
+            1.,
+            &[0.; 6],
+            2,
+            &[0.; 3],
+            1,
+            0.,
+            &mut [0.; 2],
+            0,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "increment of y must not be zero")]
+    fn dsymv_with_a_zero_y_increment_panics() {
+        let context = Context::new();
+
+        dsymv(
+            &context,
+            Layout::ColMajor,
+            Uplo::Upper,
+            2,
+            1.,
+            &[0.; 4],
+            2,
+            &[0.; 2],
+            1,
+            0.,
+            &mut [0.; 2],
+            0,
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use super::check_matrix;
+use crate::context::Context;
+use crate::types::Layout;
+
+pub fn sgemm(
+    context: &Context,
+    layout: Layout,
+    transa: bool,
+    transb: bool,
+    m: usize,
+    n: usize,
+    k: usize,
+    alpha: f32,
+    a: &[f32],
+    lda: usize,
+    b: &[f32],
+    ldb: usize,
+    beta: f32,
+    c: &mut [f32],
+    ldc: usize,
+) {
+    if transa {
+        check_matrix("a", a.len(), layout, k, m, lda);
+    } else {
+        check_matrix("a", a.len(), layout, m, k, lda);
+    }
+
+    if transb {
+        check_matrix("b", b.len(), layout, n, k, ldb);
+    } else {
+        check_matrix("b", b.len(), layout, k, n, ldb);
+    }
+
+    check_matrix("c", c.len(), layout, m, n, ldc);
+
+    unsafe {
+        crate::sgemm(
+            context,
+            layout,
+            transa,
+            transb,
+            m,
+            n,
+            k,
+            alpha,
+            a.as_ptr(),
+            lda,
+            b.as_ptr(),
+            ldb,
+            beta,
+            c.as_mut_ptr(),
+            ldc,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sgemm_accepts_both_layouts() {
+        let context = Context::new();
+
+        // [1 2; 3 4] * [5 6; 7 8] with C padded to a leading dimension of 3.
+        for &(layout, a, b) in &[
+            (Layout::ColMajor, [1., 3., 2., 4.], [5., 7., 6., 8.]),
+            (Layout::RowMajor, [1., 2., 3., 4.], [5., 6., 7., 8.]),
+        ] {
+            let mut c = [-1.; 6];
+
+            sgemm(
+                &context, layout, false, false, 2, 2, 2, 1., &a, 2, &b, 2, 0., &mut c, 3,
+            );
+
+            let expected = match layout {
+                Layout::ColMajor => [19., 43., -1., 22., 50., -1.],
+                Layout::RowMajor => [19., 22., -1., 43., 50., -1.],
+            };
+            assert_eq!(c, expected, "{:?}", layout);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension of c is 2 but must be at least 3")]
+    fn column_major_leading_dimension_below_the_rows_panics() {
+        let context = Context::new();
+
+        sgemm(
+            &context,
+            Layout::ColMajor,
+            false,
+            false,
+            3,
+            2,
+            1,
+            1.,
+            &[0.; 3],
+            3,
+            &[0.; 2],
+            1,
+            0.,
+            &mut [0.; 6],
+            2,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "leading dimension of c is 1 but must be at least 2")]
+    fn row_major_leading_dimension_below_the_columns_panics() {
+        let context = Context::new();
+
+        sgemm(
+            &context,
+            Layout::RowMajor,
+            false,
+            false,
+            3,
+            2,
+            1,
+            1.,
+            &[0.; 3],
+            1,
+            &[0.; 2],
+            2,
+            0.,
+            &mut [0.; 6],
+            1,
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "b has length 5 but a 2x3 matrix with leading dimension 2 needs 6")]
+    fn short_matrix_panics() {
+        let context = Context::new();
+
+        sgemm(
+            &context,
+            Layout::ColMajor,
+            false,
+            false,
+            2,
+            3,
+            2,
+            1.,
+            &[0.; 4],
+            2,
+            &[0.; 5],
+            2,
+            0.,
+            &mut [0.; 6],
+            2,
+        );
+    }
+}
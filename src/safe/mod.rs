@@ -0,0 +1,147 @@
+//! Safe wrappers around the raw-pointer routines.
+//!
+//! Every routine takes slices together with the usual BLAS dimensions and
+//! strides, checks up front that each slice holds every element the call can
+//! touch, and panics when one is too short. Matrices are stored in the given
+//! [`Layout`](crate::Layout) with a leading dimension of at least the number of
+//! rows (column-major) or columns (row-major). Band and packed matrices are
+//! stored as for the raw-pointer routines.
+
+mod l1d;
+mod l1s;
+mod l2d;
+mod l2s;
+mod l3d;
+mod l3s;
+
+pub use l1d::*;
+pub use l1s::*;
+pub use l2d::*;
+pub use l2s::*;
+pub use l3d::*;
+pub use l3s::*;
+
+use crate::types::Layout;
+
+fn vector_len(n: usize, inc: usize) -> Option<usize> {
+    if n == 0 {
+        return Some(0);
+    }
+
+    (n - 1).checked_mul(inc)?.checked_add(1)
+}
+
+// Elements spanned by `outer` vectors of `inner` elements placed `ld` apart.
+fn matrix_len(inner: usize, outer: usize, ld: usize) -> Option<usize> {
+    if inner == 0 || outer == 0 {
+        return Some(0);
+    }
+
+    (outer - 1).checked_mul(ld)?.checked_add(inner)
+}
+
+#[track_caller]
+fn check_vector(name: &str, len: usize, n: usize, inc: isize) {
+    let required = vector_len(n, inc.unsigned_abs());
+
+    assert!(
+        required.is_some_and(|required| len >= required),
+        "{} has length {} but n = {} with increment {} needs {}",
+        name,
+        len,
+        n,
+        inc,
+        required.map_or("more than usize::MAX".to_string(), |r| r.to_string()),
+    );
+}
+
+#[track_caller]
+fn check_matrix(name: &str, len: usize, layout: Layout, rows: usize, cols: usize, ld: usize) {
+    let (inner, outer) = match layout {
+        Layout::ColMajor => (rows, cols),
+        Layout::RowMajor => (cols, rows),
+    };
+
+    assert!(
+        ld >= inner.max(1),
+        "leading dimension of {} is {} but must be at least {}",
+        name,
+        ld,
+        inner.max(1),
+    );
+
+    let required = matrix_len(inner, outer, ld);
+
+    assert!(
+        required.is_some_and(|required| len >= required),
+        "{} has length {} but a {}x{} matrix with leading dimension {} needs {}",
+        name,
+        len,
+        rows,
+        cols,
+        ld,
+        required.map_or("more than usize::MAX".to_string(), |r| r.to_string()),
+    );
+}
+
+// Elements of a triangle of order n packed without gaps.
+fn packed_len(n: usize) -> Option<usize> {
+    Some(n.checked_mul(n.checked_add(1)?)? / 2)
+}
+
+#[track_caller]
+fn check_band(
+    name: &str,
+    len: usize,
+    layout: Layout,
+    rows: usize,
+    cols: usize,
+    kl: usize,
+    ku: usize,
+    ld: usize,
+) {
+    let diagonals = kl.saturating_add(ku).saturating_add(1);
+
+    assert!(
+        ld >= diagonals,
+        "leading dimension of {} is {} but must be at least {}",
+        name,
+        ld,
+        diagonals,
+    );
+
+    let outer = match layout {
+        Layout::ColMajor => cols,
+        Layout::RowMajor => rows,
+    };
+    let required = if rows == 0 || cols == 0 {
+        Some(0)
+    } else {
+        matrix_len(diagonals, outer, ld)
+    };
+
+    assert!(
+        required.is_some_and(|required| len >= required),
+        "{} has length {} but a {}x{} band matrix with leading dimension {} needs {}",
+        name,
+        len,
+        rows,
+        cols,
+        ld,
+        required.map_or("more than usize::MAX".to_string(), |r| r.to_string()),
+    );
+}
+
+#[track_caller]
+fn check_packed(name: &str, len: usize, n: usize) {
+    let required = packed_len(n);
+
+    assert!(
+        required.is_some_and(|required| len >= required),
+        "{} has length {} but a packed triangle of order {} needs {}",
+        name,
+        len,
+        n,
+        required.map_or("more than usize::MAX".to_string(), |r| r.to_string()),
+    );
+}
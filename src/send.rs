@@ -17,7 +17,3 @@ unsafe impl Send for DSend {}
 pub struct DSendMut(pub *mut f64);
 
 unsafe impl Send for DSendMut {}
-
-a
-b
-c
\ No newline at end of file
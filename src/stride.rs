@@ -0,0 +1,10 @@
+/// Offset of the first element visited in a vector of `n` elements with
+/// increment `inc`. A negative increment walks the vector backwards from its
+/// last element, as in the reference BLAS.
+pub(crate) fn start(n: usize, inc: isize) -> isize {
+    if inc < 0 && n > 0 {
+        (n - 1) as isize * -inc
+    } else {
+        0
+    }
+}
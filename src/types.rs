@@ -0,0 +1,177 @@
+/// Storage order of the matrices passed to a routine.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Consecutive elements of a row are adjacent, rows are `ld` apart.
+    RowMajor,
+    /// Consecutive elements of a column are adjacent, columns are `ld` apart.
+    ColMajor,
+}
+
+impl Layout {
+    // Row and column stride of op(X) for a matrix X stored with this layout
+    // and leading dimension `ld`.
+    pub(crate) fn strides(self, trans: bool, ld: usize) -> (usize, usize) {
+        match (self, trans) {
+            (Layout::ColMajor, false) | (Layout::RowMajor, true) => (1, ld),
+            (Layout::ColMajor, true) | (Layout::RowMajor, false) => (ld, 1),
+        }
+    }
+
+    // Offset of element (0, 0) and row and column stride of a matrix with kl
+    // subdiagonals and ku superdiagonals held in the reference band storage
+    // with this layout and leading dimension `ld`. Only elements inside the
+    // band have a place in that storage.
+    pub(crate) fn band(self, kl: usize, ku: usize, ld: usize) -> (usize, usize, usize) {
+        match self {
+            Layout::ColMajor => (ku, 1, ld.saturating_sub(1)),
+            Layout::RowMajor => (kl, ld.saturating_sub(1), 1),
+        }
+    }
+
+    // Triangle and transposition that turn the uplo triangle of op(A) packed
+    // with this layout into a triangle packed column by column. Rows of one
+    // triangle are packed as the columns of the other triangle of A^T.
+    pub(crate) fn packed(self, uplo: Uplo, trans: bool) -> (Uplo, bool) {
+        match self {
+            Layout::ColMajor => (uplo, trans),
+            Layout::RowMajor => (uplo.transpose(), !trans),
+        }
+    }
+}
+
+/// Triangle of a symmetric or triangular matrix that a routine reads or
+/// writes. The other triangle is never accessed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Uplo {
+    /// Elements on and above the diagonal.
+    Upper,
+    /// Elements on and below the diagonal.
+    Lower,
+}
+
+impl Uplo {
+    // Triangle of the transpose that holds the elements of this one.
+    pub(crate) fn transpose(self) -> Uplo {
+        match self {
+            Uplo::Upper => Uplo::Lower,
+            Uplo::Lower => Uplo::Upper,
+        }
+    }
+
+    // First row and length of the part of column j of an n x n matrix that
+    // lies in this triangle.
+    pub(crate) fn column(self, n: usize, j: usize) -> (usize, usize) {
+        match self {
+            Uplo::Upper => (0, j + 1),
+            Uplo::Lower => (j, n - j),
+        }
+    }
+
+    // Offset of element (i, j) of this triangle of an n x n matrix packed
+    // column by column.
+    pub(crate) fn packed(self, n: usize, i: usize, j: usize) -> usize {
+        match self {
+            Uplo::Upper => i + j * (j + 1) / 2,
+            Uplo::Lower => i + j * (2 * n - j - 1) / 2,
+        }
+    }
+
+    // Subdiagonals and superdiagonals of a band matrix with k diagonals next
+    // to the main one in this triangle.
+    pub(crate) fn band(self, k: usize) -> (usize, usize) {
+        match self {
+            Uplo::Upper => (0, k),
+            Uplo::Lower => (k, 0),
+        }
+    }
+}
+
+/// Whether the diagonal of a triangular matrix is read or assumed to be all
+/// ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Diag {
+    /// The diagonal is read from the matrix.
+    NonUnit,
+    /// Every diagonal element is one and the stored diagonal is not read.
+    Unit,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LAYOUTS: [Layout; 2] = [Layout::ColMajor, Layout::RowMajor];
+
+    fn in_triangle(uplo: Uplo, i: usize, j: usize) -> bool {
+        match uplo {
+            Uplo::Upper => i <= j,
+            Uplo::Lower => i >= j,
+        }
+    }
+
+    #[test]
+    fn band_follows_the_reference_storage() {
+        // Element (i, j) of the band sits in row ku + i - j of column j
+        // (column-major) or in column kl + j - i of row i (row-major).
+        for &(kl, ku) in &[(0, 0), (2, 0), (0, 3), (1, 2), (6, 6)] {
+            let ld = kl + ku + 2;
+
+            for &layout in &LAYOUTS {
+                let (offset, rs, cs) = layout.band(kl, ku, ld);
+
+                for i in 0..5 {
+                    for j in 0..5 {
+                        if i <= j + kl && j <= i + ku {
+                            let expected = match layout {
+                                Layout::ColMajor => ku + i - j + j * ld,
+                                Layout::RowMajor => kl + j - i + i * ld,
+                            };
+                            assert_eq!(offset + i * rs + j * cs, expected, "({}, {})", i, j);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn packed_offsets_follow_the_storage_order() {
+        // Column-major storage packs the triangle column by column and
+        // row-major storage row by row, so walking it in that order must visit
+        // offsets 0, 1, 2, ...
+        for n in 0..7 {
+            for &layout in &LAYOUTS {
+                for &uplo in &[Uplo::Upper, Uplo::Lower] {
+                    let (column_uplo, trans) = layout.packed(uplo, false);
+                    let mut next = 0;
+
+                    for outer in 0..n {
+                        for inner in 0..n {
+                            let (i, j) = match layout {
+                                Layout::ColMajor => (inner, outer),
+                                Layout::RowMajor => (outer, inner),
+                            };
+                            if !in_triangle(uplo, i, j) {
+                                continue;
+                            }
+
+                            let offset = if trans {
+                                column_uplo.packed(n, j, i)
+                            } else {
+                                column_uplo.packed(n, i, j)
+                            };
+                            assert_eq!(
+                                offset, next,
+                                "{:?} {:?} n {} ({}, {})",
+                                layout, uplo, n, i, j
+                            );
+                            next += 1;
+                        }
+                    }
+
+                    assert_eq!(next, n * (n + 1) / 2);
+                }
+            }
+        }
+    }
+}